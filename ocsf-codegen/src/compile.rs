@@ -0,0 +1,367 @@
+//! Compiles a raw ocsf-schema directory into the resolved schema, as `python -m ocsf.compile` does
+use crate::Error;
+use crate::model::{OcsfSchema, json_files, read_json, read_types};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// The core schema, before its extensions, with the classes and objects that only exist for others
+/// to extend
+pub(crate) struct Compiled {
+    pub(crate) schema: OcsfSchema,
+    /// The attributes of `dictionary.json`, which extensions draw on as well
+    pub(crate) dictionary: Value,
+}
+
+/// A JSON file of `objects/` or `events/`, with its includes expanded
+struct Source {
+    path: PathBuf,
+    file: Value,
+}
+
+struct Compiler<'a> {
+    dictionary: &'a Value,
+    sources: BTreeMap<String, Source>,
+    /// Resolved classes or objects, in the shape of resolved.json
+    resolved: BTreeMap<String, Value>,
+}
+
+pub(crate) fn compile(root: &Path) -> Result<Compiled, Error> {
+    let version_path = root.join("version.json");
+    let version = if version_path.exists() {
+        read_json(&version_path)?["version"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    } else {
+        String::new()
+    };
+    let categories = read_json(&root.join("categories.json"))?["attributes"].clone();
+    let dictionary_path = root.join("dictionary.json");
+    let dictionary_file = read_json(&dictionary_path)?;
+    let types = read_types(&dictionary_file, &dictionary_path)?;
+    let dictionary = dictionary_file["attributes"].clone();
+
+    let objects = Compiler::new(&dictionary, root, "objects")?.resolve_all()?;
+    let classes = Compiler::new(&dictionary, root, "events")?.resolve_all()?;
+    let mut schema = OcsfSchema {
+        version,
+        classes: BTreeMap::new(),
+        objects: BTreeMap::new(),
+        types,
+    };
+    for (name, object) in objects {
+        let def = serde_json::from_value(object).map_err(|source| Error::Json {
+            path: root.join("objects"),
+            source,
+        })?;
+        schema.objects.insert(name, def);
+    }
+    for (name, mut class) in classes {
+        if class["uid"].is_i64() {
+            number_class(&mut class, &categories).map_err(|message| Error::Schema {
+                path: root.join("events"),
+                message,
+            })?;
+        }
+        let def = serde_json::from_value(class).map_err(|source| Error::Json {
+            path: root.join("events"),
+            source,
+        })?;
+        schema.classes.insert(name, def);
+    }
+    Ok(Compiled { schema, dictionary })
+}
+
+/// The extension directories of `extensions/`, sorted by path, but for the ignored ones
+pub(crate) fn extension_dirs(
+    root: &Path,
+    ignored: &BTreeSet<String>,
+) -> Result<Vec<PathBuf>, Error> {
+    let mut dirs = Vec::new();
+    for path in json_files(&root.join("extensions")) {
+        if path
+            .file_name()
+            .is_some_and(|name| name == "extension.json")
+        {
+            let extension = read_json(&path)?;
+            if !extension["name"]
+                .as_str()
+                .is_some_and(|name| ignored.contains(name))
+            {
+                dirs.extend(path.parent().map(Path::to_path_buf));
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+impl<'a> Compiler<'a> {
+    fn new(dictionary: &'a Value, root: &Path, dir: &str) -> Result<Self, Error> {
+        let mut sources = BTreeMap::new();
+        for path in json_files(&root.join(dir)) {
+            let file = read_source(&path, &[root])?;
+            let name = file["name"]
+                .as_str()
+                .ok_or_else(|| Error::Schema {
+                    path: path.clone(),
+                    message: "no name".to_string(),
+                })?
+                .to_string();
+            sources.insert(name, Source { path, file });
+        }
+        Ok(Compiler {
+            dictionary,
+            sources,
+            resolved: BTreeMap::new(),
+        })
+    }
+
+    fn resolve_all(mut self) -> Result<BTreeMap<String, Value>, Error> {
+        let names: Vec<String> = self.sources.keys().cloned().collect();
+        for name in &names {
+            self.resolve(name, &mut Vec::new())?;
+        }
+        Ok(self.resolved)
+    }
+
+    /// Resolves what `name` extends first. Only its own uid and deprecation are not inherited.
+    fn resolve(&mut self, name: &str, extending: &mut Vec<String>) -> Result<(), Error> {
+        if self.resolved.contains_key(name) {
+            return Ok(());
+        }
+        let Source { path, file } = &self.sources[name];
+        let (path, file) = (path.clone(), file.clone());
+        let invalid = |message: String| Error::Schema {
+            path: path.clone(),
+            message,
+        };
+        let mut def = match file["extends"].as_str() {
+            Some(parent) if extending.iter().any(|name| name == parent) || parent == name => {
+                return Err(invalid(format!("{} extends itself", name)));
+            }
+            Some(parent) if !self.sources.contains_key(parent) => {
+                return Err(invalid(format!(
+                    "{} extends {}, which is not defined",
+                    name, parent
+                )));
+            }
+            Some(parent) => {
+                extending.push(name.to_string());
+                self.resolve(parent, extending)?;
+                extending.pop();
+                let mut def = self.resolved[parent].clone();
+                let inherited = def.as_object_mut().expect("resolved to an object");
+                inherited.remove("uid");
+                inherited.remove("@deprecated");
+                def
+            }
+            None => json!({ "attributes": {} }),
+        };
+
+        for key in [
+            "name",
+            "caption",
+            "description",
+            "category",
+            "uid",
+            "constraints",
+            "@deprecated",
+        ] {
+            if let Some(value) = file.get(key) {
+                def[key] = value.clone();
+            }
+        }
+        if def.get("caption").is_none() {
+            def["caption"] = name.into();
+        }
+        let mut profiles: BTreeSet<String> = strings(&def["profiles"])
+            .chain(strings(&file["profiles"]))
+            .collect();
+        for (attr_name, overrides) in file["attributes"].as_object().into_iter().flatten() {
+            let base = match def["attributes"].get(attr_name) {
+                Some(attr) => attr.clone(),
+                None => self.dictionary.get(attr_name).cloned().ok_or_else(|| {
+                    invalid(format!("attribute {} is not in the dictionary", attr_name))
+                })?,
+            };
+            def["attributes"][attr_name] = merge_attribute(base, overrides);
+        }
+        // A profile stays listed while some attribute is still part of it
+        let attributes = def["attributes"]
+            .as_object()
+            .into_iter()
+            .flat_map(|attributes| attributes.values());
+        let tagged: BTreeSet<&str> = attributes
+            .filter_map(|attr| attr["profile"].as_str())
+            .collect();
+        profiles.retain(|profile| tagged.contains(profile.as_str()));
+        if !profiles.is_empty() {
+            def["profiles"] = json!(profiles);
+        }
+        self.resolved.insert(name.to_string(), def);
+        Ok(())
+    }
+}
+
+/// Lays the attributes a class or file lists over those it inherits or finds in the dictionary.
+/// Enums are merged value by value. An attribute listed by a class is not part of a profile,
+/// unless the class includes it from one.
+pub(crate) fn merge_attribute(mut base: Value, overrides: &Value) -> Value {
+    if let Some(base) = base.as_object_mut() {
+        base.remove("profile");
+    }
+    for (key, value) in overrides.as_object().into_iter().flatten() {
+        match (key.as_str(), base.get_mut(key)) {
+            ("enum", Some(Value::Object(values))) => {
+                values.extend(
+                    value
+                        .as_object()
+                        .into_iter()
+                        .flatten()
+                        .map(|(k, v)| (k.clone(), v.clone())),
+                );
+            }
+            _ => base[key] = value.clone(),
+        }
+    }
+    if base["type"] == "object_t"
+        && let Some(object_type) = base["object_type"].as_str()
+    {
+        base["type"] = object_type.to_string().into();
+    }
+    if base.get("requirement").is_none() {
+        base["requirement"] = "optional".into();
+    }
+    base
+}
+
+/// Reads a class or object file, replacing the `$include` of its attributes with the attributes of
+/// the included files.
+/// Those are looked up under each of `roots` in turn, as extensions include files of their own and
+/// of the core schema.
+/// Attributes of an included profile are tagged with it, and the profile is added to the file's
+/// `profiles`.
+/// The file's own attributes are laid over the included ones.
+pub(crate) fn read_source(path: &Path, roots: &[&Path]) -> Result<Value, Error> {
+    let mut file = read_json(path)?;
+    let Some(includes) = file
+        .get_mut("attributes")
+        .and_then(|attributes| attributes.as_object_mut()?.remove("$include"))
+    else {
+        return Ok(file);
+    };
+    let mut attributes = Map::new();
+    for include in strings(&includes).collect::<Vec<_>>() {
+        let included_path = roots
+            .iter()
+            .map(|root| root.join(&include))
+            .find(|path| path.exists())
+            .ok_or_else(|| Error::Schema {
+                path: path.to_path_buf(),
+                message: format!("cannot find the included {}", include),
+            })?;
+        let included = read_source(&included_path, roots)?;
+        let profile = (included["meta"] == "profile" || include.starts_with("profiles/"))
+            .then(|| included["name"].as_str())
+            .flatten();
+        for (attr_name, attr) in included["attributes"].as_object().into_iter().flatten() {
+            let mut attr = attr.clone();
+            if let Some(profile) = profile {
+                attr["profile"] = profile.into();
+            }
+            attributes.insert(attr_name.clone(), attr);
+        }
+        if let Some(profile) = profile {
+            let profiles = file
+                .as_object_mut()
+                .expect("read an object")
+                .entry("profiles")
+                .or_insert(json!([]));
+            if let Some(profiles) = profiles.as_array_mut() {
+                profiles.push(profile.into());
+            }
+        }
+    }
+    for (attr_name, overrides) in file["attributes"].as_object().into_iter().flatten() {
+        let attr = match attributes.remove(attr_name) {
+            Some(included) => {
+                let mut merged = included;
+                merged
+                    .as_object_mut()
+                    .map(|merged| merged.remove("profile"));
+                overrides
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .for_each(|(key, value)| merged[key] = value.clone());
+                merged
+            }
+            None => overrides.clone(),
+        };
+        attributes.insert(attr_name.clone(), attr);
+    }
+    file["attributes"] = Value::Object(attributes);
+    Ok(file)
+}
+
+/// A class's uid is `category uid * 1000 + uid`, and its `class_uid`, `category_uid` and
+/// `type_uid` enumerate the values it takes.
+/// The base event is in the `other` category, which has the uid 0.
+fn number_class(class: &mut Value, categories: &Value) -> Result<(), String> {
+    let name = class["name"].as_str().unwrap_or_default().to_string();
+    let category = class["category"].as_str().unwrap_or("other");
+    let (category_uid, category_caption) = match &categories[category] {
+        Value::Null if category == "other" => (0, "Other".to_string()),
+        Value::Null => return Err(format!("unknown category {} of {}", category, name)),
+        found => (
+            found["uid"].as_i64().unwrap_or_default(),
+            found["caption"].as_str().unwrap_or(category).to_string(),
+        ),
+    };
+    let uid = category_uid * 1000 + class["uid"].as_i64().unwrap_or_default();
+    class["uid"] = uid.into();
+    let caption = class["caption"].as_str().unwrap_or_default().to_string();
+    let attributes = &mut class["attributes"];
+    set_enum(
+        attributes,
+        "class_uid",
+        BTreeMap::from([(uid, caption.clone())]),
+    );
+    set_enum(
+        attributes,
+        "category_uid",
+        BTreeMap::from([(category_uid, category_caption)]),
+    );
+    let activities: BTreeMap<i64, String> = attributes["activity_id"]["enum"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(id, member)| {
+            let caption = format!("{}: {}", caption, member["caption"].as_str()?);
+            Some((uid * 100 + id.parse::<i64>().ok()?, caption))
+        })
+        .collect();
+    if !activities.is_empty() {
+        set_enum(attributes, "type_uid", activities);
+    }
+    Ok(())
+}
+
+fn set_enum(attributes: &mut Value, attr_name: &str, values: BTreeMap<i64, String>) {
+    if let Some(attr) = attributes.get_mut(attr_name) {
+        let values: Map<String, Value> = values
+            .into_iter()
+            .map(|(value, caption)| (value.to_string(), json!({ "caption": caption })))
+            .collect();
+        attr["enum"] = Value::Object(values);
+    }
+}
+
+fn strings(value: &Value) -> impl Iterator<Item = String> + '_ {
+    let values = match value {
+        Value::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+    values.iter().filter_map(Value::as_str).map(String::from)
+}
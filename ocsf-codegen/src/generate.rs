@@ -0,0 +1,1965 @@
+//! Rust code for the classes, objects and enums of a resolved schema
+use crate::model::{
+    AttributeDef, ClassDef, DeprecatedInfo, EnumMemberDef, OcsfSchema, constraint_path,
+};
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maps (struct key, attribute name) to the name of the generated enum
+type EnumNames = BTreeMap<(String, String), String>;
+
+/// What the builder configures, checked before generating
+pub(crate) struct Options {
+    /// The path of the ocsf-types runtime, `crate` within ocsf-types itself
+    pub(crate) runtime: String,
+    pub(crate) type_overrides: BTreeMap<String, TokenStream>,
+    pub(crate) derives: Vec<TokenStream>,
+    /// Whether categories, extensions and `preserve-unknown` are gated behind cargo features
+    pub(crate) features: bool,
+}
+
+struct Context<'a> {
+    schema: &'a OcsfSchema,
+    enum_names: EnumNames,
+    options: &'a Options,
+}
+
+impl Context<'_> {
+    /// The Rust type of an OCSF type, unless the builder overrides it
+    fn map_type(&self, t: &str) -> TokenStream {
+        match self.options.type_overrides.get(t) {
+            Some(rust_type) => rust_type.clone(),
+            None => map_ocsf_type(t),
+        }
+    }
+
+    /// Attributes of an overridden type are left out of format checks, path access and observables,
+    /// as the type need not implement the traits they use
+    fn is_overridden(&self, t: &str) -> bool {
+        self.options.type_overrides.contains_key(t)
+    }
+}
+
+/// The root module of the types, and the files of its submodules.
+/// Inline submodules are nested in the root instead, so that the types fit in a single file.
+pub(crate) fn generate(
+    schema: &OcsfSchema,
+    options: &Options,
+    inline: bool,
+) -> (TokenStream, Vec<(String, TokenStream)>) {
+    let version = &schema.version;
+
+    // Enums, objects and classes outside the categories and extensions
+    let mut common_code = Vec::new();
+    // Classes by category, and classes and objects by extension, each in a module behind the cargo
+    // feature of the same name
+    let mut feature_code: BTreeMap<&str, Vec<TokenStream>> = BTreeMap::new();
+
+    let (enum_names, enum_defs) = collect_enums(schema);
+    let cx = &Context {
+        schema,
+        enum_names,
+        options,
+    };
+    for (enum_name, attr) in &enum_defs {
+        common_code.push(generate_enum(enum_name, attr));
+    }
+
+    for (name, def) in &schema.classes {
+        let generated_code = match feature_of(def) {
+            Some(feature) => feature_code.entry(feature).or_default(),
+            None => &mut common_code,
+        };
+        generated_code.push(generate_struct(name, def, cx));
+        generated_code.push(generate_validate_impl(name, def, cx));
+        generated_code.push(generate_normalize_impl(name, def, cx));
+        generated_code.push(generate_class_impl(name, def, cx));
+        generated_code.push(generate_builder(name, def, cx));
+        generated_code.push(generate_known_fields_impl(name, def, cx));
+        generated_code.push(generate_path_impl(name, def, cx));
+        generated_code.push(generate_observables_impl(name, def, cx));
+    }
+
+    for (name, def) in &schema.objects {
+        let generated_code = match feature_of(def) {
+            Some(feature) => feature_code.entry(feature).or_default(),
+            None => &mut common_code,
+        };
+        generated_code.push(generate_struct(name, def, cx));
+        generated_code.push(generate_validate_impl(name, def, cx));
+        generated_code.push(generate_normalize_impl(name, def, cx));
+        generated_code.push(generate_builder(name, def, cx));
+        generated_code.push(generate_known_fields_impl(name, def, cx));
+        generated_code.push(generate_path_impl(name, def, cx));
+        generated_code.push(generate_observables_impl(name, def, cx));
+    }
+
+    let mut submodules = vec![(
+        "common".to_string(),
+        submodule(
+            "Objects and enums shared by every category, and the classes outside the categories",
+            &common_code,
+        ),
+    )];
+    let extensions: BTreeSet<&str> = schema
+        .classes
+        .values()
+        .chain(schema.objects.values())
+        .filter_map(|def| def.extension.as_deref())
+        .collect();
+    for (feature, code) in &feature_code {
+        let doc = match (extensions.contains(feature), options.features) {
+            (true, true) => format!(
+                "Classes and objects of the `{}` extension, enabled by the `{}` cargo feature",
+                feature, feature
+            ),
+            (true, false) => format!("Classes and objects of the `{}` extension", feature),
+            (false, true) => format!(
+                "Classes of the `{}` category, enabled by the `{}` cargo feature",
+                feature, feature
+            ),
+            (false, false) => format!("Classes of the `{}` category", feature),
+        };
+        submodules.push((feature.to_string(), submodule(&doc, code)));
+    }
+    let schema_tables = submodule(
+        "Static tables behind the `schema` module",
+        &[generate_schema_tables(schema, cx)],
+    );
+
+    let declare = |name: &str, code: &TokenStream| {
+        let ident = format_ident!("{}", name);
+        if inline {
+            quote! { mod #ident { #code } }
+        } else {
+            quote! { mod #ident; }
+        }
+    };
+    let modules = submodules.iter().map(|(name, code)| {
+        let ident = format_ident!("{}", name);
+        let module = declare(name, code);
+        let cfg = match feature_code.contains_key(name.as_str()) && options.features {
+            true => quote! { #[cfg(feature = #name)] },
+            false => quote! {},
+        };
+        quote! {
+            #cfg
+            pub #module
+            #cfg
+            pub use #ident::*;
+        }
+    });
+    let schema_tables_module = declare("schema_tables", &schema_tables);
+    let runtime: TokenStream = options
+        .runtime
+        .parse()
+        .expect("the runtime path is checked by the builder");
+    let schema_doc = format!(
+        " The classes and objects of this version, see [`{}::schema`]",
+        options.runtime
+    );
+    let event_enum = generate_event_enum(&schema.classes, cx);
+    let profile_traits = generate_profile_traits(schema, cx);
+    let visitors = generate_visitors(schema, cx);
+    let root = quote! {
+        #![allow(deprecated)]
+        #![allow(unused_imports)]
+        #![allow(clippy::only_used_in_recursion)]
+        use serde::{Serialize, Deserialize};
+        use serde_json::Value;
+        use #runtime::builder::{check_required, BuildError};
+        use #runtime::datetime::{DateTime, Timestamp};
+        use #runtime::format::StringFormat;
+        use #runtime::network::{IpAddress, MacAddress, Port, Subnet};
+        use #runtime::class::{OcsfClass, RawEvent};
+        use #runtime::normalize::{fill_sibling, CaptionConflict, Normalize};
+        use #runtime::observable::{FoundObservable, ObservableSet, Observables};
+        use #runtime::path::{AsFieldRef, FieldRef, FieldValue, FromFieldValue, PathAccess, PathError, Segment};
+        use #runtime::schema::{AttributeInfo, ClassInfo, Deprecation, EventSchema, ObjectInfo, Requirement, Schema};
+        use #runtime::strict::{find_unknown_in_array, unknown_field, KnownFields};
+        use #runtime::validation::{attr_path, index_path, ConstraintRule, Validate, ValidationReport};
+
+        /// The OCSF version the types were generated from
+        pub const SCHEMA_VERSION: &str = #version;
+
+        #(#modules)*
+        #schema_tables_module
+        #[doc = #schema_doc]
+        pub static SCHEMA: Schema = Schema {
+            version: SCHEMA_VERSION,
+            classes: schema_tables::SCHEMA_CLASSES,
+            objects: schema_tables::SCHEMA_OBJECTS,
+        };
+
+        #event_enum
+        #profile_traits
+        #visitors
+    };
+    let files = match inline {
+        true => Vec::new(),
+        false => submodules
+            .into_iter()
+            .chain([("schema_tables".to_string(), schema_tables)])
+            .collect(),
+    };
+    (root, files)
+}
+
+fn submodule(doc: &str, code: &[TokenStream]) -> TokenStream {
+    quote! {
+        #![doc = #doc]
+        #![allow(deprecated)]
+        #![allow(unused_imports)]
+        #![allow(clippy::only_used_in_recursion)]
+        use super::*;
+        #(#code)*
+    }
+}
+
+/// Classes with a `class_uid` go into the module and cargo feature named after their category,
+/// e.g. `iam`.
+/// Classes of the `other` category, such as the base event, are always compiled, like the objects.
+fn category_module(def: &ClassDef) -> Option<&str> {
+    (def.uid.is_some() && !def.category.is_empty() && def.category != "other")
+        .then_some(def.category.as_str())
+}
+
+/// Classes and objects of an extension go into the module and cargo feature named after it, e.g.
+/// `win`, whatever their category
+fn feature_of(def: &ClassDef) -> Option<&str> {
+    def.extension.as_deref().or_else(|| category_module(def))
+}
+
+fn class_cfg(cx: &Context, def: &ClassDef) -> TokenStream {
+    match feature_of(def).filter(|_| cx.options.features) {
+        Some(feature) => quote! { #[cfg(feature = #feature)] },
+        None => quote! {},
+    }
+}
+
+/// The extension that adds `attr` to `def`, when `def` is not part of that extension
+fn foreign_extension<'a>(def: &ClassDef, attr: &'a AttributeDef) -> Option<&'a str> {
+    attr.extension
+        .as_deref()
+        .filter(|extension| def.extension.as_deref() != Some(*extension))
+}
+
+/// Attributes that an extension adds to a class or object outside of it are compiled with the
+/// extension's feature
+fn attr_cfg(cx: &Context, def: &ClassDef, attr: &AttributeDef) -> TokenStream {
+    match foreign_extension(def, attr).filter(|_| cx.options.features) {
+        Some(extension) => quote! { #[cfg(feature = #extension)] },
+        None => quote! {},
+    }
+}
+
+fn generate_class_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let class_uid = Literal::i64_unsuffixed(def.uid.unwrap_or_default());
+    let (category_uid, category_caption) = category_of(def);
+    let category_uid = Literal::i64_unsuffixed(category_uid);
+    let class_name = &def.name;
+    let category_name = &def.category;
+    let caption = &def.caption;
+    let constants = [
+        ("class_uid", quote! { Self::CLASS_UID }),
+        ("category_uid", quote! { Self::CATEGORY_UID }),
+        ("class_name", quote! { Self::CAPTION.to_string() }),
+        (
+            "category_name",
+            quote! { Self::CATEGORY_CAPTION.to_string() },
+        ),
+    ];
+    let initializers = constants
+        .iter()
+        .filter(|(attr_name, _)| def.attributes.contains_key(*attr_name))
+        .map(|(attr_name, value)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            quote! { #field_ident: Some(#value) }
+        });
+    let type_methods = generate_type_methods(name, def, cx);
+    quote! {
+        impl #struct_name {
+            /// Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`
+            /// set for this class
+            pub fn new_event() -> Self {
+                Self {
+                    #(#initializers,)*
+                    ..Default::default()
+                }
+            }
+            #type_methods
+        }
+        impl OcsfClass for #struct_name {
+            const CLASS_UID: i64 = #class_uid;
+            const CATEGORY_UID: i64 = #category_uid;
+            const CLASS_NAME: &'static str = #class_name;
+            const CATEGORY_NAME: &'static str = #category_name;
+            const CAPTION: &'static str = #caption;
+            const CATEGORY_CAPTION: &'static str = #category_caption;
+        }
+    }
+}
+
+/// Fills captions from their sibling `*_id` using the enum captions, and recurses into nested
+/// objects
+fn generate_normalize_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let steps: Vec<TokenStream> = def
+        .attributes
+        .iter()
+        .filter_map(|(attr_name, attr)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let cfg = attr_cfg(cx, def, attr);
+            let sibling = attr.sibling.as_ref().filter(|sibling| {
+                !attr.is_array
+                    && matches!(attr.type_name.as_str(), "integer_t" | "integer" | "long_t")
+                    && attr.enum_values.as_ref().is_some_and(|e| !e.is_empty())
+                    && def
+                        .attributes
+                        .get(*sibling)
+                        .is_some_and(|s| !s.is_array && s.type_name == "string_t")
+            });
+            if let Some(sibling) = sibling {
+                let sibling_ident = format_ident!("{}", sanitize_name(sibling));
+                let sibling_cfg = attr_cfg(cx, def, &def.attributes[sibling]);
+                let (id, caption) = if cx.enum_names.contains_key(&(name.to_string(), attr_name.clone())) {
+                    (
+                        quote! { self.#field_ident.map(i64::from) },
+                        quote! { self.#field_ident.and_then(|id| id.caption()) },
+                    )
+                } else {
+                    let arms = sorted_enum_values(attr).into_iter().map(|(value, member)| {
+                        let value = Literal::i64_unsuffixed(value);
+                        let caption = &member.caption;
+                        quote! { Some(#value) => Some(#caption) }
+                    });
+                    (
+                        quote! { self.#field_ident },
+                        quote! {
+                            match self.#field_ident {
+                                #(#arms,)*
+                                _ => None,
+                            }
+                        },
+                    )
+                };
+                return Some(quote! {
+                    #cfg
+                    #sibling_cfg
+                    fill_sibling(conflicts, path, #sibling, #id, #caption, &mut self.#sibling_ident);
+                });
+            }
+            if is_primitive_type(&attr.type_name) {
+                None
+            } else if attr.is_array {
+                Some(quote! {
+                    #cfg
+                    if let Some(items) = &mut self.#field_ident {
+                        let path = attr_path(path, #attr_name);
+                        for (i, item) in items.iter_mut().enumerate() {
+                            item.normalize_at(&index_path(&path, i), conflicts);
+                        }
+                    }
+                })
+            } else {
+                Some(quote! {
+                    #cfg
+                    if let Some(value) = &mut self.#field_ident {
+                        value.normalize_at(&attr_path(path, #attr_name), conflicts);
+                    }
+                })
+            }
+        })
+        .collect();
+
+    if steps.is_empty() {
+        return quote! {
+            impl Normalize for #struct_name {
+                fn normalize_at(&mut self, _path: &str, _conflicts: &mut Vec<CaptionConflict>) {}
+            }
+        };
+    }
+    quote! {
+        impl Normalize for #struct_name {
+            fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+                #(#steps)*
+            }
+        }
+    }
+}
+
+/// Setters take plain values and wrap them in `Option`/`Box`. Event class builders start from
+/// `new_event()`.
+fn generate_builder(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let builder_name = format_ident!("{}Builder", name.to_pascal_case());
+    let type_name = struct_name.to_string();
+
+    let setters = def.attributes.iter().map(|(attr_name, attr)| {
+        let field_ident = format_ident!("{}", sanitize_name(attr_name));
+        // `build` would shadow the method finishing the builder
+        let setter_ident = match sanitize_name(attr_name).as_str() {
+            "build" | "build_unchecked" => format_ident!("set_{}", sanitize_name(attr_name)),
+            _ => field_ident.clone(),
+        };
+        let enum_name = cx
+            .enum_names
+            .get(&(name.to_string(), attr_name.to_string()));
+        let raw_type = match enum_name {
+            Some(enum_name) => {
+                let enum_ident = format_ident!("{}", enum_name);
+                quote! { #enum_ident }
+            }
+            None => cx.map_type(&attr.type_name),
+        };
+        let is_primitive = enum_name.is_some() || is_primitive_type(&attr.type_name);
+        let (param_type, value) = if attr.is_array {
+            (quote! { Vec<#raw_type> }, quote! { value })
+        } else if !is_primitive {
+            (quote! { #raw_type }, quote! { Box::new(value) })
+        } else if enum_name.is_some()
+            || is_string_type(&attr.type_name)
+            || matches!(
+                attr.type_name.as_str(),
+                "timestamp_t" | "datetime_t" | "json_t" | "object_t" | "object"
+            )
+            || is_format_type(&attr.type_name)
+            || cx.is_overridden(&attr.type_name)
+        {
+            (quote! { impl Into<#raw_type> }, quote! { value.into() })
+        } else {
+            (raw_type, quote! { value })
+        };
+        let doc = format!("{} ({})", attr.caption, requirement_doc(attr));
+        let cfg = attr_cfg(cx, def, attr);
+        quote! {
+            #[doc = #doc]
+            #cfg
+            pub fn #setter_ident(mut self, value: #param_type) -> Self {
+                self.inner.#field_ident = Some(#value);
+                self
+            }
+        }
+    });
+
+    let (required, extension_required): (Vec<_>, Vec<_>) = def
+        .attributes
+        .iter()
+        .filter(|(_, attr)| attr.requirement == "required")
+        .partition(|(_, attr)| foreign_extension(def, attr).is_none());
+    let required = required.into_iter().map(|(attr_name, _)| {
+        let field_ident = format_ident!("{}", sanitize_name(attr_name));
+        quote! { (#attr_name, self.inner.#field_ident.is_some()) }
+    });
+    // Required attributes of an extension are only checked with its feature
+    let check_required = if extension_required.is_empty() {
+        quote! { check_required(#type_name, &[#(#required),*])?; }
+    } else {
+        let extension_required = extension_required.into_iter().map(|(attr_name, attr)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let cfg = attr_cfg(cx, def, attr);
+            quote! {
+                #cfg
+                let required = [&required[..], &[(#attr_name, self.inner.#field_ident.is_some())][..]].concat();
+            }
+        });
+        quote! {
+            let required = [#(#required),*];
+            #(#extension_required)*
+            check_required(#type_name, &required)?;
+        }
+    };
+    let (start, fill_type) = if def.uid.is_some() {
+        let fill_type = has_type_attributes(def).then(|| {
+            quote! {
+                if self.inner.type_uid.is_none() {
+                    self.inner.type_uid = self.inner.derived_type_uid();
+                }
+                if self.inner.type_name.is_none() {
+                    self.inner.type_name = self.inner.derived_type_name();
+                }
+            }
+        });
+        (quote! { #struct_name::new_event() }, fill_type)
+    } else {
+        (quote! { #struct_name::default() }, None)
+    };
+    let builder_doc = format!("Builder for [`{}`]", struct_name);
+    let build_self = if fill_type.is_some() {
+        quote! { mut self }
+    } else {
+        quote! { self }
+    };
+    let build_doc = if fill_type.is_some() {
+        "Fills `type_uid` and `type_name` from `activity_id` when they are not set, \
+         then checks that every required attribute is set"
+    } else {
+        "Checks that every required attribute is set"
+    };
+    quote! {
+        impl #struct_name {
+            pub fn builder() -> #builder_name {
+                #builder_name { inner: #start }
+            }
+        }
+        #[doc = #builder_doc]
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct #builder_name {
+            inner: #struct_name,
+        }
+        impl #builder_name {
+            #(#setters)*
+            #[doc = #build_doc]
+            pub fn build(#build_self) -> Result<#struct_name, BuildError> {
+                #fill_type
+                #check_required
+                Ok(self.inner)
+            }
+            /// Returns the value without checking required attributes
+            pub fn build_unchecked(self) -> #struct_name {
+                self.inner
+            }
+        }
+    }
+}
+
+/// Walks a JSON value alongside the schema, collecting the paths of keys it does not define
+fn generate_known_fields_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let mut leaves = Vec::new();
+    let mut nested = Vec::new();
+    for (attr_name, attr) in &def.attributes {
+        let cfg = attr_cfg(cx, def, attr);
+        if is_primitive_type(&attr.type_name) && foreign_extension(def, attr).is_none() {
+            leaves.push(attr_name);
+            continue;
+        }
+        let type_ident = map_ocsf_type(&attr.type_name);
+        nested.push(if is_primitive_type(&attr.type_name) {
+            quote! { #cfg #attr_name => {} }
+        } else if attr.is_array {
+            quote! {
+                #cfg
+                #attr_name => find_unknown_in_array::<#type_ident>(value, &attr_path(path, key), unknown)
+            }
+        } else {
+            quote! {
+                #cfg
+                #attr_name => #type_ident::find_unknown_fields(value, &attr_path(path, key), unknown)
+            }
+        });
+    }
+    let leaves = (!leaves.is_empty()).then(|| quote! { #(#leaves)|* => {} });
+    let body = if nested.is_empty() && leaves.is_none() {
+        quote! {
+            for key in object.keys() {
+                unknown_field(path, key, unknown);
+            }
+        }
+    } else if nested.is_empty() {
+        quote! {
+            for key in object.keys() {
+                match key.as_str() {
+                    #leaves
+                    _ => unknown_field(path, key, unknown),
+                }
+            }
+        }
+    } else {
+        quote! {
+            for (key, value) in object {
+                match key.as_str() {
+                    #(#nested,)*
+                    #leaves
+                    _ => unknown_field(path, key, unknown),
+                }
+            }
+        }
+    };
+    quote! {
+        impl KnownFields for #struct_name {
+            fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+                let Some(object) = value.as_object() else {
+                    return;
+                };
+                #body
+            }
+        }
+    }
+}
+
+fn generate_path_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    if def.attributes.is_empty() {
+        return quote! {
+            impl PathAccess for #struct_name {
+                fn get_path(&self, _path: &str) -> Option<FieldRef<'_>> {
+                    None
+                }
+                fn set_path(&mut self, path: &str, _value: FieldValue) -> Result<(), PathError> {
+                    Err(Segment::parse_mut(path)?.unknown())
+                }
+            }
+        };
+    }
+    let (get_arms, set_arms): (Vec<_>, Vec<_>) = def
+        .attributes
+        .iter()
+        .filter(|(_, attr)| !cx.is_overridden(&attr.type_name))
+        .map(|(attr_name, attr)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let (get, set) = match (is_primitive_type(&attr.type_name), attr.is_array) {
+                (true, false) => (
+                    quote! { segment.leaf(self.#field_ident.as_ref()) },
+                    quote! { segment.set_leaf(&mut self.#field_ident, value) },
+                ),
+                (true, true) => (
+                    quote! { segment.leaf_in(self.#field_ident.as_deref()) },
+                    quote! { segment.set_leaf_in(&mut self.#field_ident, value) },
+                ),
+                (false, false) => (
+                    quote! { segment.object(self.#field_ident.as_deref()) },
+                    quote! { segment.set_object(&mut self.#field_ident, value) },
+                ),
+                (false, true) => (
+                    quote! { segment.object_in(self.#field_ident.as_deref()) },
+                    quote! { segment.set_object_in(&mut self.#field_ident, value) },
+                ),
+            };
+            let cfg = attr_cfg(cx, def, attr);
+            (
+                quote! { #cfg #attr_name => #get },
+                quote! { #cfg #attr_name => #set },
+            )
+        })
+        .unzip();
+    quote! {
+        impl PathAccess for #struct_name {
+            fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+                let segment = Segment::parse(path)?;
+                match segment.name {
+                    #(#get_arms,)*
+                    _ => None,
+                }
+            }
+            fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+                let segment = Segment::parse_mut(path)?;
+                match segment.name {
+                    #(#set_arms,)*
+                    _ => Err(segment.unknown()),
+                }
+            }
+        }
+    }
+}
+
+/// The observable type id of an attribute, from its own annotation or else from its type in the
+/// dictionary
+fn observable_type_id(schema: &OcsfSchema, attr: &AttributeDef) -> Option<i64> {
+    attr.observable.or_else(|| {
+        schema
+            .types
+            .get(&attr.type_name)
+            .and_then(|def| def.observable)
+    })
+}
+
+/// Adds the observable attributes and recurses into nested objects. The `observables` attribute
+/// itself is skipped.
+fn generate_observables_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    if !has_observable_object(cx.schema) {
+        return quote! {};
+    }
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let steps: Vec<TokenStream> = def
+        .attributes
+        .iter()
+        .filter(|(attr_name, _)| *attr_name != "observables")
+        .filter_map(|(attr_name, attr)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let cfg = attr_cfg(cx, def, attr);
+            if !is_primitive_type(&attr.type_name) {
+                let nested = if attr.is_array {
+                    quote! {
+                        #cfg
+                        for value in self.#field_ident.iter().flatten() {
+                            value.collect_observables(&attr_path(path, #attr_name), found);
+                        }
+                    }
+                } else {
+                    quote! {
+                        #cfg
+                        if let Some(value) = &self.#field_ident {
+                            value.collect_observables(&attr_path(path, #attr_name), found);
+                        }
+                    }
+                };
+                return Some(nested);
+            }
+            let key = (name.to_string(), attr_name.to_string());
+            if cx.enum_names.contains_key(&key) || cx.is_overridden(&attr.type_name) {
+                return None;
+            }
+            let type_id = Literal::i64_unsuffixed(observable_type_id(cx.schema, attr)?);
+            Some(if attr.is_array {
+                quote! { #cfg found.add_all(path, #attr_name, #type_id, self.#field_ident.as_deref()); }
+            } else {
+                quote! { #cfg found.add(path, #attr_name, #type_id, self.#field_ident.as_ref()); }
+            })
+        })
+        .collect();
+    let (path, found) = if steps.is_empty() {
+        (quote! { _path }, quote! { _found })
+    } else {
+        (quote! { path }, quote! { found })
+    };
+    let from_found = (name == "observable").then(generate_from_found);
+    quote! {
+        impl Observables for #struct_name {
+            type Observable = Observable;
+
+            fn collect_observables(&self, #path: &str, #found: &mut ObservableSet) {
+                #(#steps)*
+            }
+        }
+        #from_found
+    }
+}
+
+/// Observables are only collected into a schema's own `observable` object, a schema without one
+/// gets no `Observables` impls
+fn has_observable_object(schema: &OcsfSchema) -> bool {
+    schema.objects.contains_key("observable")
+}
+
+/// Builds the `observable` object from what the `Observables` impls find
+fn generate_from_found() -> TokenStream {
+    quote! {
+        #[allow(clippy::useless_conversion)]
+        impl From<FoundObservable> for Observable {
+            fn from(found: FoundObservable) -> Self {
+                Observable {
+                    name: Some(found.name.into()),
+                    type_id: Some(found.type_id.into()),
+                    value: Some(found.value.into()),
+                    ..Default::default()
+                }
+            }
+        }
+    }
+}
+
+/// "required", or "required (`cloud` profile)" for attributes a profile adds
+fn requirement_doc(attr: &AttributeDef) -> String {
+    match &attr.profile {
+        Some(profile) => format!("{} (`{}` profile)", attr.requirement, profile),
+        None => attr.requirement.clone(),
+    }
+}
+
+/// The Rust type of an attribute, without its `Option`, `Box` or `Vec`
+fn rust_type(name: &str, attr_name: &str, attr: &AttributeDef, cx: &Context) -> TokenStream {
+    match cx
+        .enum_names
+        .get(&(name.to_string(), attr_name.to_string()))
+    {
+        Some(enum_name) => {
+            let enum_ident = format_ident!("{}", enum_name);
+            quote! { #enum_ident }
+        }
+        None => cx.map_type(&attr.type_name),
+    }
+}
+
+/// A trait per profile with accessors for the attributes it adds, implemented by every class that
+/// applies it.
+/// Attributes that some of these classes lack, or have with another type, are left out.
+fn generate_profile_traits(schema: &OcsfSchema, cx: &Context) -> TokenStream {
+    let mut profiles: BTreeMap<&str, (BTreeSet<&str>, Vec<&str>)> = BTreeMap::new();
+    for (name, def) in &schema.classes {
+        for profile in def.profiles.iter().flatten() {
+            profiles.entry(profile).or_default().1.push(name);
+        }
+        for (attr_name, attr) in &def.attributes {
+            if let Some(profile) = attr
+                .profile
+                .as_ref()
+                .filter(|_| foreign_extension(def, attr).is_none())
+            {
+                profiles.entry(profile).or_default().0.insert(attr_name);
+            }
+        }
+    }
+    let mut traits = Vec::new();
+    for (profile, (attr_names, classes)) in profiles {
+        let members: Vec<(&str, &AttributeDef, TokenStream)> = attr_names
+            .into_iter()
+            .filter_map(|attr_name| {
+                let mut types = classes.iter().map(|class| {
+                    let attr = schema.classes[*class].attributes.get(attr_name)?;
+                    Some((attr, rust_type(class, attr_name, attr, cx)))
+                });
+                let (attr, raw_type) = types.next()??;
+                types
+                    .all(|other| {
+                        other.is_some_and(|(other, other_type)| {
+                            other.is_array == attr.is_array
+                                && other_type.to_string() == raw_type.to_string()
+                        })
+                    })
+                    .then_some((attr_name, attr, raw_type))
+            })
+            .collect();
+        if classes.is_empty() || members.is_empty() {
+            continue;
+        }
+        let trait_ident = format_ident!("{}Profile", profile.to_pascal_case());
+        let trait_doc = format!(
+            "Attributes of the `{}` profile, implemented by every class that applies it",
+            profile
+        );
+        let (methods, impls): (Vec<_>, Vec<_>) = members
+            .iter()
+            .map(|(attr_name, attr, raw_type)| {
+                let field_ident = format_ident!("{}", sanitize_name(attr_name));
+                let mut_ident = format_ident!("{}_mut", attr_name.to_snake_case());
+                let is_object = !is_primitive_type(&attr.type_name);
+                let (get_type, get) = if attr.is_array {
+                    (
+                        quote! { Option<&[#raw_type]> },
+                        quote! { self.#field_ident.as_deref() },
+                    )
+                } else if is_object || raw_type.to_string() == "String" {
+                    let raw_type = if is_object {
+                        raw_type.clone()
+                    } else {
+                        quote! { str }
+                    };
+                    (
+                        quote! { Option<&#raw_type> },
+                        quote! { self.#field_ident.as_deref() },
+                    )
+                } else {
+                    (
+                        quote! { Option<&#raw_type> },
+                        quote! { self.#field_ident.as_ref() },
+                    )
+                };
+                let field_type = if attr.is_array {
+                    quote! { Vec<#raw_type> }
+                } else if is_object {
+                    quote! { Box<#raw_type> }
+                } else {
+                    raw_type.clone()
+                };
+                let doc = &attr.caption;
+                (
+                    quote! {
+                        #[doc = #doc]
+                        fn #field_ident(&self) -> #get_type;
+                        fn #mut_ident(&mut self) -> &mut Option<#field_type>;
+                    },
+                    quote! {
+                        fn #field_ident(&self) -> #get_type {
+                            #get
+                        }
+                        fn #mut_ident(&mut self) -> &mut Option<#field_type> {
+                            &mut self.#field_ident
+                        }
+                    },
+                )
+            })
+            .unzip();
+        let class_impls = classes.iter().map(|class| {
+            let class_ident = format_ident!("{}", class.to_pascal_case());
+            let cfg = class_cfg(cx, &schema.classes[*class]);
+            quote! {
+                #cfg
+                impl #trait_ident for #class_ident {
+                    #(#impls)*
+                }
+            }
+        });
+        traits.push(quote! {
+            #[doc = #trait_doc]
+            pub trait #trait_ident {
+                /// The profile name, as listed in `metadata.profiles`
+                const PROFILE: &'static str = #profile;
+                #(#methods)*
+            }
+            #(#class_impls)*
+        });
+    }
+    quote! { #(#traits)* }
+}
+
+fn has_type_attributes(def: &ClassDef) -> bool {
+    def.uid.is_some()
+        && ["activity_id", "type_uid", "type_name"]
+            .iter()
+            .all(|attr_name| def.attributes.contains_key(*attr_name))
+}
+
+/// `type_uid` is `class_uid * 100 + activity_id`, `type_name` is "<class caption>: <activity
+/// caption>"
+fn generate_type_methods(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    if !has_type_attributes(def) {
+        return quote! {};
+    }
+    let activity_enum = cx
+        .enum_names
+        .contains_key(&(name.to_string(), "activity_id".to_string()));
+    let activity_id = if activity_enum {
+        quote! { i64::from(activity_id) }
+    } else {
+        quote! { activity_id }
+    };
+    let type_name = if activity_enum {
+        quote! {
+            self.activity_id
+                .and_then(|activity_id| activity_id.caption())
+                .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+        }
+    } else {
+        // the schema has no activity captions for this class
+        quote! { None }
+    };
+    quote! {
+        /// `class_uid * 100 + activity_id`, or `None` without an `activity_id`
+        pub fn derived_type_uid(&self) -> Option<i64> {
+            self.activity_id.map(|activity_id| Self::CLASS_UID * 100 + #activity_id)
+        }
+        /// The class caption and the `activity_id` caption, e.g. `Account Change: Create`.
+        /// `None` without an `activity_id` or when the schema has no caption for it.
+        pub fn derived_type_name(&self) -> Option<String> {
+            #type_name
+        }
+        /// Sets `type_uid` and `type_name` to the values derived from `activity_id`
+        pub fn fill_type(&mut self) {
+            self.type_uid = self.derived_type_uid();
+            self.type_name = self.derived_type_name();
+        }
+    }
+}
+
+/// The `category_uid` attribute of a class enumerates its single category.
+/// Falls back to the uid layout (`[extension]category_uid * 1000 + class`) and the category name.
+pub(crate) fn category_of(def: &ClassDef) -> (i64, String) {
+    let category = def
+        .attributes
+        .get("category_uid")
+        .and_then(|attr| attr.enum_values.as_ref())
+        .and_then(|values| values.iter().next())
+        .and_then(|(uid, member)| Some((uid.parse().ok()?, member.caption.clone())));
+    category.unwrap_or_else(|| {
+        let uid = def.uid.unwrap_or_default();
+        ((uid / 1000) % 100, def.category.clone())
+    })
+}
+
+/// One variant per class, picked by `class_uid` when deserializing
+fn generate_event_enum(classes: &BTreeMap<String, ClassDef>, cx: &Context) -> TokenStream {
+    let classes: Vec<(proc_macro2::Ident, i64, TokenStream)> = classes
+        .iter()
+        .filter_map(|(name, def)| {
+            def.uid.map(|uid| {
+                (
+                    format_ident!("{}", name.to_pascal_case()),
+                    uid,
+                    class_cfg(cx, def),
+                )
+            })
+        })
+        .collect();
+
+    let variants = classes.iter().map(|(ident, uid, cfg)| {
+        let doc = format!("`class_uid` {}", uid);
+        quote! {
+            #[doc = #doc]
+            #cfg
+            #ident(Box<#ident>)
+        }
+    });
+    let deserialize_arms = classes.iter().map(|(ident, uid, cfg)| {
+        let uid = Literal::i64_unsuffixed(*uid);
+        quote! {
+            #cfg
+            Some(#uid) => serde_json::from_str(event.raw.get()).map(|e| OcsfEvent::#ident(Box::new(e)))
+        }
+    });
+    let class_uid_arms = classes.iter().map(|(ident, uid, cfg)| {
+        let uid = Literal::i64_unsuffixed(*uid);
+        quote! { #cfg OcsfEvent::#ident(_) => Some(#uid) }
+    });
+    let known_fields_arms = classes.iter().map(|(ident, uid, cfg)| {
+        let uid = Literal::i64_unsuffixed(*uid);
+        quote! { #cfg Some(#uid) => #ident::find_unknown_fields(value, path, unknown) }
+    });
+    let get_path_arms = classes.iter().map(|(ident, _, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => event.get_path(path) }
+    });
+    let set_path_arms = classes.iter().map(|(ident, _, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => event.set_path(path, value) }
+    });
+    let observables_arms = classes.iter().map(|(ident, _, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => event.collect_observables(path, found) }
+    });
+    let observables_impl = has_observable_object(cx.schema).then(|| {
+        quote! {
+            /// Unknown events have no observables
+            impl Observables for OcsfEvent {
+                type Observable = Observable;
+
+                fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+                    match self {
+                        #(#observables_arms,)*
+                        OcsfEvent::Unknown(_) => {}
+                    }
+                }
+            }
+        }
+    });
+    let validate_arms = classes.iter().map(|(ident, _, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => event.validate_at(path, report) }
+    });
+    quote! {
+        /// Any OCSF event, with the class chosen by its `class_uid` when deserializing.
+        ///
+        /// Events without a `class_uid`, or with one that is not part of the schema, are kept as
+        /// [`OcsfEvent::Unknown`].
+        /// So are events of a category or extension whose cargo feature is disabled.
+        #[derive(Debug, Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[non_exhaustive]
+        pub enum OcsfEvent {
+            #(#variants,)*
+            /// An event of a class that is not part of the schema
+            Unknown(Value),
+        }
+        impl OcsfEvent {
+            /// The `class_uid` of the variant, or the raw `class_uid` of an unknown event
+            pub fn class_uid(&self) -> Option<i64> {
+                match self {
+                    #(#class_uid_arms,)*
+                    OcsfEvent::Unknown(value) => value.get("class_uid").and_then(Value::as_i64),
+                }
+            }
+        }
+        impl<'de> Deserialize<'de> for OcsfEvent {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                // The event is parsed once into the struct of its class, only unknown ones become a `Value`
+                let event = RawEvent::deserialize(deserializer)?;
+                let event = match event.class_uid {
+                    #(#deserialize_arms,)*
+                    _ => serde_json::from_str(event.raw.get()).map(OcsfEvent::Unknown),
+                };
+                event.map_err(serde::de::Error::custom)
+            }
+        }
+        /// Unknown events have no attributes
+        impl PathAccess for OcsfEvent {
+            fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+                match self {
+                    #(#get_path_arms,)*
+                    OcsfEvent::Unknown(_) => None,
+                }
+            }
+            fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+                match self {
+                    #(#set_path_arms,)*
+                    OcsfEvent::Unknown(_) => Err(Segment::parse_mut(path)?.unknown()),
+                }
+            }
+        }
+        #observables_impl
+        impl EventSchema for OcsfEvent {
+            fn schema() -> &'static Schema {
+                &SCHEMA
+            }
+        }
+        /// Unknown events are not validated
+        impl Validate for OcsfEvent {
+            fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+                match self {
+                    #(#validate_arms,)*
+                    OcsfEvent::Unknown(_) => {}
+                }
+            }
+        }
+        impl KnownFields for OcsfEvent {
+            fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+                match value.get("class_uid").and_then(Value::as_i64) {
+                    #(#known_fields_arms,)*
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+const VISIT_DOC: &str = r##"Traversal of every nested object of an event.
+
+`Visit` and `VisitMut` have a hook per class and object, such as `visit_user` or `visit_endpoint_mut`.
+The default hooks call the matching `walk_*` function, which visits every object nested in the value.
+Overriding a hook and calling `walk_*` from it keeps the traversal going."##;
+
+/// Only ocsf-types itself has the classes the example uses
+const VISIT_EXAMPLE: &str = r##"
+```rust
+# #[cfg(feature = "system")]
+# {
+use ocsf_types::visit::{self, VisitMut};
+use ocsf_types::User;
+
+struct Redact;
+
+impl VisitMut for Redact {
+    fn visit_user_mut(&mut self, user: &mut User) {
+        user.email_addr = None;
+        visit::walk_user_mut(self, user);
+    }
+}
+
+let mut event: ocsf_types::FileActivity = serde_json::from_str(
+    r#"{"actor": {"user": {"email_addr": "jdoe@example.com"}}, "file": {"owner": {"email_addr": "a@example.com"}}}"#,
+).unwrap();
+Redact.visit_file_activity_mut(&mut event);
+assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"actor":{"user":{}},"file":{"owner":{}}}"#);
+# }
+```"##;
+
+/// `Visit` and `VisitMut` with a hook and a `walk_*` function per struct, in a `visit` module
+fn generate_visitors(schema: &OcsfSchema, cx: &Context) -> TokenStream {
+    let mut hooks = Vec::new();
+    let mut hooks_mut = Vec::new();
+    let mut walks = Vec::new();
+    for (name, def) in schema.classes.iter().chain(schema.objects.iter()) {
+        let struct_name = format_ident!("{}", name.to_pascal_case());
+        let cfg = class_cfg(cx, def);
+        let snake = name.to_pascal_case().to_snake_case();
+        let (visit, visit_mut) = (
+            format_ident!("visit_{}", snake),
+            format_ident!("visit_{}_mut", snake),
+        );
+        let (walk, walk_mut) = (
+            format_ident!("walk_{}", snake),
+            format_ident!("walk_{}_mut", snake),
+        );
+        let (mut nested, mut nested_mut) = (Vec::new(), Vec::new());
+        for (attr_name, attr) in &def.attributes {
+            if is_primitive_type(&attr.type_name) {
+                continue;
+            }
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let attr_cfg = attr_cfg(cx, def, attr);
+            let snake = attr.type_name.to_pascal_case().to_snake_case();
+            let (visit, visit_mut) = (
+                format_ident!("visit_{}", snake),
+                format_ident!("visit_{}_mut", snake),
+            );
+            if attr.is_array {
+                nested.push(quote! {
+                    #attr_cfg
+                    for value in node.#field_ident.iter().flatten() {
+                        visitor.#visit(value);
+                    }
+                });
+                nested_mut.push(quote! {
+                    #attr_cfg
+                    for value in node.#field_ident.iter_mut().flatten() {
+                        visitor.#visit_mut(value);
+                    }
+                });
+            } else {
+                nested.push(quote! {
+                    #attr_cfg
+                    if let Some(value) = &node.#field_ident {
+                        visitor.#visit(value);
+                    }
+                });
+                nested_mut.push(quote! {
+                    #attr_cfg
+                    if let Some(value) = &mut node.#field_ident {
+                        visitor.#visit_mut(value);
+                    }
+                });
+            }
+        }
+        let (visitor, node) = if nested.is_empty() {
+            (quote! { _visitor }, quote! { _node })
+        } else {
+            (quote! { visitor }, quote! { node })
+        };
+        hooks.push(quote! {
+            #cfg
+            fn #visit(&mut self, node: &#struct_name) {
+                #walk(self, node)
+            }
+        });
+        hooks_mut.push(quote! {
+            #cfg
+            fn #visit_mut(&mut self, node: &mut #struct_name) {
+                #walk_mut(self, node)
+            }
+        });
+        walks.push(quote! {
+            #cfg
+            pub fn #walk<V: Visit + ?Sized>(#visitor: &mut V, #node: &#struct_name) {
+                #(#nested)*
+            }
+            #cfg
+            pub fn #walk_mut<V: VisitMut + ?Sized>(#visitor: &mut V, #node: &mut #struct_name) {
+                #(#nested_mut)*
+            }
+        });
+    }
+    let classes: Vec<_> = schema
+        .classes
+        .iter()
+        .filter(|(_, def)| def.uid.is_some())
+        .map(|(name, def)| {
+            let snake = name.to_pascal_case().to_snake_case();
+            (
+                format_ident!("{}", name.to_pascal_case()),
+                format_ident!("visit_{}", snake),
+                format_ident!("visit_{}_mut", snake),
+                class_cfg(cx, def),
+            )
+        })
+        .collect();
+    let event_arms = classes.iter().map(|(ident, visit, _, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => visitor.#visit(event) }
+    });
+    let event_arms_mut = classes.iter().map(|(ident, _, visit_mut, cfg)| {
+        quote! { #cfg OcsfEvent::#ident(event) => visitor.#visit_mut(event) }
+    });
+    let doc = match cx.options.runtime.as_str() {
+        "crate" => format!("{}{}", VISIT_DOC, VISIT_EXAMPLE),
+        _ => VISIT_DOC.to_string(),
+    };
+    quote! {
+        #[doc = #doc]
+        pub mod visit {
+            use super::*;
+
+            /// Hooks for every class and object, called with shared references
+            pub trait Visit {
+                fn visit_event(&mut self, event: &OcsfEvent) {
+                    walk_event(self, event)
+                }
+                #(#hooks)*
+            }
+
+            /// Hooks for every class and object, called with mutable references
+            pub trait VisitMut {
+                fn visit_event_mut(&mut self, event: &mut OcsfEvent) {
+                    walk_event_mut(self, event)
+                }
+                #(#hooks_mut)*
+            }
+
+            /// Calls the hook of the event's class. Unknown events are skipped.
+            pub fn walk_event<V: Visit + ?Sized>(visitor: &mut V, event: &OcsfEvent) {
+                match event {
+                    #(#event_arms,)*
+                    OcsfEvent::Unknown(_) => {}
+                }
+            }
+
+            /// Calls the hook of the event's class. Unknown events are skipped.
+            pub fn walk_event_mut<V: VisitMut + ?Sized>(visitor: &mut V, event: &mut OcsfEvent) {
+                match event {
+                    #(#event_arms_mut,)*
+                    OcsfEvent::Unknown(_) => {}
+                }
+            }
+
+            #(#walks)*
+        }
+    }
+}
+
+/// Static tables behind the `schema` module, sorted by name like resolved.json.
+/// Attributes get a static per class or object.
+/// Entries are compiled with the same features as the types they describe.
+fn generate_schema_tables(schema: &OcsfSchema, cx: &Context) -> TokenStream {
+    let mut attribute_tables = Vec::new();
+    let mut attributes_of = |kind: &str, name: &str, def: &ClassDef| {
+        let ident = format_ident!("{}_{}_ATTRIBUTES", kind, name.to_shouty_snake_case());
+        let attributes = attribute_infos(def, cx);
+        let cfg = class_cfg(cx, def);
+        attribute_tables.push(quote! {
+            #cfg
+            static #ident: &[AttributeInfo] = &[#(#attributes),*];
+        });
+        ident
+    };
+    let classes: Vec<TokenStream> = schema
+        .classes
+        .iter()
+        .filter_map(|(name, def)| {
+            let uid = Literal::i64_unsuffixed(def.uid?);
+            let category_uid = Literal::i64_unsuffixed(category_of(def).0);
+            let (caption, description, category) = (&def.caption, &def.description, &def.category);
+            let deprecated = deprecation_info(def.deprecated.as_ref());
+            let attributes = attributes_of("CLASS", name, def);
+            let profiles = def.profiles.iter().flatten();
+            let extension = option_str(def.extension.as_deref());
+            let cfg = class_cfg(cx, def);
+            Some(quote! {
+                #cfg
+                ClassInfo {
+                    uid: #uid,
+                    name: #name,
+                    caption: #caption,
+                    description: #description,
+                    category: #category,
+                    category_uid: #category_uid,
+                    deprecated: #deprecated,
+                    profiles: &[#(#profiles),*],
+                    extension: #extension,
+                    attributes: #attributes,
+                }
+            })
+        })
+        .collect();
+    let objects: Vec<TokenStream> = schema
+        .objects
+        .iter()
+        .map(|(name, def)| {
+            let (caption, description) = (&def.caption, &def.description);
+            let deprecated = deprecation_info(def.deprecated.as_ref());
+            let attributes = attributes_of("OBJECT", name, def);
+            let extension = option_str(def.extension.as_deref());
+            let cfg = class_cfg(cx, def);
+            quote! {
+                #cfg
+                ObjectInfo {
+                    name: #name,
+                    caption: #caption,
+                    description: #description,
+                    deprecated: #deprecated,
+                    extension: #extension,
+                    attributes: #attributes,
+                }
+            }
+        })
+        .collect();
+    quote! {
+        pub(crate) static SCHEMA_CLASSES: &[ClassInfo] = &[#(#classes),*];
+        pub(crate) static SCHEMA_OBJECTS: &[ObjectInfo] = &[#(#objects),*];
+        #(#attribute_tables)*
+    }
+}
+
+fn attribute_infos(def: &ClassDef, cx: &Context) -> Vec<TokenStream> {
+    def.attributes
+        .iter()
+        .map(|(name, attr)| {
+            let (caption, description, is_array) =
+                (&attr.caption, &attr.description, attr.is_array);
+            let requirement = match attr.requirement.as_str() {
+                "required" => quote! { Requirement::Required },
+                "recommended" => quote! { Requirement::Recommended },
+                _ => quote! { Requirement::Optional },
+            };
+            let (type_name, object_type) = if is_primitive_type(&attr.type_name) {
+                (attr.type_name.as_str(), quote! { None })
+            } else {
+                let object_type = &attr.type_name;
+                ("object_t", quote! { Some(#object_type) })
+            };
+            let deprecated = deprecation_info(attr.deprecated.as_ref());
+            let profile = option_str(attr.profile.as_deref());
+            let extension = option_str(attr.extension.as_deref());
+            let enum_values = sorted_enum_values(attr).into_iter().map(|(value, member)| {
+                let (value, caption) = (Literal::i64_unsuffixed(value), &member.caption);
+                quote! { (#value, #caption) }
+            });
+            let cfg = attr_cfg(cx, def, attr);
+            quote! {
+                #cfg
+                AttributeInfo {
+                    name: #name,
+                    caption: #caption,
+                    description: #description,
+                    requirement: #requirement,
+                    type_name: #type_name,
+                    object_type: #object_type,
+                    is_array: #is_array,
+                    deprecated: #deprecated,
+                    profile: #profile,
+                    extension: #extension,
+                    enum_values: &[#(#enum_values),*],
+                }
+            }
+        })
+        .collect()
+}
+
+fn option_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+fn deprecation_info(deprecated: Option<&DeprecatedInfo>) -> TokenStream {
+    match deprecated {
+        Some(DeprecatedInfo { message, since }) => quote! {
+            Some(Deprecation { message: #message, since: #since })
+        },
+        None => quote! { None },
+    }
+}
+
+/// An attribute gets a Rust enum when it is an integer `*_id` with an enum block
+fn is_enum_attribute(attr_name: &str, attr: &AttributeDef) -> bool {
+    attr_name.ends_with("_id")
+        && matches!(attr.type_name.as_str(), "integer_t" | "integer" | "long_t")
+        && attr.enum_values.as_ref().is_some_and(|e| !e.is_empty())
+}
+
+/// Attributes sharing a name share one enum (e.g. `SeverityId`) for the values more than half of
+/// them have.
+/// A struct whose values differ gets its own, prefixed with the struct name (e.g.
+/// `AccountChangeActivityId`).
+fn collect_enums(schema: &OcsfSchema) -> (EnumNames, BTreeMap<String, &AttributeDef>) {
+    let mut by_attr: BTreeMap<&str, Vec<(&str, &AttributeDef)>> = BTreeMap::new();
+    for (name, def) in schema.classes.iter().chain(schema.objects.iter()) {
+        for (attr_name, attr) in &def.attributes {
+            if is_enum_attribute(attr_name, attr) {
+                by_attr.entry(attr_name).or_default().push((name, attr));
+            }
+        }
+    }
+    let struct_names: Vec<String> = schema
+        .classes
+        .keys()
+        .chain(schema.objects.keys())
+        .map(|k| k.to_pascal_case())
+        .collect();
+
+    let mut names = EnumNames::new();
+    let mut defs = BTreeMap::new();
+    for (attr_name, uses) in by_attr {
+        let shared_name = attr_name.to_pascal_case();
+        let count = |values: &Option<_>| {
+            uses.iter()
+                .filter(|(_, a)| &a.enum_values == values)
+                .count()
+        };
+        let common = uses
+            .iter()
+            .map(|(_, a)| &a.enum_values)
+            .find(|values| count(values) * 2 > uses.len());
+        let shared = !struct_names.contains(&shared_name);
+        for (struct_key, attr) in uses {
+            let enum_name = if shared && Some(&attr.enum_values) == common {
+                shared_name.clone()
+            } else {
+                format!("{}{}", struct_key.to_pascal_case(), shared_name)
+            };
+            names.insert(
+                (struct_key.to_string(), attr_name.to_string()),
+                enum_name.clone(),
+            );
+            defs.entry(enum_name).or_insert(attr);
+        }
+    }
+    (names, defs)
+}
+
+fn sorted_enum_values(attr: &AttributeDef) -> Vec<(i64, &EnumMemberDef)> {
+    let mut values: Vec<(i64, &EnumMemberDef)> = attr
+        .enum_values
+        .iter()
+        .flatten()
+        .filter_map(|(k, v)| k.parse::<i64>().ok().map(|k| (k, v)))
+        .collect();
+    values.sort_by_key(|(k, _)| *k);
+    values
+}
+
+fn generate_enum(enum_name: &str, attr: &AttributeDef) -> TokenStream {
+    let enum_ident = format_ident!("{}", enum_name);
+    let doc_str = format!("{}\n\n{}", attr.caption, attr.description);
+
+    let values = sorted_enum_values(attr);
+
+    let mut used = vec![String::from("Unrecognized")];
+    let variants: Vec<(i64, proc_macro2::Ident, String)> = values
+        .iter()
+        .map(|(value, member)| {
+            let mut variant = member.caption.to_pascal_case();
+            if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+                variant = format!("Value{}", variant);
+            }
+            if used.contains(&variant) {
+                variant = format!("{}{}", variant, value.to_string().replace('-', "Minus"));
+            }
+            used.push(variant.clone());
+            let doc = format!("{}\n\n{}", member.caption, member.description);
+            (*value, format_ident!("{}", variant), doc)
+        })
+        .collect();
+
+    let variant_defs = variants.iter().map(|(value, ident, doc)| {
+        let doc = format!("{} (`{}`)", doc.trim_end(), value);
+        quote! {
+            #[doc = #doc]
+            #ident
+        }
+    });
+    let from_int = variants.iter().map(|(value, ident, _)| {
+        let value = Literal::i64_unsuffixed(*value);
+        quote! { #value => #enum_ident::#ident }
+    });
+    let to_int = variants.iter().map(|(value, ident, _)| {
+        let value = Literal::i64_unsuffixed(*value);
+        quote! { #enum_ident::#ident => #value }
+    });
+    let captions = variants
+        .iter()
+        .zip(&values)
+        .map(|((_, ident, _), (_, member))| {
+            let caption = &member.caption;
+            quote! { #enum_ident::#ident => Some(#caption) }
+        });
+    quote! {
+        #[doc = #doc_str]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "i64", into = "i64")]
+        #[non_exhaustive]
+        pub enum #enum_ident {
+            #(#variant_defs,)*
+            /// A value that is not defined by the schema
+            Unrecognized(i64),
+        }
+        impl From<i64> for #enum_ident {
+            fn from(value: i64) -> Self {
+                match value {
+                    #(#from_int,)*
+                    other => #enum_ident::Unrecognized(other),
+                }
+            }
+        }
+        impl From<#enum_ident> for i64 {
+            fn from(value: #enum_ident) -> Self {
+                match value {
+                    #(#to_int,)*
+                    #enum_ident::Unrecognized(other) => other,
+                }
+            }
+        }
+        impl AsFieldRef for #enum_ident {
+            fn as_field_ref(&self) -> FieldRef<'_> {
+                FieldRef::Integer(i64::from(*self))
+            }
+        }
+        impl FromFieldValue for #enum_ident {
+            fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+                i64::from_field_value(value).map(#enum_ident::from)
+            }
+        }
+        impl #enum_ident {
+            /// The caption the schema gives this value, `None` when it is unrecognized
+            pub fn caption(&self) -> Option<&'static str> {
+                match self {
+                    #(#captions,)*
+                    #enum_ident::Unrecognized(_) => None,
+                }
+            }
+        }
+    }
+}
+
+fn generate_struct(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+
+    let deprecation_attribute = if let Some(info) = &def.deprecated {
+        let msg = format!("{} (Since {})", info.message, info.since);
+        quote! {#[deprecated(note = #msg)]}
+    } else {
+        quote! {}
+    };
+    let uid_doc = def.uid.map(|u| format!("UID:{}", u)).unwrap_or_default();
+    let mut meta_doc = format!("Category: {} | Name: {}", def.category, def.name);
+    if let Some(extension) = &def.extension {
+        meta_doc.push_str(&format!(" | Extension: {}", extension));
+    }
+    let constraint_doc = if let Some(map) = &def.constraints {
+        let mut doc = String::from("\n\n**Constraints:**\n");
+        for (rule, fields) in map {
+            let field_list = fields.join("`,`");
+            doc.push_str(&format!("* {}: `[{}]`\n", rule, field_list));
+        }
+        doc
+    } else {
+        String::new()
+    };
+
+    let doc_str = format!(
+        "{}\n\n{}\n\n[{}] {}{}",
+        def.caption, def.description, uid_doc, meta_doc, constraint_doc
+    );
+    let fields = def.attributes.iter().map(|(attr_name, attr)| {
+        let safe_name = sanitize_name(attr_name);
+        let field_ident = format_ident!("{}", safe_name);
+
+        let enum_name = cx
+            .enum_names
+            .get(&(name.to_string(), attr_name.to_string()));
+        let raw_type = match enum_name {
+            Some(enum_name) => {
+                let enum_ident = format_ident!("{}", enum_name);
+                quote! { #enum_ident }
+            }
+            None => cx.map_type(&attr.type_name),
+        };
+
+        let is_primitive = enum_name.is_some() || is_primitive_type(&attr.type_name);
+
+        let type_container = if attr.is_array {
+            quote! {Vec<#raw_type>}
+        } else if !is_primitive {
+            quote! { Box<#raw_type> } // may be recursive non primitive
+        } else {
+            raw_type
+        };
+
+        /*
+        let final_type = if attr.requirement != "required" {
+            quote! {Option<#type_container>}
+        } else {
+            type_container
+        };
+        */
+        let final_type = quote! {Option<#type_container>};
+
+        /*
+        let serde_skip = if attr.requirement != "required" {
+            quote! {#[serde(skip_serializing_if = "Option::is_none")]}
+        } else {
+            quote! {}
+        };
+        */
+
+        // May result in required fields being skipped if object is improperly created
+        let serde_skip = quote! {#[serde(skip_serializing_if = "Option::is_none")]};
+
+        let type_token = final_type;
+        let mut attr_doc = format!(
+            "{}\n\n{}\n\n{}",
+            attr.caption,
+            attr.description,
+            requirement_doc(attr)
+        );
+        if let Some(extension) = foreign_extension(def, attr) {
+            attr_doc.push_str(&format!(", added by the `{}` extension", extension));
+        }
+        let cfg = attr_cfg(cx, def, attr);
+        quote! {
+            #[doc = #attr_doc]
+            #cfg
+            #[serde(rename = #attr_name)]
+            #serde_skip
+            pub #field_ident: #type_token
+        }
+    });
+    let derives = &cx.options.derives;
+    let unknown_fields = cx.options.features.then(|| {
+        quote! {
+            /// Keys that the schema does not define, kept so they survive a round-trip
+            #[cfg(feature = "preserve-unknown")]
+            #[serde(flatten)]
+            pub unknown_fields: serde_json::Map<String, Value>,
+        }
+    });
+    quote! {
+        #[doc = #doc_str]
+        #deprecation_attribute
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default #(, #derives)*)]
+        #[serde(default)]
+        #[non_exhaustive]
+        pub struct #struct_name {
+            #(#fields,)*
+            #unknown_fields
+        }
+    }
+}
+
+/// Checks requirement levels, constraints and `type_uid`, and recurses into nested objects,
+/// tracking the JSON path
+fn generate_validate_impl(name: &str, def: &ClassDef, cx: &Context) -> TokenStream {
+    let struct_name = format_ident!("{}", name.to_pascal_case());
+    let algorithm_id = if !def.attributes.contains_key("algorithm_id") {
+        quote! { None }
+    } else if cx
+        .enum_names
+        .contains_key(&(name.to_string(), "algorithm_id".to_string()))
+    {
+        quote! { self.algorithm_id.map(i64::from) }
+    } else {
+        quote! { self.algorithm_id }
+    };
+    let mut checks: Vec<TokenStream> = def
+        .attributes
+        .iter()
+        .filter_map(|(attr_name, attr)| {
+            let field_ident = format_ident!("{}", sanitize_name(attr_name));
+            let presence = match (attr.requirement.as_str(), &attr.profile) {
+                ("required", None) => Some(quote! {
+                    report.check_required(path, #attr_name, self.#field_ident.is_some());
+                }),
+                ("recommended", None) => Some(quote! {
+                    report.check_recommended(path, #attr_name, self.#field_ident.is_some());
+                }),
+                ("required", Some(profile)) => Some(quote! {
+                    report.check_required_in_profile(path, #profile, #attr_name, self.#field_ident.is_some());
+                }),
+                ("recommended", Some(profile)) => Some(quote! {
+                    report.check_recommended_in_profile(path, #profile, #attr_name, self.#field_ident.is_some());
+                }),
+                _ => None,
+            };
+            let checked = !cx.is_overridden(&attr.type_name);
+            let string_format = string_format(&attr.type_name).filter(|_| checked);
+            let nested = if !checked {
+                None
+            } else if attr.type_name == "file_hash_t" && !attr.is_array {
+                Some(quote! {
+                    report.check_file_hash(path, #attr_name, self.#field_ident.as_deref(), #algorithm_id);
+                })
+            } else if let Some(format) = &string_format {
+                if attr.is_array {
+                    Some(quote! {
+                        report.check_string_formats(path, #attr_name, #format, self.#field_ident.as_deref());
+                    })
+                } else {
+                    Some(quote! {
+                        report.check_string_format(path, #attr_name, #format, self.#field_ident.as_deref());
+                    })
+                }
+            } else if is_format_type(&attr.type_name) && attr.is_array {
+                Some(quote! {
+                    report.check_formats(path, #attr_name, self.#field_ident.as_deref());
+                })
+            } else if is_format_type(&attr.type_name) {
+                Some(quote! {
+                    report.check_format(path, #attr_name, self.#field_ident.as_ref());
+                })
+            } else if is_primitive_type(&attr.type_name) {
+                None
+            } else if attr.is_array {
+                Some(quote! {
+                    if let Some(items) = &self.#field_ident {
+                        let path = attr_path(path, #attr_name);
+                        for (i, item) in items.iter().enumerate() {
+                            item.validate_at(&index_path(&path, i), report);
+                        }
+                    }
+                })
+            } else {
+                Some(quote! {
+                    if let Some(value) = &self.#field_ident {
+                        value.validate_at(&attr_path(path, #attr_name), report);
+                    }
+                })
+            };
+            let cfg = attr_cfg(cx, def, attr);
+            match (presence, nested) {
+                (None, None) => None,
+                (presence, nested) => {
+                    let statements = presence.into_iter().chain(nested);
+                    Some(quote! { #(#cfg #statements)* })
+                }
+            }
+        })
+        .collect();
+
+    for (rule, fields) in def.constraints.iter().flatten() {
+        let rule = match rule.as_str() {
+            "at_least_one" => quote! { ConstraintRule::AtLeastOne },
+            "just_one" => quote! { ConstraintRule::JustOne },
+            _ => continue,
+        };
+        let pairs = fields.iter().map(|field| {
+            let steps = constraint_path(cx.schema, def, field)
+                .expect("constraints are checked before generating");
+            let names: Vec<&str> = field.split('.').collect();
+            let present = constraint_presence(quote! { self }, &names, &steps);
+            // Attributes of another extension only exist with its feature, and count as missing
+            // without it
+            let features: BTreeSet<&str> = steps
+                .iter()
+                .filter_map(|(holder, attr)| foreign_extension(holder, attr))
+                .filter(|_| cx.options.features)
+                .collect();
+            let predicate = match features.len() {
+                0 => return quote! { (#field, #present) },
+                1 => quote! { #(feature = #features)* },
+                _ => quote! { all(#(feature = #features),*) },
+            };
+            quote! {
+                (#field, {
+                    #[cfg(#predicate)]
+                    let present = #present;
+                    #[cfg(not(#predicate))]
+                    let present = false;
+                    present
+                })
+            }
+        });
+        checks.push(quote! {
+            report.check_constraint(path, #rule, &[#(#pairs),*]);
+        });
+    }
+    if has_type_attributes(def) {
+        checks.push(quote! {
+            report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+        });
+    }
+    // Profile attributes are checked against the profiles the event itself lists
+    if def.profiles.as_ref().is_some_and(|p| !p.is_empty())
+        && def.attributes.contains_key("metadata")
+    {
+        checks.insert(
+            0,
+            quote! {
+                report.apply_profiles(self.metadata.as_ref().and_then(|metadata| metadata.profiles.as_deref()));
+            },
+        );
+    }
+
+    if checks.is_empty() {
+        return quote! {
+            impl Validate for #struct_name {
+                fn validate_at(&self, _path: &str, _report: &mut ValidationReport) {}
+            }
+        };
+    }
+    quote! {
+        impl Validate for #struct_name {
+            fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+                #(#checks)*
+            }
+        }
+    }
+}
+
+/// Whether the last of the attributes a constraint names is set, following objects, e.g.
+/// `device.os.sp_name`.
+/// An attribute of an array is set when any item has it.
+fn constraint_presence(
+    value: TokenStream,
+    names: &[&str],
+    steps: &[(&ClassDef, &AttributeDef)],
+) -> TokenStream {
+    let field_ident = format_ident!("{}", sanitize_name(names[0]));
+    if names.len() == 1 {
+        return quote! { #value.#field_ident.is_some() };
+    }
+    let rest = constraint_presence(quote! { value }, &names[1..], &steps[1..]);
+    if steps[0].1.is_array {
+        quote! { #value.#field_ident.iter().flatten().any(|value| #rest) }
+    } else {
+        quote! { #value.#field_ident.as_ref().is_some_and(|value| #rest) }
+    }
+}
+
+pub(crate) fn is_primitive_type(t: &str) -> bool {
+    matches!(
+        t,
+        // String types
+        "string_t" | "string" | "bytestring_t" | "datetime_t" | "email_t" |
+            "file_hash_t" | "file_name_t" | "file_path_t" | "hostname_t" |
+            "ip_t" | "mac_t" | "subnet_t" | "url_t" | "username_t" | "uuid_t" |
+            "process_name_t" | "reg_key_path_t" | "resource_uid_t" |
+            // Integer types
+            "integer_t" | "integer" | "long_t" | "port_t" | "timestamp_t" |
+            // Float types
+            "float_t" |
+            // Boolean types
+            "boolean_t" |
+            // JSON/Object types
+            "json_t" | "object_t" | "object"
+    )
+}
+
+fn is_string_type(t: &str) -> bool {
+    matches!(
+        t,
+        "string_t"
+            | "string"
+            | "bytestring_t"
+            | "email_t"
+            | "file_hash_t"
+            | "file_name_t"
+            | "file_path_t"
+            | "hostname_t"
+            | "url_t"
+            | "username_t"
+            | "uuid_t"
+            | "process_name_t"
+            | "reg_key_path_t"
+            | "resource_uid_t"
+    )
+}
+
+/// Types whose values are kept as found when deserializing, and checked by `Validate`
+fn is_format_type(t: &str) -> bool {
+    matches!(t, "ip_t" | "mac_t" | "subnet_t" | "port_t" | "datetime_t")
+}
+
+/// String types whose values `Validate` checks against a `StringFormat`
+fn string_format(t: &str) -> Option<TokenStream> {
+    match t {
+        "email_t" => Some(quote! { StringFormat::Email }),
+        "uuid_t" => Some(quote! { StringFormat::Uuid }),
+        "url_t" => Some(quote! { StringFormat::Url }),
+        "hostname_t" => Some(quote! { StringFormat::Hostname }),
+        "file_hash_t" => Some(quote! { StringFormat::FileHash }),
+        _ => None,
+    }
+}
+
+fn map_ocsf_type(t: &str) -> TokenStream {
+    match t {
+        t if is_string_type(t) => quote! { String },
+        "integer_t" | "integer" | "long_t" => quote! { i64 },
+        "ip_t" => quote! { IpAddress },
+        "mac_t" => quote! { MacAddress },
+        "subnet_t" => quote! { Subnet },
+        "port_t" => quote! { Port },
+        "timestamp_t" => quote! { Timestamp },
+        "datetime_t" => quote! { DateTime },
+        "float_t" => quote! { f64 },
+        "boolean_t" => quote! { bool },
+        "json_t" | "object_t" | "object" => quote! { serde_json::Value },
+        other => {
+            let type_name = format_ident!("{}", other.to_pascal_case());
+            quote! { #type_name }
+        }
+    }
+}
+
+/// Tell rust to treat keywords as raw identifiers
+fn sanitize_name(name: &str) -> String {
+    let name = name.to_snake_case();
+    match name.as_str() {
+        "type" | "ref" | "match" | "enum" | "const" | "struct" | "self" | "use" | "extern"
+        | "crate" | "super" | "trait" | "impl" | "async" | "await" | "dyn" | "abstract"
+        | "yield" | "box" | "final" | "let" | "loop" | "pub" | "return" | "unsafe" | "where"
+        | "while" | "for" | "if" | "else" | "false" | "true" | "mod" | "move" | "mut" => {
+            format!("r#{}", name)
+        }
+        _ => name,
+    }
+}
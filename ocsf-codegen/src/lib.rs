@@ -0,0 +1,346 @@
+//! # OCSF Codegen
+//! Generates the Rust types of [ocsf-types](https://docs.rs/ocsf-types) from an OCSF schema,
+//! either an [ocsf-schema](https://github.com/ocsf/ocsf-schema) checkout, which it compiles itself,
+//! or a resolved schema such as the `resolved.json` it compiles to, optionally with extensions of your own on top.
+//!
+//! The generated code builds on the runtime of ocsf-types, so the crate it goes into depends on
+//! `ocsf-types`, `serde` with the `derive` feature and `serde_json`.
+//! ## Usage
+//! In a build script, the types go into a single file in `OUT_DIR`:
+//! ```no_run
+//! // build.rs
+//! ocsf_codegen::Codegen::new("ocsf-schema")
+//!     .extension("acme-extension")
+//!     .classes(["detection_finding", "acme/beacon_detection"])
+//!     .type_override("ip_t", "std::net::IpAddr")
+//!     .generate()
+//!     .unwrap();
+//! ```
+//! The file holds a module named after it, `ocsf` by default:
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/ocsf.rs"));
+//! pub use ocsf::*;
+//! ```
+//! [`Codegen::output_dir`] generates a module directory to check in instead, as ocsf-types does.
+//! The `ocsf-codegen` binary takes the same options on the command line, see `ocsf-codegen --help`.
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+mod compile;
+mod generate;
+mod model;
+
+/// Generates the types of a resolved schema, see the [crate] docs
+#[derive(Debug, Clone)]
+pub struct Codegen {
+    input: PathBuf,
+    output: Option<Output>,
+    extensions: Vec<PathBuf>,
+    ignored_extensions: BTreeSet<String>,
+    classes: Option<BTreeSet<String>>,
+    type_overrides: BTreeMap<String, String>,
+    derives: Vec<String>,
+    runtime: String,
+    cargo_features: bool,
+    rustfmt: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Output {
+    Dir(PathBuf),
+    File(PathBuf),
+}
+
+impl Codegen {
+    /// Generates the types of the schema at `input`, an ocsf-schema directory or a resolved schema
+    /// file.
+    ///
+    /// A directory is compiled the way `python -m ocsf.compile` does, with the extensions in its
+    /// `extensions/`.
+    /// Attributes are observable by their own `observable` annotation, or by the one of their type
+    /// in the `types` of the dictionary. A resolved schema without `types` only has the former.
+    pub fn new(input: impl Into<PathBuf>) -> Self {
+        Codegen {
+            input: input.into(),
+            output: None,
+            extensions: Vec::new(),
+            ignored_extensions: BTreeSet::new(),
+            classes: None,
+            type_overrides: BTreeMap::new(),
+            derives: Vec::new(),
+            runtime: "::ocsf_types".to_string(),
+            cargo_features: false,
+            rustfmt: true,
+        }
+    }
+
+    /// Writes the types into a single file holding a module named after it, `$OUT_DIR/ocsf.rs` by
+    /// default
+    pub fn output_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = Some(Output::File(path.into()));
+        self
+    }
+
+    /// Writes the types into a module directory, with a `mod.rs` and a file per category and
+    /// extension
+    pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output = Some(Output::Dir(dir.into()));
+        self
+    }
+
+    /// Adds an extension directory laid out like those of ocsf-schema on top of the schema.
+    ///
+    /// It holds `extension.json` with the extension's `name` and `uid`, `dictionary.json` with its
+    /// new attributes,
+    /// and a JSON file per object in `objects/` and per class in `events/`.
+    /// A file named after an object or class of the schema adds attributes to it,
+    /// any other file defines a new one, keyed `<extension>/<name>`.
+    pub fn extension(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.extensions.push(dir.into());
+        self
+    }
+
+    /// Leaves out the extension of ocsf-schema with this name, such as `win`
+    pub fn ignore_extension(mut self, name: impl Into<String>) -> Self {
+        self.ignored_extensions.insert(name.into());
+        self
+    }
+
+    /// Generates only this class, by its key such as `file_activity` or
+    /// `win/registry_key_activity`,
+    /// and the others added the same way, with the objects they use. Every class is generated by
+    /// default.
+    pub fn class(mut self, name: impl Into<String>) -> Self {
+        self.classes.get_or_insert_default().insert(name.into());
+        self
+    }
+
+    /// Like [`Codegen::class`], for several classes
+    pub fn classes<I: IntoIterator<Item = S>, S: Into<String>>(mut self, names: I) -> Self {
+        self.classes
+            .get_or_insert_default()
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Maps the OCSF scalar type `ocsf_type`, e.g. `ip_t`, to `rust_type`, e.g. `std::net::IpAddr`.
+    ///
+    /// The Rust type has to implement `Debug`, `Clone`, `PartialEq`, `Default` and serde's traits,
+    /// and the derives added with [`Codegen::derive`].
+    /// Attributes of an overridden type are not checked by `Validate`, reached by path access or
+    /// collected as observables.
+    pub fn type_override(
+        mut self,
+        ocsf_type: impl Into<String>,
+        rust_type: impl Into<String>,
+    ) -> Self {
+        self.type_overrides
+            .insert(ocsf_type.into(), rust_type.into());
+        self
+    }
+
+    /// Adds a derive to every generated class and object. The type of every attribute has to
+    /// support it,
+    /// e.g. `Eq` needs a [`Codegen::type_override`] of `float_t`.
+    pub fn derive(mut self, path: impl Into<String>) -> Self {
+        self.derives.push(path.into());
+        self
+    }
+
+    /// The path of the ocsf-types runtime in the generated code, `::ocsf_types` by default
+    pub fn runtime(mut self, path: impl Into<String>) -> Self {
+        self.runtime = path.into();
+        self
+    }
+
+    /// Gates the classes of each category and extension behind a cargo feature of the same name,
+    /// as ocsf-types does, along with `unknown_fields` behind `preserve-unknown`. Off by default.
+    pub fn cargo_features(mut self, enabled: bool) -> Self {
+        self.cargo_features = enabled;
+        self
+    }
+
+    /// Formats the output with `rustfmt`, on by default. A failure to format is only a warning.
+    pub fn rustfmt(mut self, enabled: bool) -> Self {
+        self.rustfmt = enabled;
+        self
+    }
+
+    /// Reads the schema and its extensions and writes the types
+    pub fn generate(&self) -> Result<(), Error> {
+        let output = match (&self.output, std::env::var_os("OUT_DIR")) {
+            (Some(output), _) => output.clone(),
+            (None, Some(out_dir)) => Output::File(Path::new(&out_dir).join("ocsf.rs")),
+            (None, None) => return Err(Error::NoOutput),
+        };
+        let options = self.options()?;
+        let schema = self.load()?;
+        if std::env::var_os("OUT_DIR").is_some() {
+            for input in std::iter::once(&self.input).chain(&self.extensions) {
+                println!("cargo:rerun-if-changed={}", input.display());
+            }
+        }
+
+        let formatted = match &output {
+            Output::Dir(dir) => {
+                let (root, files) = generate::generate(&schema, &options, false);
+                create_dir(dir)?;
+                for (name, code) in files {
+                    write(&dir.join(format!("{}.rs", name)), code)?;
+                }
+                write(&dir.join("mod.rs"), root)?;
+                dir.join("mod.rs")
+            }
+            Output::File(path) => {
+                let (root, _) = generate::generate(&schema, &options, true);
+                let stem = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or("ocsf");
+                let module = format_ident!("{}", stem);
+                if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                    create_dir(dir)?;
+                }
+                write(path, quote! { pub mod #module { #root } })?;
+                path.clone()
+            }
+        };
+        if self.rustfmt {
+            // Formats the submodules of a directory as well.
+            // The 2024 style wraps struct literals that hold long strings, such as the schema
+            // tables
+            let status = Command::new("rustfmt")
+                .args(["--edition", "2024"])
+                .arg(&formatted)
+                .status();
+            if !status.is_ok_and(|status| status.success()) {
+                println!("cargo:warning=failed to format {}", formatted.display());
+            }
+        }
+        Ok(())
+    }
+
+    /// The schema the types are generated from, with its extensions and the allow-list applied,
+    /// in the shape of the `resolved.json` of `python -m ocsf.compile`.
+    ///
+    /// Compare it with the output of `ocsf.compile` to check how the compiler treats a schema
+    /// directory.
+    pub fn resolve(&self) -> Result<serde_json::Value, Error> {
+        let schema = self.load()?;
+        Ok(serde_json::to_value(&schema).expect("the schema has string keys"))
+    }
+
+    fn load(&self) -> Result<model::OcsfSchema, Error> {
+        let mut schema = model::load(&self.input, &self.extensions, &self.ignored_extensions)?;
+        if let Some(classes) = &self.classes {
+            model::retain_classes(&mut schema, classes)?;
+        }
+        model::check_constraints(&schema, &self.input)?;
+        Ok(schema)
+    }
+
+    fn options(&self) -> Result<generate::Options, Error> {
+        let mut type_overrides = BTreeMap::new();
+        for (ocsf_type, rust_type) in &self.type_overrides {
+            if !generate::is_primitive_type(ocsf_type) {
+                return Err(Error::UnknownType(ocsf_type.clone()));
+            }
+            type_overrides.insert(ocsf_type.clone(), tokens(rust_type)?);
+        }
+        let derives = self
+            .derives
+            .iter()
+            .map(|path| tokens(path))
+            .collect::<Result<_, _>>()?;
+        tokens(&self.runtime)?;
+        Ok(generate::Options {
+            runtime: self.runtime.clone(),
+            type_overrides,
+            derives,
+            features: self.cargo_features,
+        })
+    }
+}
+
+fn tokens(code: &str) -> Result<TokenStream, Error> {
+    match code.parse() {
+        Ok(tokens) if !code.trim().is_empty() => Ok(tokens),
+        _ => Err(Error::InvalidTokens(code.to_string())),
+    }
+}
+
+fn create_dir(dir: &Path) -> Result<(), Error> {
+    fs::create_dir_all(dir).map_err(|source| Error::Io {
+        path: dir.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, code: TokenStream) -> Result<(), Error> {
+    fs::write(path, code.to_string()).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Why [`Codegen::generate`] failed
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading an input or writing an output failed
+    Io { path: PathBuf, source: io::Error },
+    /// An input is not JSON, or not shaped like a resolved schema
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The schema names something it does not define, such as the object a file `extends` or an
+    /// attribute of a constraint
+    Schema { path: PathBuf, message: String },
+    /// An extension directory lacks something the generator needs, such as the class an event
+    /// `extends`
+    Extension { path: PathBuf, message: String },
+    /// A class of the allow-list is not part of the schema
+    UnknownClass(String),
+    /// A type override is for something other than an OCSF scalar type such as `ip_t`
+    UnknownType(String),
+    /// A Rust type, derive or runtime path does not parse
+    InvalidTokens(String),
+    /// There is no output path and no `OUT_DIR` to default to
+    NoOutput,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Json { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Schema { path, message } => write!(f, "{}: {}", path.display(), message),
+            Error::Extension { path, message } => {
+                write!(f, "extension {}: {}", path.display(), message)
+            }
+            Error::UnknownClass(name) => write!(f, "unknown class {}", name),
+            Error::UnknownType(name) => write!(f, "{} is not an OCSF scalar type", name),
+            Error::InvalidTokens(code) => write!(f, "`{}` is not valid Rust", code),
+            Error::NoOutput => f.write_str("no output path, and OUT_DIR is not set"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
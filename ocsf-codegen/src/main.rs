@@ -0,0 +1,109 @@
+use ocsf_codegen::Codegen;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: ocsf-codegen <SCHEMA> (--out-dir DIR | --out-file FILE) [options]
+
+SCHEMA is an ocsf-schema directory or a resolved schema file such as resolved.json.
+
+Options:
+  --extension DIR         Add an extension directory on top of the schema, repeatable
+  --ignore-extension NAME Leave out an extension of the ocsf-schema directory, repeatable
+  --class NAME            Generate only this class and the objects it uses, repeatable
+  --type OCSF_TYPE=RUST   Map an OCSF scalar type to a Rust type, e.g. ip_t=std::net::IpAddr, repeatable
+  --derive PATH           Add a derive to every class and object, repeatable
+  --runtime PATH          The path of the ocsf-types runtime, ::ocsf_types by default
+  --cargo-features        Gate categories and extensions behind cargo features
+  --no-rustfmt            Leave the output unformatted";
+
+fn main() -> ExitCode {
+    match parse(std::env::args().skip(1)) {
+        Ok(codegen) => match codegen.generate() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Default)]
+struct Args {
+    input: Option<String>,
+    out_dir: Option<String>,
+    out_file: Option<String>,
+    extensions: Vec<String>,
+    ignored_extensions: Vec<String>,
+    classes: Vec<String>,
+    types: Vec<(String, String)>,
+    derives: Vec<String>,
+    runtime: Option<String>,
+    cargo_features: bool,
+    no_rustfmt: bool,
+}
+
+fn parse(mut args: impl Iterator<Item = String>) -> Result<Codegen, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} takes a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            "--out-dir" => parsed.out_dir = Some(value()?),
+            "--out-file" => parsed.out_file = Some(value()?),
+            "--extension" => parsed.extensions.push(value()?),
+            "--ignore-extension" => parsed.ignored_extensions.push(value()?),
+            "--class" => parsed.classes.push(value()?),
+            "--type" => {
+                let mapping = value()?;
+                let (ocsf_type, rust_type) = mapping
+                    .split_once('=')
+                    .ok_or_else(|| format!("--type takes OCSF_TYPE=RUST, not {}", mapping))?;
+                parsed
+                    .types
+                    .push((ocsf_type.to_string(), rust_type.to_string()));
+            }
+            "--derive" => parsed.derives.push(value()?),
+            "--runtime" => parsed.runtime = Some(value()?),
+            "--cargo-features" => parsed.cargo_features = true,
+            "--no-rustfmt" => parsed.no_rustfmt = true,
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            _ if parsed.input.is_none() => parsed.input = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    let input = parsed.input.ok_or("missing the schema")?;
+    let mut codegen = match (parsed.out_dir, parsed.out_file) {
+        (Some(dir), None) => Codegen::new(input).output_dir(dir),
+        (None, Some(file)) => Codegen::new(input).output_file(file),
+        _ => return Err("expected one of --out-dir and --out-file".to_string()),
+    };
+    for dir in parsed.extensions {
+        codegen = codegen.extension(dir);
+    }
+    for name in parsed.ignored_extensions {
+        codegen = codegen.ignore_extension(name);
+    }
+    if !parsed.classes.is_empty() {
+        codegen = codegen.classes(parsed.classes);
+    }
+    for (ocsf_type, rust_type) in parsed.types {
+        codegen = codegen.type_override(ocsf_type, rust_type);
+    }
+    for path in parsed.derives {
+        codegen = codegen.derive(path);
+    }
+    if let Some(path) = parsed.runtime {
+        codegen = codegen.runtime(path);
+    }
+    Ok(codegen
+        .cargo_features(parsed.cargo_features)
+        .rustfmt(!parsed.no_rustfmt))
+}
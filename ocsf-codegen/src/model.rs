@@ -0,0 +1,550 @@
+//! The resolved schema the generator reads, and the extensions and allow-list applied to it
+use crate::{Error, compile};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub(crate) struct OcsfSchema {
+    #[serde(default)]
+    pub(crate) version: String,
+    #[serde(default)]
+    pub(crate) classes: BTreeMap<String, ClassDef>,
+    #[serde(default)]
+    pub(crate) objects: BTreeMap<String, ClassDef>,
+    /// The scalar types of `dictionary.json`, e.g. `ip_t`
+    #[serde(default)]
+    pub(crate) types: BTreeMap<String, TypeDef>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct TypeDef {
+    /// The observable type id of the attributes of this type, e.g. 2 for `ip_t`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) observable: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct ClassDef {
+    #[serde(default)]
+    pub(crate) caption: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) attributes: BTreeMap<String, AttributeDef>,
+    #[serde(default)]
+    pub(crate) description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) uid: Option<i64>,
+    #[serde(default)]
+    pub(crate) category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) profiles: Option<Vec<String>>,
+    /// The extension that defines the class or object, e.g. `win`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) extension: Option<String>,
+    #[serde(
+        default,
+        rename = "associations",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) _associations: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) constraints: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(rename = "@deprecated")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deprecated: Option<DeprecatedInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct DeprecatedInfo {
+    pub(crate) message: String,
+    pub(crate) since: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct AttributeDef {
+    #[serde(rename = "type")]
+    pub(crate) type_name: String,
+    #[serde(default)]
+    pub(crate) caption: String,
+    #[serde(default)]
+    pub(crate) description: String,
+    #[serde(default)]
+    pub(crate) requirement: String,
+    #[serde(default)]
+    pub(crate) is_array: bool,
+    #[serde(default, rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub(crate) enum_values: Option<BTreeMap<String, EnumMemberDef>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sibling: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) observable: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) profile: Option<String>,
+    /// The extension that adds the attribute to a class or object outside of it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) extension: Option<String>,
+    #[serde(rename = "@deprecated")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deprecated: Option<DeprecatedInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub(crate) struct EnumMemberDef {
+    #[serde(default)]
+    pub(crate) caption: String,
+    #[serde(default)]
+    pub(crate) description: String,
+}
+
+/// Reads a resolved schema, such as `resolved.json`, or compiles an ocsf-schema directory with the
+/// extensions it has,
+/// then adds the other extensions on top of it
+pub(crate) fn load(
+    input: &Path,
+    extensions: &[PathBuf],
+    ignored: &BTreeSet<String>,
+) -> Result<OcsfSchema, Error> {
+    let (mut schema, dictionary, mut dirs) = if input.is_dir() {
+        let compiled = compile::compile(input)?;
+        (
+            compiled.schema,
+            compiled.dictionary,
+            compile::extension_dirs(input, ignored)?,
+        )
+    } else {
+        let schema = serde_json::from_value(read_json(input)?).map_err(|source| Error::Json {
+            path: input.to_path_buf(),
+            source,
+        })?;
+        (schema, Value::Null, Vec::new())
+    };
+    dirs.extend(extensions.iter().cloned());
+    for dir in &dirs {
+        let roots: Vec<&Path> = std::iter::once(dir.as_path())
+            .chain(input.is_dir().then_some(input))
+            .collect();
+        add_extension(&mut schema, dir, &dictionary, &roots)?;
+    }
+    // Classes without a uid, such as the one of each category in ocsf-schema, and objects named
+    // `_*` only exist for others to extend
+    schema.classes.retain(|_, def| def.uid.is_some());
+    schema.objects.retain(|name, _| !name.starts_with('_'));
+    tag_extension_attributes(&mut schema);
+    Ok(schema)
+}
+
+/// Keeps the listed classes and the objects they reach, directly or through other objects
+pub(crate) fn retain_classes(
+    schema: &mut OcsfSchema,
+    classes: &BTreeSet<String>,
+) -> Result<(), Error> {
+    if let Some(unknown) = classes
+        .iter()
+        .find(|name| !schema.classes.contains_key(*name))
+    {
+        return Err(Error::UnknownClass(unknown.clone()));
+    }
+    schema.classes.retain(|name, _| classes.contains(name));
+    let mut reached = BTreeSet::new();
+    let mut pending: Vec<&str> = schema
+        .classes
+        .values()
+        .flat_map(|def| def.attributes.values())
+        .map(|attr| attr.type_name.as_str())
+        .collect();
+    while let Some(name) = pending.pop() {
+        if let Some(def) = schema.objects.get(name)
+            && reached.insert(name.to_string())
+        {
+            pending.extend(def.attributes.values().map(|attr| attr.type_name.as_str()));
+        }
+    }
+    schema.objects.retain(|name, _| reached.contains(name));
+    Ok(())
+}
+
+/// The attributes a constraint names, following dotted names such as `device.os.sp_name` through
+/// objects,
+/// with the class or object holding each of them
+pub(crate) fn constraint_path<'a>(
+    schema: &'a OcsfSchema,
+    def: &'a ClassDef,
+    field: &str,
+) -> Option<Vec<(&'a ClassDef, &'a AttributeDef)>> {
+    let mut steps: Vec<(&ClassDef, &AttributeDef)> = Vec::new();
+    for attr_name in field.split('.') {
+        let holder = match steps.last() {
+            Some((_, attr)) => schema.objects.get(&attr.type_name)?,
+            None => def,
+        };
+        steps.push((holder, holder.attributes.get(attr_name)?));
+    }
+    Some(steps)
+}
+
+/// Fails on a constraint naming an attribute that does not exist, rather than checking fewer
+/// attributes than it names
+pub(crate) fn check_constraints(schema: &OcsfSchema, input: &Path) -> Result<(), Error> {
+    for (name, def) in schema.classes.iter().chain(&schema.objects) {
+        for (rule, fields) in def.constraints.iter().flatten() {
+            if let Some(field) = fields
+                .iter()
+                .find(|field| constraint_path(schema, def, field).is_none())
+            {
+                let message = format!(
+                    "the {} constraint of {} names {}, which is not one of its attributes",
+                    rule, name, field
+                );
+                return Err(Error::Schema {
+                    path: input.to_path_buf(),
+                    message,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds an extension directory laid out like those of ocsf-schema: `extension.json` with the
+/// extension's `name` and `uid`,
+/// `dictionary.json` with its new attributes, and a JSON file per object in `objects/` and per
+/// class in `events/`.
+/// A file named after an object or class of the schema adds its attributes to it.
+/// Any other file defines a new one, keyed `<extension>/<name>`, with the attributes of the one it
+/// `extends`.
+/// Classes get the uid `extension uid * 100000 + category uid * 1000 + uid`.
+/// Attributes missing from the extension's dictionary come from `base_dictionary`, that of
+/// ocsf-schema, and `$include`s from `roots`.
+fn add_extension(
+    schema: &mut OcsfSchema,
+    dir: &Path,
+    base_dictionary: &Value,
+    roots: &[&Path],
+) -> Result<(), Error> {
+    let invalid = |message: String| Error::Extension {
+        path: dir.to_path_buf(),
+        message,
+    };
+    let extension = read_json(&dir.join("extension.json"))?;
+    let ext = extension["name"]
+        .as_str()
+        .ok_or_else(|| invalid("extension.json has no name".to_string()))?
+        .to_string();
+    let ext_uid = extension["uid"]
+        .as_i64()
+        .ok_or_else(|| invalid("extension.json has no uid".to_string()))?;
+    let dictionary_path = dir.join("dictionary.json");
+    let dictionary = if dictionary_path.exists() {
+        let dictionary = read_json(&dictionary_path)?;
+        for (name, def) in read_types(&dictionary, &dictionary_path)? {
+            schema.types.entry(name).or_insert(def);
+        }
+        dictionary["attributes"].clone()
+    } else {
+        Value::Null
+    };
+    let objects = json_files(&dir.join("objects"))
+        .iter()
+        .map(|path| compile::read_source(path, roots))
+        .collect::<Result<Vec<Value>, Error>>()?;
+    let events = json_files(&dir.join("events"))
+        .iter()
+        .map(|path| compile::read_source(path, roots))
+        .collect::<Result<Vec<Value>, Error>>()?;
+    let new_objects: BTreeSet<String> = objects
+        .iter()
+        .filter_map(|object| object["name"].as_str())
+        .filter(|name| !schema.objects.contains_key(*name))
+        .map(String::from)
+        .collect();
+    let resolver = ExtensionResolver {
+        ext: &ext,
+        dictionary: &dictionary,
+        base_dictionary,
+        new_objects: &new_objects,
+    };
+
+    for object in &objects {
+        let name = object["name"]
+            .as_str()
+            .or(object["extends"].as_str())
+            .ok_or_else(|| invalid("an object has no name".to_string()))?;
+        if let Some(def) = schema.objects.get(name) {
+            let mut def = def.clone();
+            resolver
+                .merge_attributes(schema, &mut def, object, true)
+                .map_err(invalid)?;
+            schema.objects.insert(name.to_string(), def);
+            continue;
+        }
+        let parent = object["extends"]
+            .as_str()
+            .and_then(|parent| resolver.find(&schema.objects, parent));
+        let mut def = parent.cloned().unwrap_or_else(|| empty_def(name));
+        resolver.define(&mut def, name, object);
+        resolver
+            .merge_attributes(schema, &mut def, object, false)
+            .map_err(invalid)?;
+        schema.objects.insert(format!("{}/{}", ext, name), def);
+    }
+
+    let categories: BTreeMap<String, (i64, String)> = schema
+        .classes
+        .values()
+        .filter(|def| def.uid.is_some())
+        .map(|def| (def.category.clone(), crate::generate::category_of(def)))
+        .collect();
+    for event in &events {
+        let name = event["name"]
+            .as_str()
+            .or(event["extends"].as_str())
+            .ok_or_else(|| invalid("a class has no name".to_string()))?;
+        if event["uid"].is_null() {
+            let mut def = schema
+                .classes
+                .get(name)
+                .ok_or_else(|| invalid(format!("no class {} to extend", name)))?
+                .clone();
+            resolver
+                .merge_attributes(schema, &mut def, event, true)
+                .map_err(invalid)?;
+            if let Some(profiles) = event["profiles"].as_array() {
+                let added = profiles_of(profiles);
+                def.profiles.get_or_insert_default().extend(added);
+            }
+            schema.classes.insert(name.to_string(), def);
+            continue;
+        }
+        let parent_name = event["extends"].as_str().unwrap_or("base_event");
+        let mut def = resolver
+            .find(&schema.classes, parent_name)
+            .ok_or_else(|| invalid(format!("no class {} for {} to extend", parent_name, name)))?
+            .clone();
+        resolver.define(&mut def, name, event);
+        if let Some(category) = event["category"].as_str() {
+            def.category = category.to_string();
+        }
+        if let Some(profiles) = event["profiles"].as_array() {
+            let added = profiles_of(profiles);
+            def.profiles.get_or_insert_default().extend(added);
+        }
+        resolver
+            .merge_attributes(schema, &mut def, event, false)
+            .map_err(invalid)?;
+        let (category_uid, category_caption) = categories
+            .get(&def.category)
+            .cloned()
+            .ok_or_else(|| invalid(format!("unknown category {} of {}", def.category, name)))?;
+        let uid = event["uid"]
+            .as_i64()
+            .ok_or_else(|| invalid(format!("the uid of {} is not an integer", name)))?;
+        let uid = ext_uid * 100000 + category_uid * 1000 + uid;
+        def.uid = Some(uid);
+        let caption = def.caption.clone();
+        set_enum(&mut def, "class_uid", uid, &caption);
+        set_enum(&mut def, "category_uid", category_uid, &category_caption);
+        schema.classes.insert(format!("{}/{}", ext, name), def);
+    }
+    Ok(())
+}
+
+fn profiles_of(profiles: &[Value]) -> Vec<String> {
+    profiles
+        .iter()
+        .filter_map(Value::as_str)
+        .map(String::from)
+        .collect()
+}
+
+fn empty_def(name: &str) -> ClassDef {
+    serde_json::from_value(serde_json::json!({ "name": name })).unwrap()
+}
+
+/// The single value of an enum such as `class_uid`, which names the class
+fn set_enum(def: &mut ClassDef, attr_name: &str, value: i64, caption: &str) {
+    if let Some(attr) = def.attributes.get_mut(attr_name) {
+        let member = EnumMemberDef {
+            caption: caption.to_string(),
+            description: String::new(),
+        };
+        attr.enum_values = Some(BTreeMap::from([(value.to_string(), member)]));
+    }
+}
+
+pub(crate) fn read_json(path: &Path) -> Result<Value, Error> {
+    let content = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&content).map_err(|source| Error::Json {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The JSON files in `dir` and its subdirectories, sorted by path
+pub(crate) fn json_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(json_files(&path));
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+struct ExtensionResolver<'a> {
+    ext: &'a str,
+    dictionary: &'a Value,
+    base_dictionary: &'a Value,
+    new_objects: &'a BTreeSet<String>,
+}
+
+impl ExtensionResolver<'_> {
+    /// Objects and classes of the extension shadow those of the schema with the same name
+    fn find<'d>(&self, defs: &'d BTreeMap<String, ClassDef>, name: &str) -> Option<&'d ClassDef> {
+        defs.get(&format!("{}/{}", self.ext, name))
+            .or_else(|| defs.get(name))
+    }
+
+    fn define(&self, def: &mut ClassDef, name: &str, file: &Value) {
+        def.name = name.to_string();
+        def.extension = Some(self.ext.to_string());
+        def.caption = file["caption"].as_str().unwrap_or(name).to_string();
+        def.description = file["description"].as_str().unwrap_or_default().to_string();
+        def.deprecated = serde_json::from_value(file["@deprecated"].clone()).unwrap_or_default();
+        if let Ok(constraints) = serde_json::from_value(file["constraints"].clone()) {
+            def.constraints = constraints;
+        }
+    }
+
+    /// Attributes listed by `file` override those `def` has, or are looked up in the extension's
+    /// dictionary,
+    /// then in any class or object of the schema. `tag` marks new attributes as added by the
+    /// extension.
+    fn merge_attributes(
+        &self,
+        schema: &OcsfSchema,
+        def: &mut ClassDef,
+        file: &Value,
+        tag: bool,
+    ) -> Result<(), String> {
+        for (attr_name, overrides) in file["attributes"].as_object().into_iter().flatten() {
+            let mut attr = match def.attributes.get(attr_name) {
+                Some(attr) => attr.clone(),
+                None => {
+                    let mut attr = self.dictionary_attribute(schema, attr_name)?;
+                    if tag {
+                        attr.extension = Some(self.ext.to_string());
+                    }
+                    attr
+                }
+            };
+            for (key, value) in overrides.as_object().into_iter().flatten() {
+                match (key.as_str(), value) {
+                    ("requirement", Value::String(value)) => attr.requirement = value.clone(),
+                    ("caption", Value::String(value)) => attr.caption = value.clone(),
+                    ("description", Value::String(value)) => attr.description = value.clone(),
+                    ("profile", Value::String(value)) => attr.profile = Some(value.clone()),
+                    ("enum", value) => {
+                        attr.enum_values = serde_json::from_value(value.clone()).ok()
+                    }
+                    _ => {}
+                }
+            }
+            def.attributes.insert(attr_name.clone(), attr);
+        }
+        Ok(())
+    }
+
+    fn dictionary_attribute(
+        &self,
+        schema: &OcsfSchema,
+        attr_name: &str,
+    ) -> Result<AttributeDef, String> {
+        let Some(entry) = self
+            .dictionary
+            .get(attr_name)
+            .or_else(|| self.base_dictionary.get(attr_name))
+        else {
+            return schema
+                .classes
+                .values()
+                .chain(schema.objects.values())
+                .find_map(|def| def.attributes.get(attr_name))
+                .cloned()
+                .ok_or_else(|| {
+                    format!(
+                        "attribute {} is in neither the extension dictionary nor the schema",
+                        attr_name
+                    )
+                });
+        };
+        let mut entry = entry.clone();
+        if entry["type"] == "object_t"
+            && let Some(object_type) = entry["object_type"].as_str()
+        {
+            let object_type = if self.new_objects.contains(object_type) {
+                format!("{}/{}", self.ext, object_type)
+            } else {
+                object_type.to_string()
+            };
+            entry["type"] = object_type.into();
+        }
+        if entry.get("requirement").is_none() {
+            entry["requirement"] = "optional".into();
+        }
+        serde_json::from_value(entry)
+            .map_err(|err| format!("dictionary attribute {}: {}", attr_name, err))
+    }
+}
+
+/// Attributes that refer to an object of an extension are added by that extension, unless their
+/// class or object is part of it.
+/// Only attributes added from outside the extension keep the tag.
+fn tag_extension_attributes(schema: &mut OcsfSchema) {
+    let object_extensions: BTreeMap<String, String> = schema
+        .objects
+        .iter()
+        .filter_map(|(name, def)| Some((name.clone(), def.extension.clone()?)))
+        .collect();
+    for def in schema
+        .classes
+        .values_mut()
+        .chain(schema.objects.values_mut())
+    {
+        for attr in def.attributes.values_mut() {
+            if attr.extension.is_none() {
+                attr.extension = object_extensions.get(&attr.type_name).cloned();
+            }
+            if attr.extension == def.extension {
+                attr.extension = None;
+            }
+        }
+    }
+}
+
+/// The `types` of a `dictionary.json`, which an extension's dictionary may leave out
+pub(crate) fn read_types(
+    dictionary: &Value,
+    path: &Path,
+) -> Result<BTreeMap<String, TypeDef>, Error> {
+    match dictionary["types"].get("attributes") {
+        Some(types) => serde_json::from_value(types.clone()).map_err(|source| Error::Json {
+            path: path.to_path_buf(),
+            source,
+        }),
+        None => Ok(BTreeMap::new()),
+    }
+}
@@ -0,0 +1,455 @@
+use ocsf_codegen::{Codegen, Error};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const SCHEMA: &str = r#"{
+    "version": "1.7.0",
+    "classes": {
+        "file_activity": {
+            "name": "file_activity",
+            "caption": "File System Activity",
+            "category": "system",
+            "uid": 1001,
+            "constraints": {"at_least_one": ["file.owner.name", "src_ip"]},
+            "attributes": {
+                "class_uid": {"type": "integer_t", "requirement": "required", "enum": {"1001": {"caption": "File System Activity"}}},
+                "category_uid": {"type": "integer_t", "requirement": "required", "enum": {"1": {"caption": "System Activity"}}},
+                "activity_id": {"type": "integer_t", "requirement": "required", "enum": {"0": {"caption": "Unknown"}, "1": {"caption": "Create"}, "99": {"caption": "Other"}}},
+                "severity_id": {"type": "integer_t", "requirement": "required", "enum": {"0": {"caption": "Unknown"}, "4": {"caption": "High"}, "99": {"caption": "Other"}}},
+                "file": {"type": "file", "requirement": "required"},
+                "observables": {"type": "observable", "is_array": true, "requirement": "recommended"},
+                "src_ip": {"type": "ip_t", "requirement": "optional"}
+            }
+        },
+        "process_activity": {
+            "name": "process_activity",
+            "caption": "Process Activity",
+            "category": "system",
+            "uid": 1007,
+            "attributes": {
+                "activity_id": {"type": "integer_t", "requirement": "required", "enum": {"1": {"caption": "Launch"}}},
+                "severity_id": {"type": "integer_t", "requirement": "required", "enum": {"0": {"caption": "Unknown"}, "4": {"caption": "High"}, "99": {"caption": "Other"}}},
+                "process": {"type": "process", "requirement": "required"}
+            }
+        }
+    },
+    "objects": {
+        "file": {"name": "file", "caption": "File", "attributes": {"name": {"type": "file_name_t"}, "owner": {"type": "user"}}},
+        "user": {"name": "user", "caption": "User", "attributes": {"name": {"type": "username_t"}}},
+        "process": {"name": "process", "caption": "Process", "attributes": {"pid": {"type": "integer_t"}}},
+        "observable": {"name": "observable", "caption": "Observable", "attributes": {
+            "name": {"type": "string_t"},
+            "type_id": {"type": "integer_t", "requirement": "required", "enum": {"0": {"caption": "Unknown"}, "4": {"caption": "User Name"}, "7": {"caption": "File Name"}, "99": {"caption": "Other"}}},
+            "value": {"type": "string_t"}
+        }}
+    },
+    "types": {
+        "file_name_t": {"observable": 7},
+        "integer_t": {},
+        "ip_t": {"observable": 2},
+        "username_t": {"observable": 4}
+    }
+}"#;
+
+// A directory of its own per test, holding the schema
+fn scratch(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ocsf-codegen-{}-{}", std::process::id(), test));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("resolved.json"), SCHEMA).unwrap();
+    dir
+}
+
+#[test]
+fn allow_list_keeps_the_objects_its_classes_reach() {
+    let dir = scratch("allow_list");
+    Codegen::new(dir.join("resolved.json"))
+        .class("file_activity")
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(dir.join("ocsf.rs")).unwrap();
+    assert!(code.starts_with("pub mod ocsf {"));
+    assert!(code.contains("pub struct FileActivity {"));
+    assert!(code.contains("pub struct File {"));
+    assert!(code.contains("pub struct User {"));
+    assert!(!code.contains("ProcessActivity"));
+    assert!(!code.contains("pub struct Process {"));
+    assert!(code.contains("use ::ocsf_types::validation::"));
+    assert!(!code.contains("cfg(feature"));
+    // dotted constraint names follow the objects they go through
+    let compact: String = code.split_whitespace().collect();
+    assert!(compact.contains(r#"("file.owner.name",self.file.as_ref().is_some_and(|value|"#));
+    assert!(compact.contains(".owner.as_ref().is_some_and(|value|value.name.is_some())"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn enumerated_ids_share_an_enum_unless_their_values_differ() {
+    let dir = scratch("enums");
+    Codegen::new(dir.join("resolved.json"))
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(dir.join("ocsf.rs")).unwrap();
+    assert!(code.contains("pub enum SeverityId {"));
+    assert!(code.contains("pub severity_id: Option<SeverityId>,"));
+    assert!(code.contains("pub enum FileActivityActivityId {"));
+    assert!(code.contains("pub enum ProcessActivityActivityId {"));
+    assert!(code.contains("Unrecognized(i64),"));
+    assert!(code.contains("SeverityId::High => Some(\"High\")"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn unknown_classes_and_types_are_errors() {
+    let dir = scratch("errors");
+    let codegen = Codegen::new(dir.join("resolved.json")).output_file(dir.join("ocsf.rs"));
+    let err = codegen.clone().class("nope").generate().unwrap_err();
+    assert!(matches!(err, Error::UnknownClass(name) if name == "nope"));
+    let err = codegen
+        .clone()
+        .type_override("file", "String")
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, Error::UnknownType(name) if name == "file"));
+    let err = codegen.derive("Eq)").generate().unwrap_err();
+    assert!(matches!(err, Error::InvalidTokens(_)));
+    let err = Codegen::new(dir.join("missing.json"))
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, Error::Io { .. }));
+    fs::write(
+        dir.join("bad.json"),
+        SCHEMA.replace("file.owner.name", "file.owner.nmae"),
+    )
+    .unwrap();
+    let err = Codegen::new(dir.join("bad.json"))
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, Error::Schema { message, .. } if message.contains("file.owner.nmae")));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn type_overrides_and_derives() {
+    let dir = scratch("overrides");
+    Codegen::new(dir.join("resolved.json"))
+        .type_override("ip_t", "std::net::IpAddr")
+        .derive("Eq")
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(dir.join("ocsf.rs")).unwrap();
+    assert!(code.contains("pub src_ip: Option<std::net::IpAddr>,"));
+    assert!(code.contains("pub fn src_ip(mut self, value: impl Into<std::net::IpAddr>) -> Self {"));
+    assert!(
+        code.contains("#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, Eq)]")
+    );
+    // the overridden type is opaque to format checks and path access
+    assert!(!code.contains(r#"check_format(path, "src_ip""#));
+    assert!(!code.contains(r#""src_ip" => segment"#));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+// The generated code of the tests above, built and run in a crate of its own against the
+// ocsf-types of this workspace
+const FIXTURE_MAIN: &str = r#"
+include!("ocsf.rs");
+use ocsf::*;
+use ocsf_types::OcsfClass;
+use ocsf_types::observable::Observables;
+
+fn is_eq<T: Eq>(_: &T) {}
+
+fn main() {
+    let mut event = FileActivity::new_event();
+    event.src_ip = Some("10.0.0.1".parse().unwrap());
+    event.file = Some(Box::new(File { name: Some("a.txt".to_string()), ..Default::default() }));
+    is_eq(&event);
+    let json = serde_json::to_string(&event).unwrap();
+    let OcsfEvent::FileActivity(parsed) = serde_json::from_str(&json).unwrap() else {
+        panic!("not a file activity: {}", json);
+    };
+    assert_eq!(parsed.src_ip, Some(std::net::IpAddr::from([10, 0, 0, 1])));
+    // the overridden ip_t is not collected, file_name_t is by its dictionary type
+    let observables: Vec<Observable> = parsed.observables();
+    assert_eq!(serde_json::to_value(&observables).unwrap(), serde_json::json!([{"name": "file.name", "type_id": 7, "value": "a.txt"}]));
+    // migrations look classes up in the schema of the generated version
+    assert_eq!(<OcsfEvent as ocsf_types::schema::EventSchema>::schema().classes().len(), 1);
+    let mut value = serde_json::json!({"class_uid": 1001, "activity_id": 1});
+    assert!(ocsf_types::migrate::upgrade(&mut value.clone()).is_err());
+    assert!(ocsf_types::migrate::upgrade_in::<OcsfEvent>(&mut value).unwrap().is_empty());
+}
+"#;
+
+#[test]
+fn generated_code_compiles_with_classes_overrides_and_derives() {
+    let dir = scratch("fixture");
+    let fixture = dir.join("fixture");
+    fs::create_dir_all(fixture.join("src")).unwrap();
+    Codegen::new(dir.join("resolved.json"))
+        .classes(["file_activity"])
+        .type_override("ip_t", "std::net::IpAddr")
+        .derive("Eq")
+        .output_file(fixture.join("src/ocsf.rs"))
+        .generate()
+        .unwrap();
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let manifest = format!(
+        "[package]\nname = \"fixture\"\nversion = \"0.0.0\"\nedition = \"2024\"\n\n[dependencies]\n\
+         ocsf-types = {{ path = '{}', default-features = false }}\n\
+         serde = {{ version = \"1\", features = [\"derive\"] }}\nserde_json = \"1\"\n",
+        workspace.display()
+    );
+    fs::write(fixture.join("Cargo.toml"), manifest).unwrap();
+    fs::write(fixture.join("src/main.rs"), FIXTURE_MAIN).unwrap();
+    // the versions the workspace resolved, so that the build needs no registry update
+    if workspace.join("Cargo.lock").exists() {
+        fs::copy(workspace.join("Cargo.lock"), fixture.join("Cargo.lock")).unwrap();
+    }
+    let output = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(fixture.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", workspace.join("target/codegen-fixture"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn output_dir_with_cargo_features() {
+    let dir = scratch("output_dir");
+    Codegen::new(dir.join("resolved.json"))
+        .runtime("crate")
+        .cargo_features(true)
+        .output_dir(dir.join("ocsf_generated"))
+        .generate()
+        .unwrap();
+    let module = dir.join("ocsf_generated");
+    for file in ["mod.rs", "common.rs", "system.rs", "schema_tables.rs"] {
+        assert!(module.join(file).exists(), "{} is missing", file);
+    }
+    let root = fs::read_to_string(module.join("mod.rs")).unwrap();
+    assert!(root.contains("#[cfg(feature = \"system\")]\npub mod system;"));
+    assert!(root.contains("use crate::validation::"));
+    let system = fs::read_to_string(module.join("system.rs")).unwrap();
+    assert!(system.contains("pub struct ProcessActivity {"));
+    assert!(system.contains("#[cfg(feature = \"preserve-unknown\")]"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+// Writes an ocsf-schema directory, as laid out before `python -m ocsf.compile` resolves it
+fn schema_tree(dir: &std::path::Path) {
+    let files = [
+        ("version.json", r#"{"version": "1.7.0"}"#),
+        (
+            "categories.json",
+            r#"{"attributes": {"system": {"uid": 1, "caption": "System Activity"}}}"#,
+        ),
+        (
+            "dictionary.json",
+            r#"{"attributes": {
+                "activity_id": {"type": "integer_t"},
+                "class_uid": {"type": "integer_t"},
+                "category_uid": {"type": "integer_t"},
+                "type_uid": {"type": "integer_t"},
+                "file": {"type": "object_t", "object_type": "file"},
+                "hostname": {"type": "hostname_t"},
+                "name": {"type": "string_t"},
+                "uid": {"type": "string_t"},
+                "type_id": {"type": "integer_t"},
+                "value": {"type": "string_t"},
+                "beacon": {"type": "object_t", "object_type": "beacon"}
+            },
+            "types": {"attributes": {
+                "hostname_t": {"caption": "Hostname", "type": "string_t", "observable": 1},
+                "string_t": {"caption": "String"}
+            }}}"#,
+        ),
+        (
+            "profiles/host.json",
+            r#"{"name": "host", "meta": "profile", "attributes": {"hostname": {"requirement": "recommended"}}}"#,
+        ),
+        (
+            "events/base_event.json",
+            r#"{"name": "base_event", "caption": "Base Event", "category": "other", "uid": 0, "attributes": {
+                "$include": ["profiles/host.json"],
+                "activity_id": {"requirement": "required", "enum": {"0": {"caption": "Unknown"}}},
+                "class_uid": {"requirement": "required"},
+                "category_uid": {"requirement": "required"},
+                "type_uid": {"requirement": "required"}
+            }}"#,
+        ),
+        (
+            "events/system/system.json",
+            r#"{"name": "system", "extends": "base_event", "caption": "System Activity", "category": "system"}"#,
+        ),
+        (
+            "events/system/file_activity.json",
+            r#"{"name": "file_activity", "extends": "system", "caption": "File System Activity", "uid": 1, "attributes": {
+                "activity_id": {"enum": {"1": {"caption": "Create"}}},
+                "file": {"requirement": "required"}
+            }}"#,
+        ),
+        (
+            "objects/_entity.json",
+            r#"{"name": "_entity", "attributes": {"name": {}, "uid": {}}}"#,
+        ),
+        (
+            "objects/file.json",
+            r#"{"name": "file", "extends": "_entity", "caption": "File"}"#,
+        ),
+        (
+            "objects/observable.json",
+            r#"{"name": "observable", "caption": "Observable", "attributes": {"name": {}, "type_id": {}, "value": {}}}"#,
+        ),
+        (
+            "extensions/acme/extension.json",
+            r#"{"name": "acme", "uid": 99}"#,
+        ),
+        (
+            "extensions/acme/objects/beacon.json",
+            r#"{"name": "beacon", "extends": "_entity", "caption": "Beacon"}"#,
+        ),
+        (
+            "extensions/acme/events/beacon_activity.json",
+            r#"{"name": "beacon_activity", "extends": "system", "caption": "Beacon Activity", "uid": 1, "attributes": {
+                "beacon": {"requirement": "required"}
+            }}"#,
+        ),
+    ];
+    for (path, content) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+#[test]
+fn compiles_an_ocsf_schema_directory() {
+    let dir = scratch("schema_tree");
+    let schema = dir.join("ocsf-schema");
+    schema_tree(&schema);
+    Codegen::new(&schema)
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(dir.join("ocsf.rs")).unwrap();
+    assert!(code.contains("pub struct FileActivity {"));
+    assert!(code.contains("const CLASS_UID: i64 = 1001;"));
+    assert!(code.contains("pub file: Option<Box<File>>,"));
+    assert!(code.contains("impl HostProfile for FileActivity {"));
+    // observable by the type in the dictionary
+    let compact: String = code.split_whitespace().collect();
+    assert!(compact.contains(r#"found.add(path,"hostname",1,self.hostname.as_ref());"#));
+    assert!(code.contains("impl From<FoundObservable> for Observable {"));
+    // the category's class and `_entity` only exist to be extended
+    assert!(!code.contains("pub struct System {"));
+    assert!(!code.contains("Entity"));
+    assert!(code.contains("pub struct File {"));
+    assert!(code.contains("pub uid: Option<String>,"));
+    assert!(code.contains("pub struct AcmeBeaconActivity {"));
+    assert!(code.contains("const CLASS_UID: i64 = 9901001;"));
+    assert!(code.contains("pub struct AcmeBeacon {"));
+
+    Codegen::new(&schema)
+        .ignore_extension("acme")
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(dir.join("ocsf.rs")).unwrap();
+    assert!(!code.contains("AcmeBeaconActivity"));
+
+    fs::remove_file(schema.join("events/system/system.json")).unwrap();
+    let err = Codegen::new(&schema)
+        .output_file(dir.join("ocsf.rs"))
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, Error::Schema { message, .. } if message.contains("system")));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+// What a resolved schema defines, by the keys of its classes and objects: their attributes,
+// profiles and extension
+fn outline(schema: &serde_json::Value) -> std::collections::BTreeSet<String> {
+    let mut outline = std::collections::BTreeSet::new();
+    for section in ["classes", "objects"] {
+        for (name, def) in schema[section].as_object().into_iter().flatten() {
+            outline.insert(format!("{} {}", section, name));
+            for attr in def["attributes"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(attr, _)| attr)
+            {
+                outline.insert(format!("{} {}.{}", section, name, attr));
+            }
+            for profile in def["profiles"].as_array().into_iter().flatten() {
+                outline.insert(format!("{} {} profile {}", section, name, profile));
+            }
+            if let Some(extension) = def["extension"].as_str() {
+                outline.insert(format!("{} {} extension {}", section, name, extension));
+            }
+        }
+    }
+    outline
+}
+
+// The differences between the outlines, prefixed with the side that has them
+fn outline_diff(native: &serde_json::Value, expected: &serde_json::Value) -> Vec<String> {
+    let (native, expected) = (outline(native), outline(expected));
+    let only_native = native
+        .difference(&expected)
+        .map(|line| format!("+ {}", line));
+    let only_expected = expected
+        .difference(&native)
+        .map(|line| format!("- {}", line));
+    only_native.chain(only_expected).collect()
+}
+
+#[test]
+fn resolves_a_schema_directory_like_ocsf_compile() {
+    let dir = scratch("resolve");
+    let schema = dir.join("ocsf-schema");
+    schema_tree(&schema);
+    let resolved = Codegen::new(&schema).resolve().unwrap();
+    // what `python -m ocsf.compile` makes of the tree: profile attributes are merged into the
+    // classes that include them, and the classes and objects of an extension are keyed by it
+    let expected = serde_json::json!({
+        "classes": {
+            "base_event": {"attributes": {"activity_id": {}, "category_uid": {}, "class_uid": {}, "hostname": {}, "type_uid": {}}, "profiles": ["host"]},
+            "file_activity": {"attributes": {"activity_id": {}, "category_uid": {}, "class_uid": {}, "file": {}, "hostname": {}, "type_uid": {}}, "profiles": ["host"]},
+            "acme/beacon_activity": {"attributes": {"activity_id": {}, "beacon": {}, "category_uid": {}, "class_uid": {}, "hostname": {}, "type_uid": {}}, "profiles": ["host"], "extension": "acme"}
+        },
+        "objects": {
+            "file": {"attributes": {"name": {}, "uid": {}}},
+            "observable": {"attributes": {"name": {}, "type_id": {}, "value": {}}},
+            "acme/beacon": {"attributes": {"name": {}, "uid": {}}, "extension": "acme"}
+        }
+    });
+    assert_eq!(outline_diff(&resolved, &expected), Vec::<String>::new());
+    assert_eq!(resolved["types"]["hostname_t"]["observable"], 1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+// Compares the compiler with `python -m ocsf.compile` on a whole ocsf-schema checkout.
+// OCSF_SCHEMA names the checkout, and OCSF_RESOLVED the resolved.json that ocsf-lib compiled
+// from it, e.g. `python -m ocsf.compile ocsf-schema > resolved.json`.
+#[test]
+#[ignore = "needs an ocsf-schema checkout and the resolved.json of ocsf-lib, see OCSF_SCHEMA and OCSF_RESOLVED"]
+fn resolves_ocsf_schema_like_ocsf_compile() {
+    let schema = std::env::var_os("OCSF_SCHEMA").expect("OCSF_SCHEMA is not set");
+    let expected = std::env::var_os("OCSF_RESOLVED").expect("OCSF_RESOLVED is not set");
+    let expected: serde_json::Value = serde_json::from_slice(&fs::read(expected).unwrap()).unwrap();
+    let resolved = Codegen::new(schema).resolve().unwrap();
+    assert_eq!(resolved["version"], expected["version"]);
+    assert_eq!(outline_diff(&resolved, &expected), Vec::<String>::new());
+}
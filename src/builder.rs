@@ -0,0 +1,59 @@
+//! # Builders
+//! Every generated struct has a builder that wraps values in `Option` and `Box` for you.
+//! ```rust
+//! # #[cfg(feature = "system")]
+//! # {
+//! use ocsf_types::{FileActivity, Metadata, Product};
+//! let metadata = Metadata::builder()
+//!     .product(Product::builder().name("edr").vendor_name("acme").build_unchecked())
+//!     .version("1.7.0")
+//!     .build()
+//!     .expect("product and version are set");
+//! assert_eq!(metadata.product.as_ref().and_then(|p| p.name.as_deref()), Some("edr"));
+//!
+//! let err = FileActivity::builder().metadata(metadata).build().unwrap_err();
+//! assert!(err.missing.contains(&"activity_id"));
+//! # }
+//! ```
+use std::error::Error;
+use std::fmt;
+
+/// Returned by `build()` when required attributes were never set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    /// The struct being built, e.g. `FileActivity`
+    pub type_name: &'static str,
+    /// The required attributes that are missing, e.g. `metadata`
+    pub missing: Vec<&'static str>,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is missing required attributes: {}",
+            self.type_name,
+            self.missing.join(", ")
+        )
+    }
+}
+
+impl Error for BuildError {}
+
+/// `fields` pairs each required attribute with whether it is set
+#[doc(hidden)]
+pub fn check_required(
+    type_name: &'static str,
+    fields: &[(&'static str, bool)],
+) -> Result<(), BuildError> {
+    let missing: Vec<&'static str> = fields
+        .iter()
+        .filter(|(_, present)| !present)
+        .map(|(name, _)| *name)
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(BuildError { type_name, missing })
+    }
+}
@@ -0,0 +1,55 @@
+//! # Event Classes
+//! Schema constants shared by every event of a class.
+//! ```rust
+//! # #[cfg(feature = "iam")]
+//! # {
+//! use ocsf_types::{AccountChange, OcsfClass};
+//! fn table_name<T: OcsfClass>() -> String {
+//!     format!("{}_{}", T::CATEGORY_NAME, T::CLASS_NAME)
+//! }
+//! assert_eq!(AccountChange::CLASS_UID, 3001);
+//! assert_eq!(table_name::<AccountChange>(), "iam_account_change");
+//! # }
+//! ```
+
+/// Implemented for every generated event class.
+pub trait OcsfClass {
+    /// The class unique identifier, e.g. `3001`
+    const CLASS_UID: i64;
+    /// The category unique identifier, e.g. `3`
+    const CATEGORY_UID: i64;
+    /// The class name, e.g. `account_change`
+    const CLASS_NAME: &'static str;
+    /// The category name, e.g. `iam`
+    const CATEGORY_NAME: &'static str;
+    /// The class caption, e.g. `Account Change`
+    const CAPTION: &'static str;
+    /// The category caption, e.g. `Identity & Access Management`
+    const CATEGORY_CAPTION: &'static str;
+}
+
+/// An event as found, parsed no further than its `class_uid`
+#[doc(hidden)]
+pub struct RawEvent {
+    pub raw: Box<serde_json::value::RawValue>,
+    /// `None` when missing, not an integer, or when the event is not an object
+    pub class_uid: Option<i64>,
+}
+
+impl<'de> serde::Deserialize<'de> for RawEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Every value but the `class_uid` is skipped
+        #[derive(serde::Deserialize)]
+        struct Peek {
+            class_uid: Option<serde_json::Value>,
+        }
+        let raw = Box::<serde_json::value::RawValue>::deserialize(deserializer)?;
+        let class_uid = if raw.get().starts_with('{') {
+            let peek: Peek = serde_json::from_str(raw.get()).map_err(serde::de::Error::custom)?;
+            peek.class_uid.as_ref().and_then(serde_json::Value::as_i64)
+        } else {
+            None
+        };
+        Ok(RawEvent { raw, class_uid })
+    }
+}
@@ -0,0 +1,175 @@
+//! # Timestamps
+//! `timestamp_t` attributes, such as `time` or `start_time`, hold milliseconds since the Unix epoch
+//! and map to [`Timestamp`]. `datetime_t` attributes, such as `time_dt`, hold an RFC 3339 string
+//! and map to [`DateTime`].
+//!
+//! A [`DateTime`] is lenient in the same way as the [network values](crate::network): text that is not
+//! RFC 3339 is kept as found and serialized back unchanged, and so is the offset and precision of text
+//! that is. [`Validate`](crate::Validate) reports such values, and [`strict`](crate::strict) parsing
+//! rejects them.
+//!
+//! With the `chrono` feature both convert to and from `chrono::DateTime`.
+//! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! use ocsf_types::{FileActivity, Timestamp};
+//! let event: FileActivity = serde_json::from_str(r#"{"time": 1767051583891}"#).unwrap();
+//! let time = event.time.unwrap();
+//! assert_eq!(time, Timestamp::from_millis(1767051583891));
+//! assert_eq!(time.to_datetime().unwrap().to_rfc3339(), "2025-12-29T23:39:43.891+00:00");
+//! # }
+//! ```
+use crate::network::{ParseError, text_value};
+use crate::validation::ScalarFormat;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Milliseconds since the Unix epoch, serialized as an integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub fn from_millis(millis: i64) -> Self {
+        Timestamp(millis)
+    }
+
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// `None` when the timestamp is outside the range chrono can represent
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp_millis(self.0)
+    }
+}
+
+impl From<i64> for Timestamp {
+    fn from(millis: i64) -> Self {
+        Timestamp(millis)
+    }
+}
+
+impl From<Timestamp> for i64 {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+/// Truncates to whole milliseconds
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Timestamp(datetime.timestamp_millis())
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A `datetime_t` value, an RFC 3339 date and time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    text: String,
+    parsed: Option<Parsed>,
+}
+
+text_value!(DateTime, "datetime_t", parse_datetime);
+
+#[cfg(feature = "chrono")]
+type Parsed = chrono::DateTime<chrono::FixedOffset>;
+
+#[cfg(not(feature = "chrono"))]
+type Parsed = ();
+
+#[cfg(feature = "chrono")]
+fn parse_datetime(text: &str) -> Option<Parsed> {
+    chrono::DateTime::parse_from_rfc3339(text).ok()
+}
+
+// Checks the form `2025-12-29T23:39:43.891+01:00` without chrono at hand
+#[cfg(not(feature = "chrono"))]
+fn parse_datetime(text: &str) -> Option<Parsed> {
+    fn number(digits: &[u8]) -> Option<u32> {
+        digits.iter().try_fold(0, |n, &c| {
+            c.is_ascii_digit().then(|| n * 10 + u32::from(c - b'0'))
+        })
+    }
+    let b = text.as_bytes();
+    let (year, month, day) = (
+        number(b.get(0..4)?)?,
+        number(b.get(5..7)?)?,
+        number(b.get(8..10)?)?,
+    );
+    let (hour, minute, second) = (
+        number(b.get(11..13)?)?,
+        number(b.get(14..16)?)?,
+        number(b.get(17..19)?)?,
+    );
+    let separators = b[4] == b'-'
+        && b[7] == b'-'
+        && matches!(b[10], b'T' | b't' | b' ')
+        && b[13] == b':'
+        && b[16] == b':';
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => 0,
+    };
+    let mut rest = &b[19..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        rest = &fraction[len..];
+    }
+    let offset = match rest {
+        b"Z" | b"z" => true,
+        [b'+' | b'-', h1, h2, b':', m1, m2] => {
+            number(&[*h1, *h2]).is_some_and(|h| h < 24)
+                && number(&[*m1, *m2]).is_some_and(|m| m < 60)
+        }
+        _ => false,
+    };
+    let valid = separators
+        && offset
+        && (1..=days).contains(&day)
+        && hour < 24
+        && minute < 60
+        && second <= 60;
+    valid.then_some(())
+}
+
+#[cfg(feature = "chrono")]
+impl DateTime {
+    /// `None` when the text is not RFC 3339
+    pub fn to_chrono(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        self.parsed
+    }
+
+    /// `None` when the text is not RFC 3339
+    pub fn to_timestamp(&self) -> Option<Timestamp> {
+        self.parsed.map(Timestamp::from)
+    }
+}
+
+/// Written with `Z` for a UTC offset, and as many fractional digits as needed
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        let datetime = datetime.fixed_offset();
+        DateTime {
+            text: datetime.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+            parsed: Some(datetime),
+        }
+    }
+}
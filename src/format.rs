@@ -0,0 +1,116 @@
+//! # String Formats
+//! Rules for the string types of the OCSF dictionary that restrict their values, such as `email_t` or `uuid_t`.
+//! The attributes stay `String`. [`Validate`](crate::Validate) checks them against these rules,
+//! and [`strict`](crate::strict) parsing rejects values that break them.
+//! ```rust
+//! use ocsf_types::format::StringFormat;
+//! assert!(StringFormat::Uuid.is_valid("f3b2a4e0-8d3c-4f4e-9a1b-2c3d4e5f6a7b"));
+//! assert!(!StringFormat::Email.is_valid("not an email"));
+//! assert_eq!(StringFormat::Hostname.type_name(), "hostname_t");
+//! ```
+
+/// A string type of the OCSF dictionary with a rule for its values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StringFormat {
+    /// `email_t`, e.g. `john_doe@example.com`
+    Email,
+    /// `uuid_t`, e.g. `123e4567-e89b-12d3-a456-426614174000`
+    Uuid,
+    /// `url_t`, an absolute URL with a scheme, e.g. `https://example.com/index.html`
+    Url,
+    /// `hostname_t`, e.g. `www.example.com`
+    Hostname,
+    /// `file_hash_t`, e.g. `3172ac7e2b55cbb81f04a6e65855a628`. See [`is_valid_file_hash`] to check it against its algorithm.
+    FileHash,
+}
+
+impl StringFormat {
+    /// The type name as it appears in the schema
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            StringFormat::Email => "email_t",
+            StringFormat::Uuid => "uuid_t",
+            StringFormat::Url => "url_t",
+            StringFormat::Hostname => "hostname_t",
+            StringFormat::FileHash => "file_hash_t",
+        }
+    }
+
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            StringFormat::Email => is_email(value),
+            StringFormat::Uuid => is_uuid(value),
+            StringFormat::Url => is_url(value),
+            StringFormat::Hostname => is_hostname(value),
+            StringFormat::FileHash => is_valid_file_hash(value, None),
+        }
+    }
+}
+
+/// Checks a `file_hash_t` against the `algorithm_id` of its fingerprint.
+///
+/// Digests with a fixed size, such as MD5 or SHA-256, must be hex strings of that size.
+/// Other algorithms, or a missing one, only need a value without whitespace.
+pub fn is_valid_file_hash(value: &str, algorithm_id: Option<i64>) -> bool {
+    let hex_len = match algorithm_id {
+        Some(1) | Some(13) => 32, // MD5, xxHash H3 128-bit
+        Some(2) => 40,            // SHA-1
+        Some(3) | Some(11) => 64, // SHA-256, SHA-512/256
+        Some(4) => 128,           // SHA-512
+        Some(8) | Some(10) => 56, // SHA-224, SHA-512/224
+        Some(9) => 96,            // SHA-384
+        Some(12) => 16,           // xxHash H3 64-bit
+        _ => return !value.is_empty() && !value.contains(char::is_whitespace),
+    };
+    value.len() == hex_len && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.rsplit_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+        && domain.contains('.')
+        && is_hostname(domain)
+}
+
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.iter().map(|g| g.len()).eq([8, 4, 4, 4, 12])
+        && groups
+            .iter()
+            .all(|g| g.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        && !rest.is_empty()
+        && !value.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// Dot-separated labels of letters, digits, `-` and `_`, with an optional trailing dot.
+/// `_` is not allowed by RFC 1123, but common in Windows and service names.
+fn is_hostname(value: &str) -> bool {
+    let name = value.strip_suffix('.').unwrap_or(value);
+    !name.is_empty()
+        && name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        })
+}
@@ -3,22 +3,62 @@
 //! The types are generated programmatically from the official OCSF schema.
 //! ## Usage
 //! ```rust
+//! # #[cfg(feature = "iam")]
+//! # {
 //! use ocsf_types::AccountChange;
-//! let mut event = AccountChange::default();
-//! event.activity_id = Some(1);
-//! event.class_uid = Some(1001);
+//! let mut event = AccountChange::new_event();
+//! event.activity_id = Some(1.into());
+//! assert_eq!(event.class_uid, Some(3001));
 //! event.message = Some("User password changed".to_string());
+//! # }
 //! ```
 //! ## Features
 //! - **Strongly Typed**
 //! - **Serde Integration**
 //! - **Built from Official OCSF Schema**
-#![recursion_limit = "512"]
+//! - **Builders** for every struct, e.g. `FileActivity::builder()`, see [`builder`]
+//! - **Category Features** to compile only the classes of some OCSF categories, e.g. `system` and `network`
+//! - **Class Constants** for generic code over event classes, see [`OcsfClass`]
+//! - **Code Generation** of these types from your own schema and extensions with the `ocsf-codegen` crate
+//! - **Extensions** such as `win` behind a cargo feature each, with their classes tagged in the [`schema`]
+//! - **Migrations** of events from deprecated classes to the classes that replace them, see [`migrate`]
+//! - **Network Values** for `ip_t`, `mac_t`, `subnet_t` and `port_t` with CIDR matching, see [`network`]
+//! - **Normalization** of captions from their `*_id` attributes, see [`Normalize`]
+//! - **Observables** built from the attributes the schema marks as observable, see [`Observables`]
+//! - **Path Access** to attributes by dotted path, e.g. `actor.user.name`, see [`path`]
+//! - **Profiles** as traits such as [`HostProfile`], and validation of the profiles an event applies, see [`Validate`]
+//! - **Schema Introspection** of classes, objects and attributes at runtime, see [`schema`]
+//! - **Schema Version** named by [`SCHEMA_VERSION`], with the types also reachable as the [`v1_7`] module
+//! - **Strict Parsing** that rejects keys the schema does not define, see [`strict`]
+//! - **Typed Timestamps** that convert to chrono with the `chrono` feature, see [`datetime`]
+//! - **Validation** against the schema's required attributes, class constraints and string formats, see [`Validate`]
+//! - **Visitors** over every nested object of an event, see [`visit`]
+pub mod builder;
+pub mod class;
+pub mod datetime;
+pub mod format;
+pub mod migrate;
+pub mod network;
+pub mod normalize;
+pub mod observable;
 pub mod ocsf_generated;
+pub mod path;
+pub mod schema;
+pub mod strict;
+pub mod validation;
+pub use builder::BuildError;
+pub use class::OcsfClass;
+pub use datetime::{DateTime, Timestamp};
+pub use network::{IpAddress, MacAddress, Port, Subnet};
+pub use normalize::{CaptionConflict, Normalize};
+pub use observable::Observables;
 pub use ocsf_generated::*;
+/// The types of OCSF 1.7.0, the version the crate root re-exports
+pub use ocsf_generated as v1_7;
+pub use validation::{ConstraintRule, Validate, ValidationReport, Violation, ViolationKind};
 
 
-#[cfg(doctest)]
+#[cfg(all(doctest, feature = "iam"))]
 mod readme_tests{
     #[doc = include_str!("../README.md")]
     struct ReadmeDoctests;
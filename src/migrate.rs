@@ -0,0 +1,740 @@
+//! # Migrations
+//! Upgrades events of deprecated classes, such as `file_query` or `security_finding`, to the class
+//! that replaces them, and stamps an older `metadata.version` with [`SCHEMA_VERSION`](crate::SCHEMA_VERSION) once the
+//! event conforms to it.
+//! Attributes move to their counterpart in the new class, e.g. `file` to `query_evidence.file`,
+//! and every attribute the new class does not define, nested ones included, moves into `unmapped`.
+//! So does an `activity_id` or `status_id` whose value means something else in the new class,
+//! with its caption, and the id is set to `Unknown`.
+//! The `name` of an observable follows the attribute it names, and an observable whose attribute
+//! moved into `unmapped` is removed.
+//! [`upgrade_in`] and [`upgrade_to_in`] upgrade to the schema of another generated version.
+//! Both classes must be compiled, see [`MigrationError::UnknownClass`] and [`MigrationError::NotInSchema`].
+//! ```rust
+//! # #[cfg(feature = "discovery")]
+//! # {
+//! use ocsf_types::migrate::{self, Change};
+//! let mut event = serde_json::json!({
+//!     "class_uid": 5007, "category_uid": 5, "activity_id": 1,
+//!     "file": {"name": "a.txt", "type_id": 1},
+//!     "vendor_field": true,
+//! });
+//! let report = migrate::upgrade(&mut event).unwrap();
+//! assert_eq!(event["class_uid"], 5040);
+//! assert_eq!(event["query_evidence"]["file"]["name"], "a.txt");
+//! assert_eq!(event["unmapped"]["vendor_field"], true);
+//! assert!(report.changes.contains(&Change::Unmapped { path: "vendor_field".to_string() }));
+//! # }
+//! ```
+use crate::OcsfEvent;
+use crate::schema::{AttributeInfo, ClassInfo, EventSchema, Schema};
+use crate::validation::{attr_path, index_path};
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::fmt;
+
+/// A deprecated class and one of the classes that replace it
+struct Migration {
+    from: &'static str,
+    to: &'static str,
+    /// Attributes of `from` and where they live in `to`. A trailing `[]` appends to an array.
+    moves: &'static [(&'static str, &'static str)],
+    /// `query_evidence.query_type_id` and its caption, for the discovery queries
+    query_type: Option<(i64, &'static str)>,
+}
+
+const fn query(from: &'static str, query_type: (i64, &'static str)) -> Migration {
+    Migration {
+        from,
+        to: "evidence_info",
+        moves: &[],
+        query_type: Some(query_type),
+    }
+}
+
+const SECURITY_FINDING_MOVES: &[(&str, &str)] = &[
+    ("finding", "finding_info"),
+    ("analytic", "finding_info.analytic"),
+    ("data_sources", "finding_info.data_sources"),
+    ("kill_chain", "finding_info.kill_chain"),
+    ("state_id", "status_id"),
+    ("state", "status"),
+];
+
+const fn security_finding(to: &'static str) -> Migration {
+    Migration {
+        from: "security_finding",
+        to,
+        moves: SECURITY_FINDING_MOVES,
+        query_type: None,
+    }
+}
+
+/// The first migration of a class is the one [`upgrade`] applies
+const MIGRATIONS: &[Migration] = &[
+    query("admin_group_query", (4, "Admin Group")),
+    Migration {
+        from: "config_state",
+        to: "compliance_finding",
+        moves: &[("assessments", "compliance.assessments")],
+        query_type: None,
+    },
+    Migration {
+        from: "email_file_activity",
+        to: "email_activity",
+        moves: &[("email_uid", "email.uid"), ("file", "email.files[]")],
+        query_type: None,
+    },
+    Migration {
+        from: "email_url_activity",
+        to: "email_activity",
+        moves: &[("email_uid", "email.uid"), ("url", "email.urls[]")],
+        query_type: None,
+    },
+    query("file_query", (2, "File")),
+    query("folder_query", (3, "Folder")),
+    query("job_query", (5, "Job")),
+    query("kernel_object_query", (1, "Kernel Object")),
+    query("module_query", (6, "Module")),
+    Migration {
+        from: "network_connection_query",
+        to: "evidence_info",
+        moves: &[("state_id", "tcp_state_id")],
+        query_type: Some((7, "Network Connection")),
+    },
+    Migration {
+        from: "network_file_activity",
+        to: "file_hosting",
+        moves: &[],
+        query_type: None,
+    },
+    query("networks_query", (8, "Network Interfaces")),
+    query("peripheral_device_query", (9, "Peripheral Device")),
+    query("process_query", (10, "Process")),
+    security_finding("detection_finding"),
+    security_finding("compliance_finding"),
+    security_finding("vulnerability_finding"),
+    security_finding("data_security_finding"),
+    security_finding("incident_finding"),
+    query("service_query", (11, "Service")),
+    query("session_query", (12, "Session")),
+    query("startup_item_query", (15, "Startup Item")),
+    query("user_query", (13, "User")),
+    Migration {
+        from: "web_resource_access_activity",
+        to: "web_resources_activity",
+        moves: &[("proxy", "proxy_endpoint")],
+        query_type: None,
+    },
+    query("win/prefetch_query", (18, "Prefetch")),
+    query("win/registry_key_query", (16, "Registry Key")),
+    query("win/registry_value_query", (17, "Registry Value")),
+];
+
+/// Attributes of the discovery queries that stay at the top level of `evidence_info`
+const QUERY_ATTRIBUTES: &[&str] = &["query_info", "query_result", "query_result_id"];
+
+/// Enum attributes whose values the new class may define differently, with their caption.
+/// A value whose caption differs moves into `unmapped`, e.g. the `Access Grant` activity of a
+/// `web_resource_access_activity`, which is `Create` in `web_resources_activity`.
+const ENUM_ATTRIBUTES: &[(&str, &str)] =
+    &[("activity_id", "activity_name"), ("status_id", "status")];
+
+/// One change made to an event
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Change {
+    /// The event moved to another class, e.g. from `file_query` to `evidence_info`
+    Class {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// An attribute moved to its counterpart in the new class, e.g. `file` to `query_evidence.file`
+    Moved { from: String, to: String },
+    /// An attribute the new class does not define moved into `unmapped`, keyed by its path
+    Unmapped { path: String },
+    /// An attribute set to a value the new class or version derives, e.g. `type_uid`
+    Set { path: String, value: Value },
+    /// An attribute that only described the old class, e.g. its `type_name`
+    Removed { path: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Class { from, to } => write!(f, "class {} -> {}", from, to),
+            Change::Moved { from, to } => write!(f, "moved {} -> {}", from, to),
+            Change::Unmapped { path } => write!(f, "moved {} -> unmapped", path),
+            Change::Set { path, value } => write!(f, "set {} = {}", path, value),
+            Change::Removed { path } => write!(f, "removed {}", path),
+        }
+    }
+}
+
+/// What an upgrade changed, in the order it changed it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    pub changes: Vec<Change>,
+}
+
+impl MigrationReport {
+    /// No class or attribute changed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The paths that moved into `unmapped`
+    pub fn unmapped(&self) -> impl Iterator<Item = &str> {
+        self.changes.iter().filter_map(|change| match change {
+            Change::Unmapped { path } => Some(path.as_str()),
+            _ => None,
+        })
+    }
+
+    fn push(&mut self, change: Change) {
+        self.changes.push(change);
+    }
+}
+
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The event is not a JSON object with a `class_uid`
+    NotAnEvent,
+    /// The schema has no class with the event's `class_uid`, or its cargo feature is disabled
+    UnknownClass(i64),
+    /// `to` does not replace the class `from`, see [`replacements`]
+    NotAReplacement { from: &'static str, to: String },
+    /// `unmapped` is set to something other than an object, or already has a key for `path`
+    UnmappedConflict { path: String },
+    /// `path` is already set to a value that the upgrade cannot merge with, e.g. a `query_evidence` of another `query_type_id`
+    Conflict { path: String },
+    /// The schema the crate was generated from has no class or object of this name, which the migration needs,
+    /// or its cargo feature is disabled
+    NotInSchema(&'static str),
+    /// The event does not match the types of the schema
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::NotAnEvent => {
+                f.write_str("not an event: expected an object with a class_uid")
+            }
+            MigrationError::UnknownClass(uid) => write!(f, "unknown class_uid {}", uid),
+            MigrationError::NotAReplacement { from, to } => {
+                write!(f, "{} is not replaced by {}", from, to)
+            }
+            MigrationError::UnmappedConflict { path } => {
+                write!(f, "cannot move {} into unmapped", path)
+            }
+            MigrationError::Conflict { path } => write!(f, "{} is already set", path),
+            MigrationError::NotInSchema(name) => write!(f, "the schema has no {}", name),
+            MigrationError::Json(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for MigrationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MigrationError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for MigrationError {
+    fn from(err: serde_json::Error) -> Self {
+        MigrationError::Json(err)
+    }
+}
+
+/// The classes that replace the class named `class`, the one [`upgrade`] picks first.
+/// Empty when the class is not deprecated.
+pub fn replacements(class: &str) -> Vec<&'static str> {
+    MIGRATIONS
+        .iter()
+        .filter(|migration| migration.from == class)
+        .map(|migration| migration.to)
+        .collect()
+}
+
+/// Upgrades `event` to the first of the [`replacements`] of its class, if it has any,
+/// and stamps an older `metadata.version` with [`SCHEMA_VERSION`](crate::SCHEMA_VERSION).
+/// The version is only stamped when the upgraded event parses with [`strict`](crate::strict)
+/// parsing and its [`validate`](crate::Validate::validate) report has no errors.
+/// A `security_finding` becomes a `detection_finding`, use [`upgrade_to`] for another of its replacements.
+///
+/// The event is left untouched when an error is returned.
+pub fn upgrade(event: &mut Value) -> Result<MigrationReport, MigrationError> {
+    upgrade_in::<OcsfEvent>(event)
+}
+
+/// Like [`upgrade`], to the schema of another generated version, the one of the event type `E`,
+/// e.g. `upgrade_in::<my_types::v1_6::OcsfEvent>(&mut event)`
+pub fn upgrade_in<E: EventSchema>(event: &mut Value) -> Result<MigrationReport, MigrationError> {
+    let class = event_class(E::schema(), event)?;
+    let migration = MIGRATIONS
+        .iter()
+        .find(|migration| migration.from == class.name());
+    apply::<E>(event, class, migration)
+}
+
+/// Like [`upgrade`], but to the replacement named `to`, e.g. `detection_finding` for a `security_finding`
+pub fn upgrade_to(event: &mut Value, to: &str) -> Result<MigrationReport, MigrationError> {
+    upgrade_to_in::<OcsfEvent>(event, to)
+}
+
+/// Like [`upgrade_to`], to the schema of the event type `E`, see [`upgrade_in`]
+pub fn upgrade_to_in<E: EventSchema>(
+    event: &mut Value,
+    to: &str,
+) -> Result<MigrationReport, MigrationError> {
+    let class = event_class(E::schema(), event)?;
+    let migration = MIGRATIONS
+        .iter()
+        .find(|migration| migration.from == class.name() && migration.to == to)
+        .ok_or_else(|| MigrationError::NotAReplacement {
+            from: class.name(),
+            to: to.to_string(),
+        })?;
+    apply::<E>(event, class, Some(migration))
+}
+
+/// Like [`upgrade`], for an event already parsed into its deprecated class, e.g. an [`OcsfEvent`]
+pub fn upgrade_event<E: EventSchema>(event: &E) -> Result<(E, MigrationReport), MigrationError> {
+    let mut value = serde_json::to_value(event)?;
+    let report = upgrade_in::<E>(&mut value)?;
+    Ok((serde_json::from_value(value)?, report))
+}
+
+fn event_class(schema: &Schema, event: &Value) -> Result<&'static ClassInfo, MigrationError> {
+    let uid = event
+        .get("class_uid")
+        .and_then(Value::as_i64)
+        .ok_or(MigrationError::NotAnEvent)?;
+    schema
+        .class_by_uid(uid)
+        .ok_or(MigrationError::UnknownClass(uid))
+}
+
+fn apply<E: EventSchema>(
+    event: &mut Value,
+    from: &ClassInfo,
+    migration: Option<&Migration>,
+) -> Result<MigrationReport, MigrationError> {
+    let schema = E::schema();
+    let mut upgraded = event
+        .as_object()
+        .cloned()
+        .ok_or(MigrationError::NotAnEvent)?;
+    let mut report = MigrationReport::default();
+    if let Some(migration) = migration {
+        let to = schema
+            .class(migration.to)
+            .ok_or(MigrationError::NotInSchema(migration.to))?;
+        report.push(Change::Class {
+            from: migration.from,
+            to: migration.to,
+        });
+        // Before the moves, so that e.g. `state_id` finds `status_id` free
+        let mut unmapped = take_redefined(&mut upgraded, from, migration, to);
+        move_attributes(schema, &mut upgraded, migration, &mut report)?;
+        // An id that nothing moved into is left `Unknown`
+        for (id, _) in ENUM_ATTRIBUTES {
+            if unmapped.iter().any(|(path, _)| path == id) && !upgraded.contains_key(*id) {
+                set(&mut upgraded, id, 0.into(), &mut report);
+            }
+        }
+        set_class(&mut upgraded, to, &mut report);
+        let attribute = |name: &str| to.attribute(name);
+        take_undefined(schema, &mut upgraded, "", &attribute, &mut unmapped);
+        add_unmapped(&mut upgraded, unmapped, &mut report)?;
+        rename_observables(schema, &mut upgraded, from, to, &mut report);
+    }
+    stamp_version::<E>(&mut upgraded, &mut report);
+    *event = Value::Object(upgraded);
+    Ok(report)
+}
+
+fn set(event: &mut Map<String, Value>, path: &str, value: Value, report: &mut MigrationReport) {
+    if event.get(path) != Some(&value) {
+        event.insert(path.to_string(), value.clone());
+        report.push(Change::Set {
+            path: path.to_string(),
+            value,
+        });
+    }
+}
+
+/// Sets the uids and captions that name the class, and the type derived from `activity_id`
+fn set_class(event: &mut Map<String, Value>, class: &ClassInfo, report: &mut MigrationReport) {
+    set(event, "class_uid", class.uid().into(), report);
+    set(event, "class_name", class.caption().into(), report);
+    set(event, "category_uid", class.category_uid().into(), report);
+    let category_caption = class
+        .attribute("category_uid")
+        .and_then(|attr| caption(attr.enum_values(), class.category_uid()));
+    match category_caption {
+        Some(category_caption) => set(event, "category_name", category_caption.into(), report),
+        None => remove(event, "category_name", report),
+    }
+    let Some(activity_id) = event.get("activity_id").and_then(Value::as_i64) else {
+        return;
+    };
+    set(
+        event,
+        "type_uid",
+        (class.uid() * 100 + activity_id).into(),
+        report,
+    );
+    let activity_caption = class
+        .attribute("activity_id")
+        .and_then(|attr| caption(attr.enum_values(), activity_id));
+    match activity_caption {
+        Some(activity_caption) => {
+            let type_name = format!("{}: {}", class.caption(), activity_caption);
+            set(event, "type_name", type_name.into(), report);
+        }
+        None => remove(event, "type_name", report),
+    }
+}
+
+/// Takes the [`ENUM_ATTRIBUTES`] whose value means something else in `to`, or that an attribute
+/// of the event moves into, with their caption
+fn take_redefined(
+    event: &mut Map<String, Value>,
+    from: &ClassInfo,
+    migration: &Migration,
+    to: &ClassInfo,
+) -> Vec<(String, Value)> {
+    let mut taken = Vec::new();
+    for (id, name) in ENUM_ATTRIBUTES {
+        let (Some(old), Some(new)) = (from.attribute(id), to.attribute(id)) else {
+            continue;
+        };
+        let redefined = event.get(*id).and_then(Value::as_i64).is_some_and(|value| {
+            caption(old.enum_values(), value) != caption(new.enum_values(), value)
+        });
+        let moved_into = migration.moves.iter().any(|(source, target)| {
+            (target == id || target == name) && event.contains_key(*source)
+        });
+        if !redefined && !moved_into {
+            continue;
+        }
+        for path in [id, name] {
+            if let Some(value) = event.remove(*path) {
+                taken.push((path.to_string(), value));
+            }
+        }
+    }
+    taken
+}
+
+fn caption(values: &[(i64, &'static str)], id: i64) -> Option<&'static str> {
+    values
+        .iter()
+        .find(|(value, _)| *value == id)
+        .map(|(_, caption)| *caption)
+}
+
+fn remove(event: &mut Map<String, Value>, path: &str, report: &mut MigrationReport) {
+    if event.remove(path).is_some() {
+        report.push(Change::Removed {
+            path: path.to_string(),
+        });
+    }
+}
+
+fn move_attributes(
+    schema: &Schema,
+    event: &mut Map<String, Value>,
+    migration: &Migration,
+    report: &mut MigrationReport,
+) -> Result<(), MigrationError> {
+    if let Some((query_type_id, query_type)) = migration.query_type {
+        let query_evidence = schema
+            .object("query_evidence")
+            .ok_or(MigrationError::NotInSchema("query_evidence"))?;
+        // An existing `query_evidence` is merged with, its attributes win over the moved ones
+        let mut evidence = match event.remove("query_evidence") {
+            None => Map::new(),
+            Some(Value::Object(evidence)) => evidence,
+            Some(_) => {
+                return Err(MigrationError::Conflict {
+                    path: "query_evidence".to_string(),
+                });
+            }
+        };
+        match evidence.get("query_type_id") {
+            None => {
+                evidence.insert("query_type_id".to_string(), query_type_id.into());
+                evidence
+                    .entry("query_type")
+                    .or_insert_with(|| query_type.into());
+            }
+            Some(existing) if existing.as_i64() == Some(query_type_id) => {}
+            Some(_) => {
+                return Err(MigrationError::Conflict {
+                    path: "query_evidence.query_type_id".to_string(),
+                });
+            }
+        }
+        let names: Vec<String> = event.keys().cloned().collect();
+        for name in names {
+            if is_base_attribute(schema, &name) || QUERY_ATTRIBUTES.contains(&name.as_str()) {
+                continue;
+            }
+            let to = migration
+                .moves
+                .iter()
+                .find(|(from, _)| *from == name)
+                .map_or(name.as_str(), |(_, to)| to);
+            if query_evidence.attribute(to).is_some() && !evidence.contains_key(to) {
+                let value = event.remove(&name).expect("the key was just listed");
+                evidence.insert(to.to_string(), value);
+                report.push(Change::Moved {
+                    to: attr_path("query_evidence", to),
+                    from: name,
+                });
+            }
+        }
+        event.insert("query_evidence".to_string(), Value::Object(evidence));
+        return Ok(());
+    }
+    for (from, to) in migration.moves {
+        let Some(value) = event.remove(*from) else {
+            continue;
+        };
+        if insert_at(event, to, value.clone()) {
+            report.push(Change::Moved {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        } else {
+            // The counterpart is already set, the value stays to be moved into unmapped
+            event.insert(from.to_string(), value);
+        }
+    }
+    Ok(())
+}
+
+/// Attributes of `base_event`, which every class has
+fn is_base_attribute(schema: &Schema, name: &str) -> bool {
+    schema
+        .class("base_event")
+        .is_some_and(|base| base.attribute(name).is_some())
+}
+
+/// Inserts `value` at the dotted `path`, creating the objects on the way.
+/// `false` when the path is already set or runs through a value that is not an object.
+fn insert_at(event: &mut Map<String, Value>, path: &str, value: Value) -> bool {
+    if let Some((first, rest)) = path.split_once('.') {
+        let child = event
+            .entry(first.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        return child
+            .as_object_mut()
+            .is_some_and(|child| insert_at(child, rest, value));
+    }
+    if let Some(array) = path.strip_suffix("[]") {
+        let items = event
+            .entry(array.to_string())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        return items
+            .as_array_mut()
+            .map(|items| items.push(value))
+            .is_some();
+    }
+    if event.contains_key(path) {
+        return false;
+    }
+    event.insert(path.to_string(), value);
+    true
+}
+
+/// Removes every key of `value`, or nested in its objects, that `attribute` does not define,
+/// adding it with its path to `unmapped`
+fn take_undefined(
+    schema: &Schema,
+    value: &mut Map<String, Value>,
+    path: &str,
+    attribute: &dyn Fn(&str) -> Option<&'static AttributeInfo>,
+    unmapped: &mut Vec<(String, Value)>,
+) {
+    let names: Vec<String> = value.keys().cloned().collect();
+    for name in names {
+        let attr_path = attr_path(path, &name);
+        let Some(attr) = attribute(&name) else {
+            let taken = value.remove(&name).expect("the key was just listed");
+            unmapped.push((attr_path, taken));
+            continue;
+        };
+        let Some(object) = attr.object_type().and_then(|name| schema.object(name)) else {
+            continue;
+        };
+        let attribute = |name: &str| object.attribute(name);
+        match value.get_mut(&name) {
+            Some(Value::Object(child)) => {
+                take_undefined(schema, child, &attr_path, &attribute, unmapped)
+            }
+            Some(Value::Array(items)) => {
+                for (i, item) in items.iter_mut().enumerate() {
+                    if let Value::Object(child) = item {
+                        let path = index_path(&attr_path, i);
+                        take_undefined(schema, child, &path, &attribute, unmapped);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn add_unmapped(
+    event: &mut Map<String, Value>,
+    taken: Vec<(String, Value)>,
+    report: &mut MigrationReport,
+) -> Result<(), MigrationError> {
+    if taken.is_empty() {
+        return Ok(());
+    }
+    let unmapped = event
+        .entry("unmapped".to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    for (path, value) in taken {
+        let conflict = || MigrationError::UnmappedConflict { path: path.clone() };
+        let unmapped = unmapped.as_object_mut().ok_or_else(conflict)?;
+        if unmapped.contains_key(&path) {
+            return Err(conflict());
+        }
+        unmapped.insert(path.clone(), value);
+        report.push(Change::Unmapped { path });
+    }
+    Ok(())
+}
+
+/// Sets `metadata.version` to the version of `E` when the event names an older version and
+/// conforms to the one of `E`, see [`conforms`].
+/// A newer version, or one that is not `major.minor.patch`, is kept.
+fn stamp_version<E: EventSchema>(event: &mut Map<String, Value>, report: &mut MigrationReport) {
+    let version = E::schema().version();
+    let older = event
+        .get("metadata")
+        .and_then(|metadata| metadata.get("version"))
+        .and_then(Value::as_str)
+        .is_some_and(|found| is_older(found, version));
+    if !older || !conforms::<E>(event) {
+        return;
+    }
+    if let Some(metadata) = event.get_mut("metadata").and_then(Value::as_object_mut) {
+        metadata.insert("version".to_string(), version.into());
+        report.push(Change::Set {
+            path: "metadata.version".to_string(),
+            value: version.into(),
+        });
+    }
+}
+
+/// `true` when the event parses into `E` with [`strict`](crate::strict) parsing and has no
+/// validation errors. Warnings, such as a missing recommended attribute, are allowed.
+/// The class is one of the schema of `E`, so the event does not stay unknown.
+fn conforms<E: EventSchema>(event: &Map<String, Value>) -> bool {
+    crate::strict::from_value::<E>(Value::Object(event.clone()))
+        .is_ok_and(|parsed| parsed.validate().is_valid())
+}
+
+/// Compares `major.minor.patch` versions. A pre-release such as `1.7.0-dev` is older than its release.
+fn is_older(version: &str, than: &str) -> bool {
+    fn parse(version: &str) -> Option<([u64; 3], bool)> {
+        let (numbers, pre_release) = match version.split_once('-') {
+            Some((numbers, _)) => (numbers, true),
+            None => (version, false),
+        };
+        let mut parts = numbers.split('.').map(|part| part.parse().ok());
+        let numbers = [parts.next()??, parts.next()??, parts.next()??];
+        parts.next().is_none().then_some((numbers, pre_release))
+    }
+    match (parse(version), parse(than)) {
+        // a release sorts after its pre-releases
+        (Some((version, pre)), Some((than, than_pre))) => (version, !pre) < (than, !than_pre),
+        _ => false,
+    }
+}
+
+/// Renames the observables that name a moved attribute after its new path, and removes those
+/// that name an attribute of the old class which the new class does not define
+fn rename_observables(
+    schema: &Schema,
+    event: &mut Map<String, Value>,
+    from: &ClassInfo,
+    to: &ClassInfo,
+    report: &mut MigrationReport,
+) {
+    let renames: Vec<(String, String)> = report
+        .changes
+        .iter()
+        .filter_map(|change| match change {
+            Change::Moved { from, to } => {
+                Some((from.clone(), to.trim_end_matches("[]").to_string()))
+            }
+            _ => None,
+        })
+        .collect();
+    let Some(Value::Array(observables)) = event.get_mut("observables") else {
+        return;
+    };
+    let (mut index, mut kept) = (0, 0);
+    observables.retain_mut(|observable| {
+        let old_index = index;
+        index += 1;
+        let Some(name) = observable.get("name").and_then(Value::as_str) else {
+            kept += 1;
+            return true;
+        };
+        let renamed = renames
+            .iter()
+            .find_map(|(old, new)| match name.strip_prefix(old.as_str()) {
+                Some(rest) if rest.is_empty() || rest.starts_with('.') => {
+                    Some(format!("{}{}", new, rest))
+                }
+                _ => None,
+            });
+        let new_name = renamed.as_deref().unwrap_or(name);
+        if is_attribute_path(schema, from, name) && !is_attribute_path(schema, to, new_name) {
+            report.push(Change::Removed {
+                path: index_path("observables", old_index),
+            });
+            return false;
+        }
+        if let Some(renamed) = renamed {
+            observable["name"] = renamed.clone().into();
+            report.push(Change::Set {
+                path: attr_path(&index_path("observables", kept), "name"),
+                value: renamed.into(),
+            });
+        }
+        kept += 1;
+        true
+    });
+}
+
+/// `true` when the dotted `path`, without array indices, names an attribute of `class`
+fn is_attribute_path(schema: &Schema, class: &ClassInfo, path: &str) -> bool {
+    let mut segments = path.split('.');
+    let Some(mut attr) = segments.next().and_then(|first| class.attribute(first)) else {
+        return false;
+    };
+    for segment in segments {
+        let Some(next) = attr
+            .object_type()
+            .and_then(|name| schema.object(name))
+            .and_then(|object| object.attribute(segment))
+        else {
+            return false;
+        };
+        attr = next;
+    }
+    true
+}
@@ -0,0 +1,308 @@
+//! # Network Values
+//! Types for the `ip_t`, `mac_t`, `subnet_t` and `port_t` attributes.
+//!
+//! Deserializing is lenient: a value that is not well formed is kept as found and serialized back unchanged,
+//! so no event fails to parse because of it. [`Validate`](crate::Validate) reports such values, and
+//! [`strict`](crate::strict) parsing rejects them. [`str::parse`] is strict as well.
+//! ```rust
+//! use ocsf_types::{NetworkEndpoint, Subnet};
+//! let endpoint: NetworkEndpoint = serde_json::from_str(r#"{"ip": "10.1.2.3", "port": 443}"#).unwrap();
+//! let subnet: Subnet = "10.0.0.0/8".parse().unwrap();
+//! let ip = endpoint.ip.as_ref().and_then(|ip| ip.addr()).unwrap();
+//! assert!(subnet.contains(ip));
+//! assert_eq!(endpoint.port.and_then(|port| port.get()), Some(443));
+//! ```
+use crate::validation::ScalarFormat;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// A value that is not valid for its schema type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The schema type, e.g. `ip_t`
+    pub expected: &'static str,
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {:?}", self.expected, self.found)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Implements the parts shared by the types that keep their original text next to the parsed value
+macro_rules! text_value {
+    ($name:ident, $type_name:literal, $parse:path) => {
+        impl $name {
+            /// The text the value was created from
+            pub fn as_str(&self) -> &str {
+                &self.text
+            }
+
+            /// `false` when the text is not valid for the schema type
+            pub fn is_valid(&self) -> bool {
+                self.parsed.is_some()
+            }
+        }
+
+        /// Lenient: text that is not valid is kept, see [`is_valid`](Self::is_valid)
+        impl From<String> for $name {
+            fn from(text: String) -> Self {
+                let parsed = $parse(&text);
+                $name { text, parsed }
+            }
+        }
+
+        /// Lenient: text that is not valid is kept, see [`is_valid`](Self::is_valid)
+        impl From<&str> for $name {
+            fn from(text: &str) -> Self {
+                $name::from(text.to_string())
+            }
+        }
+
+        /// Strict: fails on text that is not valid
+        impl FromStr for $name {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                let value = $name::from(s);
+                if value.is_valid() {
+                    Ok(value)
+                } else {
+                    Err(ParseError {
+                        expected: $type_name,
+                        found: value.text,
+                    })
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.text)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.text)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map($name::from)
+            }
+        }
+
+        impl ScalarFormat for $name {
+            const TYPE_NAME: &'static str = $type_name;
+
+            fn is_valid(&self) -> bool {
+                self.parsed.is_some()
+            }
+        }
+    };
+}
+
+pub(crate) use text_value;
+
+/// An `ip_t` value, an IPv4 or IPv6 address
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IpAddress {
+    text: String,
+    parsed: Option<IpAddr>,
+}
+
+text_value!(IpAddress, "ip_t", parse_ip);
+
+fn parse_ip(text: &str) -> Option<IpAddr> {
+    text.parse().ok()
+}
+
+impl IpAddress {
+    /// `None` when the text is not an address
+    pub fn addr(&self) -> Option<IpAddr> {
+        self.parsed
+    }
+}
+
+impl From<IpAddr> for IpAddress {
+    fn from(addr: IpAddr) -> Self {
+        IpAddress {
+            text: addr.to_string(),
+            parsed: Some(addr),
+        }
+    }
+}
+
+impl From<Ipv4Addr> for IpAddress {
+    fn from(addr: Ipv4Addr) -> Self {
+        IpAddress::from(IpAddr::V4(addr))
+    }
+}
+
+impl From<Ipv6Addr> for IpAddress {
+    fn from(addr: Ipv6Addr) -> Self {
+        IpAddress::from(IpAddr::V6(addr))
+    }
+}
+
+/// A `mac_t` value, six octets written as hex pairs separated by `:` or `-`, e.g. `18:36:F3:98:4F:9A`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacAddress {
+    text: String,
+    parsed: Option<[u8; 6]>,
+}
+
+text_value!(MacAddress, "mac_t", parse_mac);
+
+fn parse_mac(text: &str) -> Option<[u8; 6]> {
+    let separator = if text.contains('-') { '-' } else { ':' };
+    let mut octets = [0; 6];
+    let mut parts = text.split(separator);
+    for octet in &mut octets {
+        let part = parts.next()?;
+        if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        *octet = u8::from_str_radix(part, 16).ok()?;
+    }
+    match parts.next() {
+        None => Some(octets),
+        Some(_) => None,
+    }
+}
+
+impl MacAddress {
+    /// `None` when the text is not a MAC address
+    pub fn octets(&self) -> Option<[u8; 6]> {
+        self.parsed
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    fn from(octets: [u8; 6]) -> Self {
+        let text = octets
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect::<Vec<_>>()
+            .join(":");
+        MacAddress {
+            text,
+            parsed: Some(octets),
+        }
+    }
+}
+
+/// A `subnet_t` value, an address and prefix length in CIDR notation, e.g. `192.168.200.0/24`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Subnet {
+    text: String,
+    parsed: Option<(IpAddr, u8)>,
+}
+
+text_value!(Subnet, "subnet_t", parse_subnet);
+
+fn parse_subnet(text: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix_len) = text.split_once('/')?;
+    let addr: IpAddr = addr.parse().ok()?;
+    let prefix_len: u8 = prefix_len.parse().ok()?;
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    (prefix_len <= max).then_some((addr, prefix_len))
+}
+
+impl Subnet {
+    /// The address with the host bits cleared. `None` when the text is not a subnet.
+    pub fn network(&self) -> Option<IpAddr> {
+        let (addr, prefix_len) = self.parsed?;
+        Some(mask(addr, prefix_len))
+    }
+
+    /// `None` when the text is not a subnet
+    pub fn prefix_len(&self) -> Option<u8> {
+        self.parsed.map(|(_, prefix_len)| prefix_len)
+    }
+
+    /// `true` when `addr` is in this subnet. Always `false` when the text is not a subnet.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        match self.parsed {
+            Some((network, prefix_len)) if network.is_ipv4() == addr.is_ipv4() => {
+                mask(network, prefix_len) == mask(addr, prefix_len)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn mask(addr: IpAddr, prefix_len: u8) -> IpAddr {
+    match addr {
+        IpAddr::V4(addr) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix_len))
+                .unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(addr) & mask))
+        }
+        IpAddr::V6(addr) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix_len))
+                .unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(addr) & mask))
+        }
+    }
+}
+
+/// A `port_t` value. Numbers outside `0..=65535` are kept as found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Port(i64);
+
+impl Port {
+    /// `None` when the number is not a port
+    pub fn get(&self) -> Option<u16> {
+        u16::try_from(self.0).ok()
+    }
+
+    /// `false` when the number is not a port
+    pub fn is_valid(&self) -> bool {
+        self.get().is_some()
+    }
+}
+
+/// Lenient: numbers that are not ports are kept, see [`is_valid`](Self::is_valid)
+impl From<i64> for Port {
+    fn from(port: i64) -> Self {
+        Port(port)
+    }
+}
+
+impl From<u16> for Port {
+    fn from(port: u16) -> Self {
+        Port(i64::from(port))
+    }
+}
+
+impl From<Port> for i64 {
+    fn from(port: Port) -> Self {
+        port.0
+    }
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ScalarFormat for Port {
+    const TYPE_NAME: &'static str = "port_t";
+
+    fn is_valid(&self) -> bool {
+        self.get().is_some()
+    }
+}
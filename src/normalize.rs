@@ -0,0 +1,81 @@
+//! # Normalization
+//! Fills caption attributes, such as `activity_name`, `severity` or `status`, from their sibling `*_id` attribute.
+//! ```rust
+//! # #[cfg(feature = "iam")]
+//! # {
+//! use ocsf_types::{AccountChange, Normalize};
+//! let mut event = AccountChange::default();
+//! event.class_uid = Some(3001);
+//! let conflicts = event.normalize();
+//! assert_eq!(event.class_name.as_deref(), Some("Account Change"));
+//! assert!(conflicts.is_empty());
+//! # }
+//! ```
+use crate::validation::attr_path;
+use std::fmt;
+
+/// The id value that the schema reserves for `Other`, whose caption is defined by the event source
+const OTHER: i64 = 99;
+
+/// A caption that disagrees with the caption the schema gives its sibling id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptionConflict {
+    /// JSON path of the caption attribute, e.g. `file.type`
+    pub path: String,
+    /// The value of the sibling id
+    pub id: i64,
+    /// The caption the schema gives the id
+    pub expected: &'static str,
+    /// The caption stored in the event
+    pub found: String,
+}
+
+impl fmt::Display for CaptionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {:?} for id {}, found {:?}",
+            self.path, self.expected, self.id, self.found
+        )
+    }
+}
+
+/// Implemented for every generated struct.
+pub trait Normalize {
+    /// Fills every missing caption, here and in nested objects, from its sibling id.
+    ///
+    /// Captions that are already set are never overwritten. The ones that disagree with their id are returned.
+    fn normalize(&mut self) -> Vec<CaptionConflict> {
+        let mut conflicts = Vec::new();
+        self.normalize_at("", &mut conflicts);
+        conflicts
+    }
+
+    /// Normalizes this value as if it was found at `path`, adding conflicts to `conflicts`
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>);
+}
+
+/// `caption` is what the schema gives `id`, `None` when the id is missing or unknown to the schema
+#[doc(hidden)]
+pub fn fill_sibling(
+    conflicts: &mut Vec<CaptionConflict>,
+    path: &str,
+    attr: &str,
+    id: Option<i64>,
+    caption: Option<&'static str>,
+    sibling: &mut Option<String>,
+) {
+    let (Some(id), Some(expected)) = (id, caption) else {
+        return;
+    };
+    match sibling {
+        None => *sibling = Some(expected.to_string()),
+        Some(found) if found != expected && id != OTHER => conflicts.push(CaptionConflict {
+            path: attr_path(path, attr),
+            id,
+            expected,
+            found: found.clone(),
+        }),
+        Some(_) => {}
+    }
+}
@@ -0,0 +1,97 @@
+//! # Observables
+//! Builds the `observables` of an event from the attributes the schema marks as observable,
+//! either by their own annotation (e.g. `process.pid`) or by their type in the schema's dictionary
+//! (e.g. `ip_t`, `hostname_t`).
+//! ```rust
+//! # #[cfg(feature = "network")]
+//! # {
+//! use ocsf_types::observable::Observables;
+//! use ocsf_types::NetworkActivity;
+//! let mut event: NetworkActivity = serde_json::from_str(
+//!     r#"{"src_endpoint": {"ip": "10.0.0.1", "hostname": "web"}, "dst_endpoint": {"ip": "10.0.0.2"}}"#,
+//! ).unwrap();
+//! let observables = event.observables();
+//! let names: Vec<_> = observables.iter().filter_map(|o| o.name.as_deref()).collect();
+//! assert_eq!(names, ["dst_endpoint.ip", "src_endpoint.hostname", "src_endpoint.ip"]);
+//! event.observables = Some(observables);
+//! # }
+//! ```
+use crate::validation::attr_path;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Implemented for every generated struct.
+pub trait Observables {
+    /// The `observable` object of the schema the type was generated from, e.g.
+    /// [`Observable`](crate::Observable)
+    type Observable: From<FoundObservable>;
+
+    /// The observables found in this value and everything nested in it, in attribute order.
+    ///
+    /// `name` is the dotted path of the attribute, without array indices, e.g. `resources.uid`.
+    /// A value found more than once under the same name and type is listed once.
+    /// The existing `observables` of an event are not read.
+    fn observables(&self) -> Vec<Self::Observable> {
+        let mut found = ObservableSet::default();
+        self.collect_observables("", &mut found);
+        found.observables.into_iter().map(Into::into).collect()
+    }
+
+    /// Adds the observables of this value to `found`, as if it was found at `path`
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet);
+}
+
+/// An observable value, which the generated code turns into its `observable` object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundObservable {
+    /// The dotted path of the attribute, e.g. `src_endpoint.ip`
+    pub name: String,
+    pub type_id: i64,
+    pub value: String,
+}
+
+/// Observables collected so far, without duplicates
+#[derive(Debug, Default)]
+pub struct ObservableSet {
+    seen: HashSet<(String, i64, String)>,
+    observables: Vec<FoundObservable>,
+}
+
+impl ObservableSet {
+    #[doc(hidden)]
+    pub fn add<T: Display>(
+        &mut self,
+        path: &str,
+        attr: &str,
+        type_id: i64,
+        value: Option<&T>,
+    ) {
+        if let Some(value) = value {
+            self.insert(attr_path(path, attr), type_id, value.to_string());
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn add_all<T: Display>(
+        &mut self,
+        path: &str,
+        attr: &str,
+        type_id: i64,
+        values: Option<&[T]>,
+    ) {
+        for value in values.unwrap_or_default() {
+            self.insert(attr_path(path, attr), type_id, value.to_string());
+        }
+    }
+
+    fn insert(&mut self, name: String, type_id: i64, value: String) {
+        if value.is_empty() || !self.seen.insert((name.clone(), type_id, value.clone())) {
+            return;
+        }
+        self.observables.push(FoundObservable {
+            name,
+            type_id,
+            value,
+        });
+    }
+}
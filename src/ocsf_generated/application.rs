@@ -0,0 +1,10158 @@
+#![doc = "Classes of the `application` category, enabled by the `application` cargo feature"]
+#![allow(deprecated)]
+#![allow(unused_imports)]
+#![allow(clippy::only_used_in_recursion)]
+use super::*;
+#[doc = "API Activity\n\nAPI events describe general CRUD (Create, Read, Update, Delete) API activities, e.g. (AWS Cloudtrail)\n\n[UID:6003] Category: application | Name: api_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ApiActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ApiActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "AI Model\n\nThe AI Model object describes the characteristics of an AI/ML model. Examples include language models like GPT-4, embedding models like text-embedding-ada-002, and computer vision models like CLIP.\n\nrecommended"]
+    #[serde(rename = "ai_model")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_model: Option<Box<AiModel>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\nrequired"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>API Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nThe network destination endpoint.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying http request.\n\nrecommended"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying http response.\n\nrecommended"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Message Context\n\nCommunication context for AI system interactions including protocols, roles, clients, and session information for MCP and other AI communication systems.\n\noptional"]
+    #[serde(rename = "message_context")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_context: Option<Box<MessageContext>>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Resources Array\n\nDetails about resources that were affected by the activity/event.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the activity.\n\nrequired"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Trace\n\nThe trace object contains information about distributed traces which are critical to observability and describe how requests move through a system, capturing each step's timing and status.\n\nrecommended (`trace` profile)"]
+    #[serde(rename = "trace")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Box<Trace>>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ApiActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        report.check_recommended(path, "ai_model", self.ai_model.is_some());
+        if let Some(value) = &self.ai_model {
+            value.validate_at(&attr_path(path, "ai_model"), report);
+        }
+        report.check_required(path, "api", self.api.is_some());
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "http_request", self.http_request.is_some());
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        report.check_recommended(path, "http_response", self.http_response.is_some());
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        if let Some(value) = &self.message_context {
+            value.validate_at(&attr_path(path, "message_context"), report);
+        }
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_required(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_recommended_in_profile(path, "trace", "trace", self.trace.is_some());
+        if let Some(value) = &self.trace {
+            value.validate_at(&attr_path(path, "trace"), report);
+        }
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ApiActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.ai_model {
+            value.normalize_at(&attr_path(path, "ai_model"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6003) => Some("API Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.message_context {
+            value.normalize_at(&attr_path(path, "message_context"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.trace {
+            value.normalize_at(&attr_path(path, "trace"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600300) => Some("API Activity: Unknown"),
+                Some(600301) => Some("API Activity: Create"),
+                Some(600302) => Some("API Activity: Read"),
+                Some(600303) => Some("API Activity: Update"),
+                Some(600304) => Some("API Activity: Delete"),
+                Some(600399) => Some("API Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ApiActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ApiActivity {
+    const CLASS_UID: i64 = 6003;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "api_activity";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "API Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl ApiActivity {
+    pub fn builder() -> ApiActivityBuilder {
+        ApiActivityBuilder {
+            inner: ApiActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ApiActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ApiActivityBuilder {
+    inner: ApiActivity,
+}
+impl ApiActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ApiActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "AI Model (recommended)"]
+    pub fn ai_model(mut self, value: AiModel) -> Self {
+        self.inner.ai_model = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (required)"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (recommended)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (recommended)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Message Context (optional)"]
+    pub fn message_context(mut self, value: MessageContext) -> Self {
+        self.inner.message_context = Some(Box::new(value));
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Resources Array (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (required)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Trace (recommended (`trace` profile))"]
+    pub fn trace(mut self, value: Trace) -> Self {
+        self.inner.trace = Some(Box::new(value));
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ApiActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ApiActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("api", self.inner.api.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("src_endpoint", self.inner.src_endpoint.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ApiActivity {
+        self.inner
+    }
+}
+impl KnownFields for ApiActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "ai_model" => AiModel::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "message_context" => {
+                    MessageContext::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "trace" => Trace::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ApiActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "ai_model" => segment.object(self.ai_model.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "message_context" => segment.object(self.message_context.as_deref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "trace" => segment.object(self.trace.as_deref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "ai_model" => segment.set_object(&mut self.ai_model, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "message_context" => segment.set_object(&mut self.message_context, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "trace" => segment.set_object(&mut self.trace, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ApiActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.ai_model {
+            value.collect_observables(&attr_path(path, "ai_model"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.message_context {
+            value.collect_observables(&attr_path(path, "message_context"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.trace {
+            value.collect_observables(&attr_path(path, "trace"), found);
+        }
+    }
+}
+#[doc = "Application Error\n\nApplication Error events describe issues with an applications. The error message should be put in the event's <code>message</code> attribute. The <code>metadata.product</code> attribute can be used to capture the originating application information. The <code>host</code> profile can used to include the generating device information. This class is helpful for applications that generate or handle OCSF events and can also be used for errors in upstream products and services.\n\n[UID:6008] Category: application | Name: application_error"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ApplicationError {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ApplicationErrorActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Application Error</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe error message as reported by the application.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ApplicationError {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ApplicationError {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6008) => Some("Application Error"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600800) => Some("Application Error: Unknown"),
+                Some(600801) => Some("Application Error: General Error"),
+                Some(600802) => Some("Application Error: Translation Error"),
+                Some(600899) => Some("Application Error: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ApplicationError {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ApplicationError {
+    const CLASS_UID: i64 = 6008;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "application_error";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Application Error";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl ApplicationError {
+    pub fn builder() -> ApplicationErrorBuilder {
+        ApplicationErrorBuilder {
+            inner: ApplicationError::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ApplicationError`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ApplicationErrorBuilder {
+    inner: ApplicationError,
+}
+impl ApplicationErrorBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ApplicationErrorActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ApplicationError, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ApplicationError",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ApplicationError {
+        self.inner
+    }
+}
+impl KnownFields for ApplicationError {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ApplicationError {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ApplicationError {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Application Lifecycle\n\nApplication Lifecycle events report installation, removal, start, stop of an application or service.\n\n[UID:6002] Category: application | Name: application_lifecycle"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ApplicationLifecycle {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ApplicationLifecycleActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Application\n\nThe application that was affected by the lifecycle event.  This also applies to self-updating application systems.\n\nrequired"]
+    #[serde(rename = "app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<Box<Product>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Application Lifecycle</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ApplicationLifecycle {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        report.check_required(path, "app", self.app.is_some());
+        if let Some(value) = &self.app {
+            value.validate_at(&attr_path(path, "app"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ApplicationLifecycle {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.app {
+            value.normalize_at(&attr_path(path, "app"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6002) => Some("Application Lifecycle"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600200) => Some("Application Lifecycle: Unknown"),
+                Some(600201) => Some("Application Lifecycle: Install"),
+                Some(600202) => Some("Application Lifecycle: Remove"),
+                Some(600203) => Some("Application Lifecycle: Start"),
+                Some(600204) => Some("Application Lifecycle: Stop"),
+                Some(600205) => Some("Application Lifecycle: Restart"),
+                Some(600206) => Some("Application Lifecycle: Enable"),
+                Some(600207) => Some("Application Lifecycle: Disable"),
+                Some(600208) => Some("Application Lifecycle: Update"),
+                Some(600299) => Some("Application Lifecycle: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ApplicationLifecycle {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ApplicationLifecycle {
+    const CLASS_UID: i64 = 6002;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "application_lifecycle";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Application Lifecycle";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl ApplicationLifecycle {
+    pub fn builder() -> ApplicationLifecycleBuilder {
+        ApplicationLifecycleBuilder {
+            inner: ApplicationLifecycle::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ApplicationLifecycle`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ApplicationLifecycleBuilder {
+    inner: ApplicationLifecycle,
+}
+impl ApplicationLifecycleBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ApplicationLifecycleActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Application (required)"]
+    pub fn app(mut self, value: Product) -> Self {
+        self.inner.app = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ApplicationLifecycle, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ApplicationLifecycle",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("app", self.inner.app.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ApplicationLifecycle {
+        self.inner
+    }
+}
+impl KnownFields for ApplicationLifecycle {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "app" => Product::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ApplicationLifecycle {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "app" => segment.object(self.app.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "app" => segment.set_object(&mut self.app, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ApplicationLifecycle {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.app {
+            value.collect_observables(&attr_path(path, "app"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Datastore Activity\n\nDatastore events describe general activities (Read, Update, Query, Delete, etc.) which affect datastores or data within those datastores, e.g. (AWS RDS, AWS S3).\n\n[UID:6005] Category: application | Name: datastore_activity\n\n**Constraints:**\n* at_least_one: `[database`,`databucket`,`table]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DatastoreActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<DatastoreActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "AI Model\n\nThe AI Model object describes the characteristics of an AI/ML model. Examples include language models like GPT-4, embedding models like text-embedding-ada-002, and computer vision models like CLIP.\n\nrecommended"]
+    #[serde(rename = "ai_model")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_model: Option<Box<AiModel>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Datastore Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Database\n\nThe database object is used for databases which are typically datastore services that contain an organized collection of structured and unstructured data or a types of data.\n\nrecommended"]
+    #[serde(rename = "database")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<Box<Database>>,
+    #[doc = "Databucket\n\nThe data bucket object is a basic container that holds data, typically organized through the use of data partitions.\n\nrecommended"]
+    #[serde(rename = "databucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub databucket: Option<Box<Databucket>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nDetails about the endpoint hosting the datastore application or service.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying http request.\n\nrecommended"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying http response.\n\nrecommended"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Message Context\n\nCommunication context for AI system interactions including protocols, roles, clients, and session information for MCP and other AI communication systems.\n\noptional"]
+    #[serde(rename = "message_context")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_context: Option<Box<MessageContext>>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Query Info\n\nThe query info object holds information related to data access within a datastore. To access, manipulate, delete, or retrieve data from a datastore, a database query must be written using a specific syntax.\n\nrecommended"]
+    #[serde(rename = "query_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_info: Option<Box<QueryInfo>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the activity.\n\nrequired"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Table\n\nThe table object represents a table within a structured relational database or datastore, which contains columns and rows of data that are able to be create, updated, deleted and queried.\n\nrecommended"]
+    #[serde(rename = "table")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<Box<Table>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Datastore Type\n\nThe datastore resource type (e.g. database, datastore, or table).\n\noptional"]
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[doc = "Datastore Type ID\n\nThe normalized datastore resource type identifier.\n\nrecommended"]
+    #[serde(rename = "type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_id: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for DatastoreActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        report.check_recommended(path, "ai_model", self.ai_model.is_some());
+        if let Some(value) = &self.ai_model {
+            value.validate_at(&attr_path(path, "ai_model"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "database", self.database.is_some());
+        if let Some(value) = &self.database {
+            value.validate_at(&attr_path(path, "database"), report);
+        }
+        report.check_recommended(path, "databucket", self.databucket.is_some());
+        if let Some(value) = &self.databucket {
+            value.validate_at(&attr_path(path, "databucket"), report);
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "http_request", self.http_request.is_some());
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        report.check_recommended(path, "http_response", self.http_response.is_some());
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        if let Some(value) = &self.message_context {
+            value.validate_at(&attr_path(path, "message_context"), report);
+        }
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "query_info", self.query_info.is_some());
+        if let Some(value) = &self.query_info {
+            value.validate_at(&attr_path(path, "query_info"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_required(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_recommended(path, "table", self.table.is_some());
+        if let Some(value) = &self.table {
+            value.validate_at(&attr_path(path, "table"), report);
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_recommended(path, "type_id", self.type_id.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                ("database", self.database.is_some()),
+                ("databucket", self.databucket.is_some()),
+                ("table", self.table.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for DatastoreActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.ai_model {
+            value.normalize_at(&attr_path(path, "ai_model"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6005) => Some("Datastore Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.database {
+            value.normalize_at(&attr_path(path, "database"), conflicts);
+        }
+        if let Some(value) = &mut self.databucket {
+            value.normalize_at(&attr_path(path, "databucket"), conflicts);
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.message_context {
+            value.normalize_at(&attr_path(path, "message_context"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.query_info {
+            value.normalize_at(&attr_path(path, "query_info"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.table {
+            value.normalize_at(&attr_path(path, "table"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600500) => Some("Datastore Activity: Unknown"),
+                Some(600501) => Some("Datastore Activity: Read"),
+                Some(600502) => Some("Datastore Activity: Update"),
+                Some(600503) => Some("Datastore Activity: Connect"),
+                Some(600504) => Some("Datastore Activity: Query"),
+                Some(600505) => Some("Datastore Activity: Write"),
+                Some(600506) => Some("Datastore Activity: Create"),
+                Some(600507) => Some("Datastore Activity: Delete"),
+                Some(600508) => Some("Datastore Activity: List"),
+                Some(600509) => Some("Datastore Activity: Encrypt"),
+                Some(600510) => Some("Datastore Activity: Decrypt"),
+                Some(600599) => Some("Datastore Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl DatastoreActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for DatastoreActivity {
+    const CLASS_UID: i64 = 6005;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "datastore_activity";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Datastore Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl DatastoreActivity {
+    pub fn builder() -> DatastoreActivityBuilder {
+        DatastoreActivityBuilder {
+            inner: DatastoreActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`DatastoreActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DatastoreActivityBuilder {
+    inner: DatastoreActivity,
+}
+impl DatastoreActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<DatastoreActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "AI Model (recommended)"]
+    pub fn ai_model(mut self, value: AiModel) -> Self {
+        self.inner.ai_model = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Database (recommended)"]
+    pub fn database(mut self, value: Database) -> Self {
+        self.inner.database = Some(Box::new(value));
+        self
+    }
+    #[doc = "Databucket (recommended)"]
+    pub fn databucket(mut self, value: Databucket) -> Self {
+        self.inner.databucket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (recommended)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (recommended)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Message Context (optional)"]
+    pub fn message_context(mut self, value: MessageContext) -> Self {
+        self.inner.message_context = Some(Box::new(value));
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Query Info (recommended)"]
+    pub fn query_info(mut self, value: QueryInfo) -> Self {
+        self.inner.query_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (required)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Table (recommended)"]
+    pub fn table(mut self, value: Table) -> Self {
+        self.inner.table = Some(Box::new(value));
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Datastore Type (optional)"]
+    pub fn r#type(mut self, value: impl Into<String>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+    #[doc = "Datastore Type ID (recommended)"]
+    pub fn type_id(mut self, value: i64) -> Self {
+        self.inner.type_id = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<DatastoreActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "DatastoreActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("src_endpoint", self.inner.src_endpoint.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> DatastoreActivity {
+        self.inner
+    }
+}
+impl KnownFields for DatastoreActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "ai_model" => AiModel::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "database" => Database::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "databucket" => {
+                    Databucket::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "message_context" => {
+                    MessageContext::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "query_info" => {
+                    QueryInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "table" => Table::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type" | "type_id" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for DatastoreActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "ai_model" => segment.object(self.ai_model.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "database" => segment.object(self.database.as_deref()),
+            "databucket" => segment.object(self.databucket.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "message_context" => segment.object(self.message_context.as_deref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "query_info" => segment.object(self.query_info.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "table" => segment.object(self.table.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type" => segment.leaf(self.r#type.as_ref()),
+            "type_id" => segment.leaf(self.type_id.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "ai_model" => segment.set_object(&mut self.ai_model, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "database" => segment.set_object(&mut self.database, value),
+            "databucket" => segment.set_object(&mut self.databucket, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "message_context" => segment.set_object(&mut self.message_context, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "query_info" => segment.set_object(&mut self.query_info, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "table" => segment.set_object(&mut self.table, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type" => segment.set_leaf(&mut self.r#type, value),
+            "type_id" => segment.set_leaf(&mut self.type_id, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for DatastoreActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.ai_model {
+            value.collect_observables(&attr_path(path, "ai_model"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.database {
+            value.collect_observables(&attr_path(path, "database"), found);
+        }
+        if let Some(value) = &self.databucket {
+            value.collect_observables(&attr_path(path, "databucket"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.message_context {
+            value.collect_observables(&attr_path(path, "message_context"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.query_info {
+            value.collect_observables(&attr_path(path, "query_info"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.table {
+            value.collect_observables(&attr_path(path, "table"), found);
+        }
+    }
+}
+#[doc = "File Hosting Activity\n\nFile Hosting Activity events report the actions taken by file management applications, including file sharing servers like Sharepoint and services such as Box, MS OneDrive, Google Drive, or network file share services.\n\n[UID:6006] Category: application | Name: file_hosting"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct FileHosting {
+    #[doc = "Access List\n\nThe list of requested access rights.\n\noptional"]
+    #[serde(rename = "access_list")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<Vec<String>>,
+    #[doc = "Access Mask\n\nThe sum of hexadecimal values of requested access rights.\n\noptional"]
+    #[serde(rename = "access_mask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_mask: Option<i64>,
+    #[doc = "Access Check Result\n\nThe list of access check results.\n\noptional"]
+    #[serde(rename = "access_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_result: Option<serde_json::Value>,
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<FileHostingActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity on the target file.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>File Hosting Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Connection Info\n\nThe network connection information.\n\noptional"]
+    #[serde(rename = "connection_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_info: Option<Box<NetworkConnectionInfo>>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nThe endpoint that received the activity on the target file.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Expiration Time\n\nThe share expiration time.\n\noptional"]
+    #[serde(rename = "expiration_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<Timestamp>,
+    #[doc = "Expiration Time\n\nThe share expiration time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "expiration_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time_dt: Option<DateTime>,
+    #[doc = "File\n\nThe file that is the target of the activity.\n\nrequired"]
+    #[serde(rename = "file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<File>>,
+    #[doc = "File Result\n\nThe resulting file object when the activity was allowed and successful.\n\noptional"]
+    #[serde(rename = "file_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_result: Option<Box<File>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\nrecommended"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the HTTP response, if available.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Share\n\nThe share name.\n\noptional"]
+    #[serde(rename = "share")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<String>,
+    #[doc = "Share Type\n\nThe share type, normalized to the caption of the share_type_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "share_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_type: Option<String>,
+    #[doc = "Share Type ID\n\nThe normalized identifier of the share type.\n\noptional"]
+    #[serde(rename = "share_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_type_id: Option<i64>,
+    #[doc = "Source Endpoint\n\nThe endpoint that performed the activity on the target file.\n\nrequired"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for FileHosting {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.connection_info {
+            value.validate_at(&attr_path(path, "connection_info"), report);
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_format(path, "expiration_time_dt", self.expiration_time_dt.as_ref());
+        report.check_required(path, "file", self.file.is_some());
+        if let Some(value) = &self.file {
+            value.validate_at(&attr_path(path, "file"), report);
+        }
+        if let Some(value) = &self.file_result {
+            value.validate_at(&attr_path(path, "file_result"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "http_request", self.http_request.is_some());
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_required(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for FileHosting {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6006) => Some("File Hosting Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.connection_info {
+            value.normalize_at(&attr_path(path, "connection_info"), conflicts);
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.file {
+            value.normalize_at(&attr_path(path, "file"), conflicts);
+        }
+        if let Some(value) = &mut self.file_result {
+            value.normalize_at(&attr_path(path, "file_result"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600600) => Some("File Hosting Activity: Unknown"),
+                Some(600601) => Some("File Hosting Activity: Upload"),
+                Some(600602) => Some("File Hosting Activity: Download"),
+                Some(600603) => Some("File Hosting Activity: Update"),
+                Some(600604) => Some("File Hosting Activity: Delete"),
+                Some(600605) => Some("File Hosting Activity: Rename"),
+                Some(600606) => Some("File Hosting Activity: Copy"),
+                Some(600607) => Some("File Hosting Activity: Move"),
+                Some(600608) => Some("File Hosting Activity: Restore"),
+                Some(600609) => Some("File Hosting Activity: Preview"),
+                Some(600610) => Some("File Hosting Activity: Lock"),
+                Some(600611) => Some("File Hosting Activity: Unlock"),
+                Some(600612) => Some("File Hosting Activity: Share"),
+                Some(600613) => Some("File Hosting Activity: Unshare"),
+                Some(600614) => Some("File Hosting Activity: Open"),
+                Some(600615) => Some("File Hosting Activity: Sync"),
+                Some(600616) => Some("File Hosting Activity: Unsync"),
+                Some(600617) => Some("File Hosting Activity: Access Check"),
+                Some(600699) => Some("File Hosting Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl FileHosting {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for FileHosting {
+    const CLASS_UID: i64 = 6006;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "file_hosting";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "File Hosting Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl FileHosting {
+    pub fn builder() -> FileHostingBuilder {
+        FileHostingBuilder {
+            inner: FileHosting::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`FileHosting`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileHostingBuilder {
+    inner: FileHosting,
+}
+impl FileHostingBuilder {
+    #[doc = "Access List (optional)"]
+    pub fn access_list(mut self, value: Vec<String>) -> Self {
+        self.inner.access_list = Some(value);
+        self
+    }
+    #[doc = "Access Mask (optional)"]
+    pub fn access_mask(mut self, value: i64) -> Self {
+        self.inner.access_mask = Some(value);
+        self
+    }
+    #[doc = "Access Check Result (optional)"]
+    pub fn access_result(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.access_result = Some(value.into());
+        self
+    }
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<FileHostingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Connection Info (optional)"]
+    pub fn connection_info(mut self, value: NetworkConnectionInfo) -> Self {
+        self.inner.connection_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Expiration Time (optional)"]
+    pub fn expiration_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.expiration_time = Some(value.into());
+        self
+    }
+    #[doc = "Expiration Time (optional (`datetime` profile))"]
+    pub fn expiration_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.expiration_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "File (required)"]
+    pub fn file(mut self, value: File) -> Self {
+        self.inner.file = Some(Box::new(value));
+        self
+    }
+    #[doc = "File Result (optional)"]
+    pub fn file_result(mut self, value: File) -> Self {
+        self.inner.file_result = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (recommended)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Share (optional)"]
+    pub fn share(mut self, value: impl Into<String>) -> Self {
+        self.inner.share = Some(value.into());
+        self
+    }
+    #[doc = "Share Type (optional)"]
+    pub fn share_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.share_type = Some(value.into());
+        self
+    }
+    #[doc = "Share Type ID (optional)"]
+    pub fn share_type_id(mut self, value: i64) -> Self {
+        self.inner.share_type_id = Some(value);
+        self
+    }
+    #[doc = "Source Endpoint (required)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<FileHosting, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "FileHosting",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("file", self.inner.file.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("src_endpoint", self.inner.src_endpoint.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> FileHosting {
+        self.inner
+    }
+}
+impl KnownFields for FileHosting {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "connection_info" => NetworkConnectionInfo::find_unknown_fields(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "file_result" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "access_list" | "access_mask" | "access_result" | "action" | "action_id"
+                | "activity_id" | "activity_name" | "category_name" | "category_uid"
+                | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "expiration_time" | "expiration_time_dt"
+                | "is_alert" | "message" | "raw_data" | "raw_data_size" | "risk_details"
+                | "risk_level" | "risk_level_id" | "risk_score" | "severity" | "severity_id"
+                | "share" | "share_type" | "share_type_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for FileHosting {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "access_list" => segment.leaf_in(self.access_list.as_deref()),
+            "access_mask" => segment.leaf(self.access_mask.as_ref()),
+            "access_result" => segment.leaf(self.access_result.as_ref()),
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "connection_info" => segment.object(self.connection_info.as_deref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "expiration_time" => segment.leaf(self.expiration_time.as_ref()),
+            "expiration_time_dt" => segment.leaf(self.expiration_time_dt.as_ref()),
+            "file" => segment.object(self.file.as_deref()),
+            "file_result" => segment.object(self.file_result.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "share" => segment.leaf(self.share.as_ref()),
+            "share_type" => segment.leaf(self.share_type.as_ref()),
+            "share_type_id" => segment.leaf(self.share_type_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "access_list" => segment.set_leaf_in(&mut self.access_list, value),
+            "access_mask" => segment.set_leaf(&mut self.access_mask, value),
+            "access_result" => segment.set_leaf(&mut self.access_result, value),
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "connection_info" => segment.set_object(&mut self.connection_info, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "expiration_time" => segment.set_leaf(&mut self.expiration_time, value),
+            "expiration_time_dt" => segment.set_leaf(&mut self.expiration_time_dt, value),
+            "file" => segment.set_object(&mut self.file, value),
+            "file_result" => segment.set_object(&mut self.file_result, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "share" => segment.set_leaf(&mut self.share, value),
+            "share_type" => segment.set_leaf(&mut self.share_type, value),
+            "share_type_id" => segment.set_leaf(&mut self.share_type_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for FileHosting {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.connection_info {
+            value.collect_observables(&attr_path(path, "connection_info"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.file {
+            value.collect_observables(&attr_path(path, "file"), found);
+        }
+        if let Some(value) = &self.file_result {
+            value.collect_observables(&attr_path(path, "file_result"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+    }
+}
+#[doc = "Scan Activity\n\nScan events report the start, completion, and results of a scan job. The scan event includes the number of items that were scanned and the number of detections that were resolved.\n\n[UID:6007] Category: application | Name: scan_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ScanActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ScanActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Scan Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Command UID\n\nThe command identifier that is associated with this scan event.  This ID uniquely identifies the proactive scan command, e.g., if remotely initiated.\n\nrecommended"]
+    #[serde(rename = "command_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_uid: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe duration of the scan\n\nrecommended"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of the scan job.\n\nrecommended"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of the scan job.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Detections\n\nThe number of detections.\n\nrecommended"]
+    #[serde(rename = "num_detections")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_detections: Option<i64>,
+    #[doc = "Scanned Files\n\nThe number of files scanned.\n\nrecommended"]
+    #[serde(rename = "num_files")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_files: Option<i64>,
+    #[doc = "Scanned Folders\n\nThe number of folders scanned.\n\nrecommended"]
+    #[serde(rename = "num_folders")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_folders: Option<i64>,
+    #[doc = "Scanned Network Items\n\nThe number of network items scanned.\n\nrecommended"]
+    #[serde(rename = "num_network_items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_network_items: Option<i64>,
+    #[doc = "Scanned Processes\n\nThe number of processes scanned.\n\nrecommended"]
+    #[serde(rename = "num_processes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_processes: Option<i64>,
+    #[doc = "Scanned Registry Items\n\nThe number of registry items scanned.\n\nrecommended"]
+    #[serde(rename = "num_registry_items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_registry_items: Option<i64>,
+    #[doc = "Resolutions\n\nThe number of items that were resolved.\n\nrecommended"]
+    #[serde(rename = "num_resolutions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_resolutions: Option<i64>,
+    #[doc = "Skipped\n\nThe number of skipped items.\n\nrecommended"]
+    #[serde(rename = "num_skipped_items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_skipped_items: Option<i64>,
+    #[doc = "Trusted\n\nThe number of trusted items.\n\nrecommended"]
+    #[serde(rename = "num_trusted_items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_trusted_items: Option<i64>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy associated with this Scan event; required if the scan was initiated by a policy.\n\nrecommended"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Scan\n\nThe Scan object describes characteristics of the scan job.\n\nrequired"]
+    #[serde(rename = "scan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<Box<Scan>>,
+    #[doc = "Schedule UID\n\nThe unique identifier of the schedule associated with a scan job.\n\nrecommended"]
+    #[serde(rename = "schedule_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_uid: Option<String>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of the scan job.\n\nrecommended"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of the scan job.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Total\n\nThe total number of items that were scanned; zero if no items were scanned.\n\nrecommended"]
+    #[serde(rename = "total")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ScanActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended(path, "command_uid", self.command_uid.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "duration", self.duration.is_some());
+        report.check_recommended(path, "end_time", self.end_time.is_some());
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "num_detections", self.num_detections.is_some());
+        report.check_recommended(path, "num_files", self.num_files.is_some());
+        report.check_recommended(path, "num_folders", self.num_folders.is_some());
+        report.check_recommended(path, "num_network_items", self.num_network_items.is_some());
+        report.check_recommended(path, "num_processes", self.num_processes.is_some());
+        report.check_recommended(
+            path,
+            "num_registry_items",
+            self.num_registry_items.is_some(),
+        );
+        report.check_recommended(path, "num_resolutions", self.num_resolutions.is_some());
+        report.check_recommended(path, "num_skipped_items", self.num_skipped_items.is_some());
+        report.check_recommended(path, "num_trusted_items", self.num_trusted_items.is_some());
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "policy", self.policy.is_some());
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "scan", self.scan.is_some());
+        if let Some(value) = &self.scan {
+            value.validate_at(&attr_path(path, "scan"), report);
+        }
+        report.check_recommended(path, "schedule_uid", self.schedule_uid.is_some());
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "start_time", self.start_time.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_recommended(path, "total", self.total.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ScanActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6007) => Some("Scan Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.scan {
+            value.normalize_at(&attr_path(path, "scan"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600700) => Some("Scan Activity: Unknown"),
+                Some(600701) => Some("Scan Activity: Started"),
+                Some(600702) => Some("Scan Activity: Completed"),
+                Some(600703) => Some("Scan Activity: Cancelled"),
+                Some(600704) => Some("Scan Activity: Duration Violation"),
+                Some(600705) => Some("Scan Activity: Pause Violation"),
+                Some(600706) => Some("Scan Activity: Error"),
+                Some(600707) => Some("Scan Activity: Paused"),
+                Some(600708) => Some("Scan Activity: Resumed"),
+                Some(600709) => Some("Scan Activity: Restarted"),
+                Some(600710) => Some("Scan Activity: Delayed"),
+                Some(600799) => Some("Scan Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ScanActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ScanActivity {
+    const CLASS_UID: i64 = 6007;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "scan_activity";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Scan Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl ScanActivity {
+    pub fn builder() -> ScanActivityBuilder {
+        ScanActivityBuilder {
+            inner: ScanActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ScanActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScanActivityBuilder {
+    inner: ScanActivity,
+}
+impl ScanActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ScanActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Command UID (recommended)"]
+    pub fn command_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.command_uid = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (recommended)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (recommended)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Detections (recommended)"]
+    pub fn num_detections(mut self, value: i64) -> Self {
+        self.inner.num_detections = Some(value);
+        self
+    }
+    #[doc = "Scanned Files (recommended)"]
+    pub fn num_files(mut self, value: i64) -> Self {
+        self.inner.num_files = Some(value);
+        self
+    }
+    #[doc = "Scanned Folders (recommended)"]
+    pub fn num_folders(mut self, value: i64) -> Self {
+        self.inner.num_folders = Some(value);
+        self
+    }
+    #[doc = "Scanned Network Items (recommended)"]
+    pub fn num_network_items(mut self, value: i64) -> Self {
+        self.inner.num_network_items = Some(value);
+        self
+    }
+    #[doc = "Scanned Processes (recommended)"]
+    pub fn num_processes(mut self, value: i64) -> Self {
+        self.inner.num_processes = Some(value);
+        self
+    }
+    #[doc = "Scanned Registry Items (recommended)"]
+    pub fn num_registry_items(mut self, value: i64) -> Self {
+        self.inner.num_registry_items = Some(value);
+        self
+    }
+    #[doc = "Resolutions (recommended)"]
+    pub fn num_resolutions(mut self, value: i64) -> Self {
+        self.inner.num_resolutions = Some(value);
+        self
+    }
+    #[doc = "Skipped (recommended)"]
+    pub fn num_skipped_items(mut self, value: i64) -> Self {
+        self.inner.num_skipped_items = Some(value);
+        self
+    }
+    #[doc = "Trusted (recommended)"]
+    pub fn num_trusted_items(mut self, value: i64) -> Self {
+        self.inner.num_trusted_items = Some(value);
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (recommended)"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Scan (required)"]
+    pub fn scan(mut self, value: Scan) -> Self {
+        self.inner.scan = Some(Box::new(value));
+        self
+    }
+    #[doc = "Schedule UID (recommended)"]
+    pub fn schedule_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.schedule_uid = Some(value.into());
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (recommended)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Total (recommended)"]
+    pub fn total(mut self, value: i64) -> Self {
+        self.inner.total = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ScanActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ScanActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("scan", self.inner.scan.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ScanActivity {
+        self.inner
+    }
+}
+impl KnownFields for ScanActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "scan" => Scan::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "command_uid" | "confidence"
+                | "confidence_id" | "confidence_score" | "count" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "is_alert"
+                | "message" | "num_detections" | "num_files" | "num_folders"
+                | "num_network_items" | "num_processes" | "num_registry_items"
+                | "num_resolutions" | "num_skipped_items" | "num_trusted_items" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "schedule_uid" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "total" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ScanActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "command_uid" => segment.leaf(self.command_uid.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "num_detections" => segment.leaf(self.num_detections.as_ref()),
+            "num_files" => segment.leaf(self.num_files.as_ref()),
+            "num_folders" => segment.leaf(self.num_folders.as_ref()),
+            "num_network_items" => segment.leaf(self.num_network_items.as_ref()),
+            "num_processes" => segment.leaf(self.num_processes.as_ref()),
+            "num_registry_items" => segment.leaf(self.num_registry_items.as_ref()),
+            "num_resolutions" => segment.leaf(self.num_resolutions.as_ref()),
+            "num_skipped_items" => segment.leaf(self.num_skipped_items.as_ref()),
+            "num_trusted_items" => segment.leaf(self.num_trusted_items.as_ref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "scan" => segment.object(self.scan.as_deref()),
+            "schedule_uid" => segment.leaf(self.schedule_uid.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "total" => segment.leaf(self.total.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "command_uid" => segment.set_leaf(&mut self.command_uid, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "num_detections" => segment.set_leaf(&mut self.num_detections, value),
+            "num_files" => segment.set_leaf(&mut self.num_files, value),
+            "num_folders" => segment.set_leaf(&mut self.num_folders, value),
+            "num_network_items" => segment.set_leaf(&mut self.num_network_items, value),
+            "num_processes" => segment.set_leaf(&mut self.num_processes, value),
+            "num_registry_items" => segment.set_leaf(&mut self.num_registry_items, value),
+            "num_resolutions" => segment.set_leaf(&mut self.num_resolutions, value),
+            "num_skipped_items" => segment.set_leaf(&mut self.num_skipped_items, value),
+            "num_trusted_items" => segment.set_leaf(&mut self.num_trusted_items, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "scan" => segment.set_object(&mut self.scan, value),
+            "schedule_uid" => segment.set_leaf(&mut self.schedule_uid, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "total" => segment.set_leaf(&mut self.total, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ScanActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.scan {
+            value.collect_observables(&attr_path(path, "scan"), found);
+        }
+    }
+}
+#[doc = "Web Resource Access Activity\n\nWeb Resource Access Activity events describe successful/failed attempts to access a web resource over HTTP.\n\n[UID:6004] Category: application | Name: web_resource_access_activity"]
+#[deprecated(
+    note = "Use the <code>Web Resources Activity</code> class with the <code>Security Control</code> and/or <code>Network Proxy</code> profile instead. (Since 1.1.0)"
+)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct WebResourceAccessActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<WebResourceAccessActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Web Resource Access Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\nrequired"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the HTTP response, if available.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Proxy\n\nDetails about the proxy service, if available.\n\noptional"]
+    #[serde(rename = "proxy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Box<NetworkProxy>>,
+    #[doc = "Proxy Connection Info\n\nThe connection information from the proxy server to the remote server.\n\nrecommended"]
+    #[serde(rename = "proxy_connection_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_connection_info: Option<Box<NetworkConnectionInfo>>,
+    #[doc = "Proxy Endpoint\n\nThe proxy (server) in a network connection.\n\noptional"]
+    #[serde(rename = "proxy_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_endpoint: Option<Box<NetworkProxy>>,
+    #[doc = "Proxy HTTP Request\n\nThe HTTP Request from the proxy server to the remote server.\n\noptional"]
+    #[serde(rename = "proxy_http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_http_request: Option<Box<HttpRequest>>,
+    #[doc = "Proxy HTTP Response\n\nThe HTTP Response from the remote server to the proxy server.\n\noptional"]
+    #[serde(rename = "proxy_http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_http_response: Option<Box<HttpResponse>>,
+    #[doc = "Proxy TLS\n\nThe TLS protocol negotiated between the proxy server and the remote server.\n\nrecommended"]
+    #[serde(rename = "proxy_tls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_tls: Option<Box<Tls>>,
+    #[doc = "Proxy Traffic\n\nThe network traffic refers to the amount of data moving across a network, from proxy to remote server at a given point of time.\n\nrecommended"]
+    #[serde(rename = "proxy_traffic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_traffic: Option<Box<NetworkTraffic>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source endpoint of the request.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "TLS\n\nThe Transport Layer Security (TLS) attributes, if available.\n\noptional"]
+    #[serde(rename = "tls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<Box<Tls>>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Web Resources\n\nDetails about the resource that is the target of the activity.\n\nrequired"]
+    #[serde(rename = "web_resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_resources: Option<Vec<WebResource>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for WebResourceAccessActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_required(path, "http_request", self.http_request.is_some());
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.proxy {
+            value.validate_at(&attr_path(path, "proxy"), report);
+        }
+        report.check_recommended(
+            path,
+            "proxy_connection_info",
+            self.proxy_connection_info.is_some(),
+        );
+        if let Some(value) = &self.proxy_connection_info {
+            value.validate_at(&attr_path(path, "proxy_connection_info"), report);
+        }
+        if let Some(value) = &self.proxy_endpoint {
+            value.validate_at(&attr_path(path, "proxy_endpoint"), report);
+        }
+        if let Some(value) = &self.proxy_http_request {
+            value.validate_at(&attr_path(path, "proxy_http_request"), report);
+        }
+        if let Some(value) = &self.proxy_http_response {
+            value.validate_at(&attr_path(path, "proxy_http_response"), report);
+        }
+        report.check_recommended(path, "proxy_tls", self.proxy_tls.is_some());
+        if let Some(value) = &self.proxy_tls {
+            value.validate_at(&attr_path(path, "proxy_tls"), report);
+        }
+        report.check_recommended(path, "proxy_traffic", self.proxy_traffic.is_some());
+        if let Some(value) = &self.proxy_traffic {
+            value.validate_at(&attr_path(path, "proxy_traffic"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        if let Some(value) = &self.tls {
+            value.validate_at(&attr_path(path, "tls"), report);
+        }
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "web_resources", self.web_resources.is_some());
+        if let Some(items) = &self.web_resources {
+            let path = attr_path(path, "web_resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for WebResourceAccessActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6004) => Some("Web Resource Access Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy {
+            value.normalize_at(&attr_path(path, "proxy"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_connection_info {
+            value.normalize_at(&attr_path(path, "proxy_connection_info"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_endpoint {
+            value.normalize_at(&attr_path(path, "proxy_endpoint"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_http_request {
+            value.normalize_at(&attr_path(path, "proxy_http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_http_response {
+            value.normalize_at(&attr_path(path, "proxy_http_response"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_tls {
+            value.normalize_at(&attr_path(path, "proxy_tls"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_traffic {
+            value.normalize_at(&attr_path(path, "proxy_traffic"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.tls {
+            value.normalize_at(&attr_path(path, "tls"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600400) => Some("Web Resource Access Activity: Unknown"),
+                Some(600401) => Some("Web Resource Access Activity: Access Grant"),
+                Some(600402) => Some("Web Resource Access Activity: Access Deny"),
+                Some(600403) => Some("Web Resource Access Activity: Access Revoke"),
+                Some(600404) => Some("Web Resource Access Activity: Access Error"),
+                Some(600499) => Some("Web Resource Access Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(items) = &mut self.web_resources {
+            let path = attr_path(path, "web_resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl WebResourceAccessActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for WebResourceAccessActivity {
+    const CLASS_UID: i64 = 6004;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "web_resource_access_activity";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Web Resource Access Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl WebResourceAccessActivity {
+    pub fn builder() -> WebResourceAccessActivityBuilder {
+        WebResourceAccessActivityBuilder {
+            inner: WebResourceAccessActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`WebResourceAccessActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WebResourceAccessActivityBuilder {
+    inner: WebResourceAccessActivity,
+}
+impl WebResourceAccessActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<WebResourceAccessActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (required)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy (optional)"]
+    pub fn proxy(mut self, value: NetworkProxy) -> Self {
+        self.inner.proxy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Connection Info (recommended)"]
+    pub fn proxy_connection_info(mut self, value: NetworkConnectionInfo) -> Self {
+        self.inner.proxy_connection_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Endpoint (optional)"]
+    pub fn proxy_endpoint(mut self, value: NetworkProxy) -> Self {
+        self.inner.proxy_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy HTTP Request (optional)"]
+    pub fn proxy_http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.proxy_http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy HTTP Response (optional)"]
+    pub fn proxy_http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.proxy_http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy TLS (recommended)"]
+    pub fn proxy_tls(mut self, value: Tls) -> Self {
+        self.inner.proxy_tls = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Traffic (recommended)"]
+    pub fn proxy_traffic(mut self, value: NetworkTraffic) -> Self {
+        self.inner.proxy_traffic = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "TLS (optional)"]
+    pub fn tls(mut self, value: Tls) -> Self {
+        self.inner.tls = Some(Box::new(value));
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Web Resources (required)"]
+    pub fn web_resources(mut self, value: Vec<WebResource>) -> Self {
+        self.inner.web_resources = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<WebResourceAccessActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "WebResourceAccessActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("http_request", self.inner.http_request.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("web_resources", self.inner.web_resources.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> WebResourceAccessActivity {
+        self.inner
+    }
+}
+impl KnownFields for WebResourceAccessActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "proxy" => NetworkProxy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "proxy_connection_info" => NetworkConnectionInfo::find_unknown_fields(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "proxy_endpoint" => {
+                    NetworkProxy::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_tls" => Tls::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "proxy_traffic" => {
+                    NetworkTraffic::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "tls" => Tls::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "web_resources" => {
+                    find_unknown_in_array::<WebResource>(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for WebResourceAccessActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "proxy" => segment.object(self.proxy.as_deref()),
+            "proxy_connection_info" => segment.object(self.proxy_connection_info.as_deref()),
+            "proxy_endpoint" => segment.object(self.proxy_endpoint.as_deref()),
+            "proxy_http_request" => segment.object(self.proxy_http_request.as_deref()),
+            "proxy_http_response" => segment.object(self.proxy_http_response.as_deref()),
+            "proxy_tls" => segment.object(self.proxy_tls.as_deref()),
+            "proxy_traffic" => segment.object(self.proxy_traffic.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "tls" => segment.object(self.tls.as_deref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "web_resources" => segment.object_in(self.web_resources.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "proxy" => segment.set_object(&mut self.proxy, value),
+            "proxy_connection_info" => segment.set_object(&mut self.proxy_connection_info, value),
+            "proxy_endpoint" => segment.set_object(&mut self.proxy_endpoint, value),
+            "proxy_http_request" => segment.set_object(&mut self.proxy_http_request, value),
+            "proxy_http_response" => segment.set_object(&mut self.proxy_http_response, value),
+            "proxy_tls" => segment.set_object(&mut self.proxy_tls, value),
+            "proxy_traffic" => segment.set_object(&mut self.proxy_traffic, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "tls" => segment.set_object(&mut self.tls, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "web_resources" => segment.set_object_in(&mut self.web_resources, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for WebResourceAccessActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.proxy {
+            value.collect_observables(&attr_path(path, "proxy"), found);
+        }
+        if let Some(value) = &self.proxy_connection_info {
+            value.collect_observables(&attr_path(path, "proxy_connection_info"), found);
+        }
+        if let Some(value) = &self.proxy_endpoint {
+            value.collect_observables(&attr_path(path, "proxy_endpoint"), found);
+        }
+        if let Some(value) = &self.proxy_http_request {
+            value.collect_observables(&attr_path(path, "proxy_http_request"), found);
+        }
+        if let Some(value) = &self.proxy_http_response {
+            value.collect_observables(&attr_path(path, "proxy_http_response"), found);
+        }
+        if let Some(value) = &self.proxy_tls {
+            value.collect_observables(&attr_path(path, "proxy_tls"), found);
+        }
+        if let Some(value) = &self.proxy_traffic {
+            value.collect_observables(&attr_path(path, "proxy_traffic"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.tls {
+            value.collect_observables(&attr_path(path, "tls"), found);
+        }
+        for value in self.web_resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "web_resources"), found);
+        }
+    }
+}
+#[doc = "Web Resources Activity\n\nWeb Resources Activity events describe actions executed on a set of Web Resources.\n\n[UID:6001] Category: application | Name: web_resources_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct WebResourcesActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<WebResourcesActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Application Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Web Resources Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nDetails about server providing the web resources.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\nrecommended"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the HTTP response, if available.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Proxy Connection Info\n\nThe connection information from the proxy server to the remote server.\n\nrecommended"]
+    #[serde(rename = "proxy_connection_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_connection_info: Option<Box<NetworkConnectionInfo>>,
+    #[doc = "Proxy Endpoint\n\nThe proxy (server) in a network connection.\n\noptional"]
+    #[serde(rename = "proxy_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_endpoint: Option<Box<NetworkProxy>>,
+    #[doc = "Proxy HTTP Request\n\nThe HTTP Request from the proxy server to the remote server.\n\noptional"]
+    #[serde(rename = "proxy_http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_http_request: Option<Box<HttpRequest>>,
+    #[doc = "Proxy HTTP Response\n\nThe HTTP Response from the remote server to the proxy server.\n\noptional"]
+    #[serde(rename = "proxy_http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_http_response: Option<Box<HttpResponse>>,
+    #[doc = "Proxy TLS\n\nThe TLS protocol negotiated between the proxy server and the remote server.\n\nrecommended"]
+    #[serde(rename = "proxy_tls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_tls: Option<Box<Tls>>,
+    #[doc = "Proxy Traffic\n\nThe network traffic refers to the amount of data moving across a network, from proxy to remote server at a given point of time.\n\nrecommended"]
+    #[serde(rename = "proxy_traffic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_traffic: Option<Box<NetworkTraffic>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the endpoint from which the request originated.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "TLS\n\nThe Transport Layer Security (TLS) attributes, if available.\n\noptional"]
+    #[serde(rename = "tls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<Box<Tls>>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Web Resources\n\nDescribes details about web resources that were affected by an activity/event.\n\nrequired"]
+    #[serde(rename = "web_resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_resources: Option<Vec<WebResource>>,
+    #[doc = "Web Resources Result\n\nThe results of the activity on web resources. It should contain the new values of the changed attributes of the web resources.\n\nrecommended"]
+    #[serde(rename = "web_resources_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_resources_result: Option<Vec<WebResource>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for WebResourcesActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "http_request", self.http_request.is_some());
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(
+            path,
+            "proxy_connection_info",
+            self.proxy_connection_info.is_some(),
+        );
+        if let Some(value) = &self.proxy_connection_info {
+            value.validate_at(&attr_path(path, "proxy_connection_info"), report);
+        }
+        if let Some(value) = &self.proxy_endpoint {
+            value.validate_at(&attr_path(path, "proxy_endpoint"), report);
+        }
+        if let Some(value) = &self.proxy_http_request {
+            value.validate_at(&attr_path(path, "proxy_http_request"), report);
+        }
+        if let Some(value) = &self.proxy_http_response {
+            value.validate_at(&attr_path(path, "proxy_http_response"), report);
+        }
+        report.check_recommended(path, "proxy_tls", self.proxy_tls.is_some());
+        if let Some(value) = &self.proxy_tls {
+            value.validate_at(&attr_path(path, "proxy_tls"), report);
+        }
+        report.check_recommended(path, "proxy_traffic", self.proxy_traffic.is_some());
+        if let Some(value) = &self.proxy_traffic {
+            value.validate_at(&attr_path(path, "proxy_traffic"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        if let Some(value) = &self.tls {
+            value.validate_at(&attr_path(path, "tls"), report);
+        }
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "web_resources", self.web_resources.is_some());
+        if let Some(items) = &self.web_resources {
+            let path = attr_path(path, "web_resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(
+            path,
+            "web_resources_result",
+            self.web_resources_result.is_some(),
+        );
+        if let Some(items) = &self.web_resources_result {
+            let path = attr_path(path, "web_resources_result");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for WebResourcesActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(6) => Some("Application Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(6001) => Some("Web Resources Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_connection_info {
+            value.normalize_at(&attr_path(path, "proxy_connection_info"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_endpoint {
+            value.normalize_at(&attr_path(path, "proxy_endpoint"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_http_request {
+            value.normalize_at(&attr_path(path, "proxy_http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_http_response {
+            value.normalize_at(&attr_path(path, "proxy_http_response"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_tls {
+            value.normalize_at(&attr_path(path, "proxy_tls"), conflicts);
+        }
+        if let Some(value) = &mut self.proxy_traffic {
+            value.normalize_at(&attr_path(path, "proxy_traffic"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.tls {
+            value.normalize_at(&attr_path(path, "tls"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(600100) => Some("Web Resources Activity: Unknown"),
+                Some(600101) => Some("Web Resources Activity: Create"),
+                Some(600102) => Some("Web Resources Activity: Read"),
+                Some(600103) => Some("Web Resources Activity: Update"),
+                Some(600104) => Some("Web Resources Activity: Delete"),
+                Some(600105) => Some("Web Resources Activity: Search"),
+                Some(600106) => Some("Web Resources Activity: Import"),
+                Some(600107) => Some("Web Resources Activity: Export"),
+                Some(600108) => Some("Web Resources Activity: Share"),
+                Some(600199) => Some("Web Resources Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(items) = &mut self.web_resources {
+            let path = attr_path(path, "web_resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.web_resources_result {
+            let path = attr_path(path, "web_resources_result");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl WebResourcesActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for WebResourcesActivity {
+    const CLASS_UID: i64 = 6001;
+    const CATEGORY_UID: i64 = 6;
+    const CLASS_NAME: &'static str = "web_resources_activity";
+    const CATEGORY_NAME: &'static str = "application";
+    const CAPTION: &'static str = "Web Resources Activity";
+    const CATEGORY_CAPTION: &'static str = "Application Activity";
+}
+impl WebResourcesActivity {
+    pub fn builder() -> WebResourcesActivityBuilder {
+        WebResourcesActivityBuilder {
+            inner: WebResourcesActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`WebResourcesActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WebResourcesActivityBuilder {
+    inner: WebResourcesActivity,
+}
+impl WebResourcesActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<WebResourcesActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (recommended)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Connection Info (recommended)"]
+    pub fn proxy_connection_info(mut self, value: NetworkConnectionInfo) -> Self {
+        self.inner.proxy_connection_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Endpoint (optional)"]
+    pub fn proxy_endpoint(mut self, value: NetworkProxy) -> Self {
+        self.inner.proxy_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy HTTP Request (optional)"]
+    pub fn proxy_http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.proxy_http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy HTTP Response (optional)"]
+    pub fn proxy_http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.proxy_http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy TLS (recommended)"]
+    pub fn proxy_tls(mut self, value: Tls) -> Self {
+        self.inner.proxy_tls = Some(Box::new(value));
+        self
+    }
+    #[doc = "Proxy Traffic (recommended)"]
+    pub fn proxy_traffic(mut self, value: NetworkTraffic) -> Self {
+        self.inner.proxy_traffic = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "TLS (optional)"]
+    pub fn tls(mut self, value: Tls) -> Self {
+        self.inner.tls = Some(Box::new(value));
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Web Resources (required)"]
+    pub fn web_resources(mut self, value: Vec<WebResource>) -> Self {
+        self.inner.web_resources = Some(value);
+        self
+    }
+    #[doc = "Web Resources Result (recommended)"]
+    pub fn web_resources_result(mut self, value: Vec<WebResource>) -> Self {
+        self.inner.web_resources_result = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<WebResourcesActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "WebResourcesActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("web_resources", self.inner.web_resources.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> WebResourcesActivity {
+        self.inner
+    }
+}
+impl KnownFields for WebResourcesActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "proxy_connection_info" => NetworkConnectionInfo::find_unknown_fields(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "proxy_endpoint" => {
+                    NetworkProxy::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "proxy_tls" => Tls::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "proxy_traffic" => {
+                    NetworkTraffic::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "tls" => Tls::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "web_resources" => {
+                    find_unknown_in_array::<WebResource>(value, &attr_path(path, key), unknown)
+                }
+                "web_resources_result" => {
+                    find_unknown_in_array::<WebResource>(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for WebResourcesActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "proxy_connection_info" => segment.object(self.proxy_connection_info.as_deref()),
+            "proxy_endpoint" => segment.object(self.proxy_endpoint.as_deref()),
+            "proxy_http_request" => segment.object(self.proxy_http_request.as_deref()),
+            "proxy_http_response" => segment.object(self.proxy_http_response.as_deref()),
+            "proxy_tls" => segment.object(self.proxy_tls.as_deref()),
+            "proxy_traffic" => segment.object(self.proxy_traffic.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "tls" => segment.object(self.tls.as_deref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "web_resources" => segment.object_in(self.web_resources.as_deref()),
+            "web_resources_result" => segment.object_in(self.web_resources_result.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "proxy_connection_info" => segment.set_object(&mut self.proxy_connection_info, value),
+            "proxy_endpoint" => segment.set_object(&mut self.proxy_endpoint, value),
+            "proxy_http_request" => segment.set_object(&mut self.proxy_http_request, value),
+            "proxy_http_response" => segment.set_object(&mut self.proxy_http_response, value),
+            "proxy_tls" => segment.set_object(&mut self.proxy_tls, value),
+            "proxy_traffic" => segment.set_object(&mut self.proxy_traffic, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "tls" => segment.set_object(&mut self.tls, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "web_resources" => segment.set_object_in(&mut self.web_resources, value),
+            "web_resources_result" => segment.set_object_in(&mut self.web_resources_result, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for WebResourcesActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.proxy_connection_info {
+            value.collect_observables(&attr_path(path, "proxy_connection_info"), found);
+        }
+        if let Some(value) = &self.proxy_endpoint {
+            value.collect_observables(&attr_path(path, "proxy_endpoint"), found);
+        }
+        if let Some(value) = &self.proxy_http_request {
+            value.collect_observables(&attr_path(path, "proxy_http_request"), found);
+        }
+        if let Some(value) = &self.proxy_http_response {
+            value.collect_observables(&attr_path(path, "proxy_http_response"), found);
+        }
+        if let Some(value) = &self.proxy_tls {
+            value.collect_observables(&attr_path(path, "proxy_tls"), found);
+        }
+        if let Some(value) = &self.proxy_traffic {
+            value.collect_observables(&attr_path(path, "proxy_traffic"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.tls {
+            value.collect_observables(&attr_path(path, "tls"), found);
+        }
+        for value in self.web_resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "web_resources"), found);
+        }
+        for value in self.web_resources_result.iter().flatten() {
+            value.collect_observables(&attr_path(path, "web_resources_result"), found);
+        }
+    }
+}
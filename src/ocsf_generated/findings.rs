@@ -0,0 +1,12095 @@
+#![doc = "Classes of the `findings` category, enabled by the `findings` cargo feature"]
+#![allow(deprecated)]
+#![allow(unused_imports)]
+#![allow(clippy::only_used_in_recursion)]
+use super::*;
+#[doc = "Application Security Posture Finding\n\nThe Application Security Posture Finding event is a notification about any bug, defect, deficiency, exploit, vulnerability, weakness or any other issue with software and related systems. Application Security Posture Findings typically involve reporting on the greater context including compliance, impacted resources, remediation guidance, specific code defects, and/or vulnerability metadata. Application Security Posture Findings can be reported by Threat & Vulnerability Management (TVM) tools, Application Security Posture Management (ASPM) tools, or other similar tools. Note: if the event producer is a security control, the <code>security_control</code> profile should be applied and its <code>attacks</code> information, if present, should be duplicated into the <code>finding_info</code> object. <br><strong>Note: </strong>If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2007] Category: findings | Name: application_security_posture_finding\n\n**Constraints:**\n* at_least_one: `[application`,`compliance`,`remediation`,`vulnerabilities]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ApplicationSecurityPostureFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ApplicationSecurityPostureFindingActivityId>,
+    #[doc = "Activity\n\nThe finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Related Application\n\nAn Application describes the details for an inventoried application as reported by an Application Security tool or other Developer-centric tooling. Applications can be defined as Kubernetes resources, Containerized resources, or application hosting-specific cloud sources such as AWS Elastic BeanStalk, AWS Lightsail, or Azure Logic Apps.\n\nrecommended"]
+    #[serde(rename = "application")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<Box<Application>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Application Security Posture Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Related Compliance\n\nProvides compliance context to vulnerabilities and other weaknesses that are reported as part of an Application Security or Vulnerability Management tool's built-in compliance framework mapping.\n\nrecommended"]
+    #[serde(rename = "compliance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<Box<Compliance>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nDescribes the affected device/host. If applicable, it can be used in conjunction with <code>Resource(s)</code>. <p> e.g. Specific details about an AWS EC2 instance, that is affected by the Finding.</p>\n\noptional"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified vulnerabilities or weaknesses.\n\nrecommended"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Affected Resources\n\nDescribes details about the resource/resources that are affected by the vulnerability/vulnerabilities.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<ApplicationSecurityPostureFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = "Vulnerabilities\n\nThis object describes vulnerabilities reported in a security finding.\n\nrecommended"]
+    #[serde(rename = "vulnerabilities")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<Vulnerability>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ApplicationSecurityPostureFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        report.check_recommended(path, "application", self.application.is_some());
+        if let Some(value) = &self.application {
+            value.validate_at(&attr_path(path, "application"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended(path, "compliance", self.compliance.is_some());
+        if let Some(value) = &self.compliance {
+            value.validate_at(&attr_path(path, "compliance"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "remediation", self.remediation.is_some());
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_recommended(path, "vulnerabilities", self.vulnerabilities.is_some());
+        if let Some(items) = &self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                ("application", self.application.is_some()),
+                ("compliance", self.compliance.is_some()),
+                ("remediation", self.remediation.is_some()),
+                ("vulnerabilities", self.vulnerabilities.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ApplicationSecurityPostureFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.application {
+            value.normalize_at(&attr_path(path, "application"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2007) => Some("Application Security Posture Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        if let Some(value) = &mut self.compliance {
+            value.normalize_at(&attr_path(path, "compliance"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200700) => Some("Application Security Posture Finding: Unknown"),
+                Some(200701) => Some("Application Security Posture Finding: Create"),
+                Some(200702) => Some("Application Security Posture Finding: Update"),
+                Some(200703) => Some("Application Security Posture Finding: Close"),
+                Some(200799) => Some("Application Security Posture Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+        if let Some(items) = &mut self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl ApplicationSecurityPostureFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ApplicationSecurityPostureFinding {
+    const CLASS_UID: i64 = 2007;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "application_security_posture_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Application Security Posture Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl ApplicationSecurityPostureFinding {
+    pub fn builder() -> ApplicationSecurityPostureFindingBuilder {
+        ApplicationSecurityPostureFindingBuilder {
+            inner: ApplicationSecurityPostureFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ApplicationSecurityPostureFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ApplicationSecurityPostureFindingBuilder {
+    inner: ApplicationSecurityPostureFinding,
+}
+impl ApplicationSecurityPostureFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(
+        mut self,
+        value: impl Into<ApplicationSecurityPostureFindingActivityId>,
+    ) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Related Application (recommended)"]
+    pub fn application(mut self, value: Application) -> Self {
+        self.inner.application = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Related Compliance (recommended)"]
+    pub fn compliance(mut self, value: Compliance) -> Self {
+        self.inner.compliance = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (optional)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (recommended)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Affected Resources (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(
+        mut self,
+        value: impl Into<ApplicationSecurityPostureFindingStatusId>,
+    ) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Vulnerabilities (recommended)"]
+    pub fn vulnerabilities(mut self, value: Vec<Vulnerability>) -> Self {
+        self.inner.vulnerabilities = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ApplicationSecurityPostureFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ApplicationSecurityPostureFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ApplicationSecurityPostureFinding {
+        self.inner
+    }
+}
+impl KnownFields for ApplicationSecurityPostureFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "application" => {
+                    Application::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "compliance" => {
+                    Compliance::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "vulnerabilities" => {
+                    find_unknown_in_array::<Vulnerability>(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ApplicationSecurityPostureFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "application" => segment.object(self.application.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "compliance" => segment.object(self.compliance.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            "vulnerabilities" => segment.object_in(self.vulnerabilities.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "application" => segment.set_object(&mut self.application, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "compliance" => segment.set_object(&mut self.compliance, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            "vulnerabilities" => segment.set_object_in(&mut self.vulnerabilities, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ApplicationSecurityPostureFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.application {
+            value.collect_observables(&attr_path(path, "application"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.compliance {
+            value.collect_observables(&attr_path(path, "compliance"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+        for value in self.vulnerabilities.iter().flatten() {
+            value.collect_observables(&attr_path(path, "vulnerabilities"), found);
+        }
+    }
+}
+#[doc = "Compliance Finding\n\nCompliance Finding events describe results of evaluations performed against resources, to check compliance with various Industry Frameworks or Security Standards such as <code>NIST SP 800-53, CIS AWS Foundations Benchmark v1.4.0, ISO/IEC 27001</code> etc. Note: if the event producer is a security control, the <code>security_control</code> profile should be applied and its <code>attacks</code> information, if present, should be duplicated into the <code>finding_info</code> object. <br><strong>Note: </strong>If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2003] Category: findings | Name: compliance_finding"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ComplianceFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ComplianceFindingActivityId>,
+    #[doc = "Activity\n\nThe finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Compliance Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Compliance\n\nThe compliance object provides context to compliance findings (e.g., a check against a specific regulatory or best practice framework such as CIS, NIST etc.) and contains compliance related details.\n\nrequired"]
+    #[serde(rename = "compliance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<Box<Compliance>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nDescribes the affected device/host. If applicable, it can be used in conjunction with <code>Resource(s)</code>. <p> e.g. Specific details about an AWS EC2 instance, that is affected by the Finding.</p>\n\noptional"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Evidence Artifacts\n\nDescribes various evidence artifacts associated with the compliance finding.\n\noptional"]
+    #[serde(rename = "evidences")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidences: Option<Vec<Evidences>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\nrecommended"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Resource\n\nDescribes details about the resource that is the subject of the compliance check.\n\nrecommended"]
+    #[serde(rename = "resource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<Box<ResourceDetails>>,
+    #[doc = "Resources Array\n\nDescribes details about the resource/resources that are the subject of the compliance check.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<ComplianceFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ComplianceFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_required(path, "compliance", self.compliance.is_some());
+        if let Some(value) = &self.compliance {
+            value.validate_at(&attr_path(path, "compliance"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.evidences {
+            let path = attr_path(path, "evidences");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "remediation", self.remediation.is_some());
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        report.check_recommended(path, "resource", self.resource.is_some());
+        if let Some(value) = &self.resource {
+            value.validate_at(&attr_path(path, "resource"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ComplianceFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2003) => Some("Compliance Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        if let Some(value) = &mut self.compliance {
+            value.normalize_at(&attr_path(path, "compliance"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.evidences {
+            let path = attr_path(path, "evidences");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        if let Some(value) = &mut self.resource {
+            value.normalize_at(&attr_path(path, "resource"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200300) => Some("Compliance Finding: Unknown"),
+                Some(200301) => Some("Compliance Finding: Create"),
+                Some(200302) => Some("Compliance Finding: Update"),
+                Some(200303) => Some("Compliance Finding: Close"),
+                Some(200399) => Some("Compliance Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+    }
+}
+impl ComplianceFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ComplianceFinding {
+    const CLASS_UID: i64 = 2003;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "compliance_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Compliance Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl ComplianceFinding {
+    pub fn builder() -> ComplianceFindingBuilder {
+        ComplianceFindingBuilder {
+            inner: ComplianceFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ComplianceFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ComplianceFindingBuilder {
+    inner: ComplianceFinding,
+}
+impl ComplianceFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ComplianceFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Compliance (required)"]
+    pub fn compliance(mut self, value: Compliance) -> Self {
+        self.inner.compliance = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (optional)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Evidence Artifacts (optional)"]
+    pub fn evidences(mut self, value: Vec<Evidences>) -> Self {
+        self.inner.evidences = Some(value);
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (recommended)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Resource (recommended)"]
+    pub fn resource(mut self, value: ResourceDetails) -> Self {
+        self.inner.resource = Some(Box::new(value));
+        self
+    }
+    #[doc = "Resources Array (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<ComplianceFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ComplianceFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ComplianceFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("compliance", self.inner.compliance.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ComplianceFinding {
+        self.inner
+    }
+}
+impl KnownFields for ComplianceFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "compliance" => {
+                    Compliance::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "evidences" => {
+                    find_unknown_in_array::<Evidences>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resource" => {
+                    ResourceDetails::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ComplianceFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "compliance" => segment.object(self.compliance.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "evidences" => segment.object_in(self.evidences.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "resource" => segment.object(self.resource.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "compliance" => segment.set_object(&mut self.compliance, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "evidences" => segment.set_object_in(&mut self.evidences, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "resource" => segment.set_object(&mut self.resource, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ComplianceFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.compliance {
+            value.collect_observables(&attr_path(path, "compliance"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        for value in self.evidences.iter().flatten() {
+            value.collect_observables(&attr_path(path, "evidences"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        if let Some(value) = &self.resource {
+            value.collect_observables(&attr_path(path, "resource"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+    }
+}
+#[doc = "Data Security Finding\n\nA Data Security Finding describes detections or alerts generated by various data security products such as Data Loss Prevention (DLP), Data Classification, Secrets Management, Digital Rights Management (DRM), Data Security Posture Management (DSPM), and similar tools. These detections or alerts can be created using fingerprinting, statistical analysis, machine learning or other methodologies. The finding describes the actors and endpoints who accessed or own the sensitive data, as well as the resources which store the sensitive data. Note: if the event producer is a security control, the <code>security_control</code> profile should be applied and its <code>attacks</code> information, if present, should be duplicated into the <code>finding_info</code> object. <br><strong>Note: </strong>If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile  or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2006] Category: findings | Name: data_security_finding"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DataSecurityFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the Data Security Finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<DataSecurityFindingActivityId>,
+    #[doc = "Activity\n\nThe Data Security finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nDescribes details about the actor implicated in the data security finding. Either an actor that owns a particular digital file or information store, or an actor which accessed classified or sensitive data.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Data Security Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Data Security\n\nThe Data Security object describes the characteristics, techniques and content of a Data Loss Prevention (DLP), Data Loss Detection (DLD), Data Classification, or similar tools' finding, alert, or detection mechanism(s).\n\nrecommended"]
+    #[serde(rename = "data_security")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_security: Option<Box<DataSecurity>>,
+    #[doc = "Database\n\nDescribes the database where classified or sensitive data is stored in, or was accessed from. Databases are typically datastore services that contain an organized collection of structured and/or semi-structured data.\n\nrecommended"]
+    #[serde(rename = "database")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<Box<Database>>,
+    #[doc = "Databucket\n\nDescribes the databucket where classified or sensitive data is stored in, or was accessed from. The data bucket object is a basic container that holds data, typically organized through the use of data partitions.\n\nrecommended"]
+    #[serde(rename = "databucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub databucket: Option<Box<Databucket>>,
+    #[doc = "Device\n\nDescribes the device where classified or sensitive data is stored in, or was accessed from.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nDescribes the endpoint where classified or sensitive data is stored in, or was accessed from.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "File\n\nDescribes a file that contains classified or sensitive data.\n\nrecommended"]
+    #[serde(rename = "file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<File>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\noptional"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\noptional"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. For example, an <code>activity_id</code> of 'Create' could constitute an alertable signal and the value would be <code>true</code>, while 'Close' likely would not and either omit the attribute or set its value to <code>false</code>.  Note that other events with the <code>security_control</code> profile may also be deemed alertable signals and may also carry <code>is_alert = true</code> attributes.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Additional Resources\n\nDescribes details about additional resources, where classified or sensitive data is stored in, or was accessed from. <p> You can populate this object, if the specific resource type objects available in the class (<code>database, databucket, table, file</code>) aren't sufficient; OR <br> You can also choose to duplicate <code>uid, name</code> of the specific resources objects, for a consistent access to resource uids across all findings.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source endpoint where classified or sensitive data was accessed from.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<DataSecurityFindingStatusId>,
+    #[doc = "Table\n\nDescribes the table where classified or sensitive data is stored in, or was accessed from. The table object represents a table within a structured relational database, warehouse, lake, or similar.\n\nrecommended"]
+    #[serde(rename = "table")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<Box<Table>>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for DataSecurityFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "data_security", self.data_security.is_some());
+        if let Some(value) = &self.data_security {
+            value.validate_at(&attr_path(path, "data_security"), report);
+        }
+        report.check_recommended(path, "database", self.database.is_some());
+        if let Some(value) = &self.database {
+            value.validate_at(&attr_path(path, "database"), report);
+        }
+        report.check_recommended(path, "databucket", self.databucket.is_some());
+        if let Some(value) = &self.databucket {
+            value.validate_at(&attr_path(path, "databucket"), report);
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "file", self.file.is_some());
+        if let Some(value) = &self.file {
+            value.validate_at(&attr_path(path, "file"), report);
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_recommended(path, "table", self.table.is_some());
+        if let Some(value) = &self.table {
+            value.validate_at(&attr_path(path, "table"), report);
+        }
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for DataSecurityFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2006) => Some("Data Security Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.data_security {
+            value.normalize_at(&attr_path(path, "data_security"), conflicts);
+        }
+        if let Some(value) = &mut self.database {
+            value.normalize_at(&attr_path(path, "database"), conflicts);
+        }
+        if let Some(value) = &mut self.databucket {
+            value.normalize_at(&attr_path(path, "databucket"), conflicts);
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.file {
+            value.normalize_at(&attr_path(path, "file"), conflicts);
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.table {
+            value.normalize_at(&attr_path(path, "table"), conflicts);
+        }
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200600) => Some("Data Security Finding: Unknown"),
+                Some(200601) => Some("Data Security Finding: Create"),
+                Some(200602) => Some("Data Security Finding: Update"),
+                Some(200603) => Some("Data Security Finding: Close"),
+                Some(200699) => Some("Data Security Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+    }
+}
+impl DataSecurityFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for DataSecurityFinding {
+    const CLASS_UID: i64 = 2006;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "data_security_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Data Security Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl DataSecurityFinding {
+    pub fn builder() -> DataSecurityFindingBuilder {
+        DataSecurityFindingBuilder {
+            inner: DataSecurityFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`DataSecurityFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataSecurityFindingBuilder {
+    inner: DataSecurityFinding,
+}
+impl DataSecurityFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<DataSecurityFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Data Security (recommended)"]
+    pub fn data_security(mut self, value: DataSecurity) -> Self {
+        self.inner.data_security = Some(Box::new(value));
+        self
+    }
+    #[doc = "Database (recommended)"]
+    pub fn database(mut self, value: Database) -> Self {
+        self.inner.database = Some(Box::new(value));
+        self
+    }
+    #[doc = "Databucket (recommended)"]
+    pub fn databucket(mut self, value: Databucket) -> Self {
+        self.inner.databucket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "File (recommended)"]
+    pub fn file(mut self, value: File) -> Self {
+        self.inner.file = Some(Box::new(value));
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (optional)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (optional)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (optional)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Additional Resources (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<DataSecurityFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Table (recommended)"]
+    pub fn table(mut self, value: Table) -> Self {
+        self.inner.table = Some(Box::new(value));
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<DataSecurityFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "DataSecurityFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> DataSecurityFinding {
+        self.inner
+    }
+}
+impl KnownFields for DataSecurityFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "data_security" => {
+                    DataSecurity::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "database" => Database::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "databucket" => {
+                    Databucket::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "table" => Table::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for DataSecurityFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "data_security" => segment.object(self.data_security.as_deref()),
+            "database" => segment.object(self.database.as_deref()),
+            "databucket" => segment.object(self.databucket.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "file" => segment.object(self.file.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "table" => segment.object(self.table.as_deref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "data_security" => segment.set_object(&mut self.data_security, value),
+            "database" => segment.set_object(&mut self.database, value),
+            "databucket" => segment.set_object(&mut self.databucket, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "file" => segment.set_object(&mut self.file, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "table" => segment.set_object(&mut self.table, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for DataSecurityFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.data_security {
+            value.collect_observables(&attr_path(path, "data_security"), found);
+        }
+        if let Some(value) = &self.database {
+            value.collect_observables(&attr_path(path, "database"), found);
+        }
+        if let Some(value) = &self.databucket {
+            value.collect_observables(&attr_path(path, "databucket"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.file {
+            value.collect_observables(&attr_path(path, "file"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.table {
+            value.collect_observables(&attr_path(path, "table"), found);
+        }
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+    }
+}
+#[doc = "Detection Finding\n\nA Detection Finding describes detections or alerts generated by security products using correlation engines, detection engines or other methodologies. Note: if the event producer is a security control, the <code>security_control</code> profile should be applied and its <code>attacks</code> information, if present, should be duplicated into the <code>finding_info</code> object. <br><strong>Note: </strong>If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile  or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2004] Category: findings | Name: detection_finding"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DetectionFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<DetectionFindingActivityId>,
+    #[doc = "Activity\n\nThe finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "Anomaly Analyses\n\nDescribes baseline information about normal activity patterns, along with any detected deviations or anomalies that triggered this finding.\n\noptional"]
+    #[serde(rename = "anomaly_analyses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anomaly_analyses: Option<Vec<AnomalyAnalysis>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Detection Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nDescribes the affected device/host. If applicable, it can be used in conjunction with <code>Resource(s)</code>. <p> e.g. Specific details about an AWS EC2 instance, that is affected by the Finding.</p>\n\noptional"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Evidence Artifacts\n\nDescribes various evidence artifacts associated to the activity/activities that triggered a security detection.\n\nrecommended"]
+    #[serde(rename = "evidences")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidences: Option<Vec<Evidences>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\noptional"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\noptional"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. For example, an <code>activity_id</code> of 'Create' could constitute an alertable signal and the value would be <code>true</code>, while 'Close' likely would not and either omit the attribute or set its value to <code>false</code>.  Note that other events with the <code>security_control</code> profile may also be deemed alertable signals and may also carry <code>is_alert = true</code> attributes.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nDescribes malware reported in a Detection Finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about malware scan job that triggered this Detection Finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Affected Resources\n\nDescribes details about resources that were the target of the activity that triggered the finding.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<DetectionFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = "Vulnerabilities\n\nDescribes vulnerabilities reported in a Detection Finding.\n\noptional"]
+    #[serde(rename = "vulnerabilities")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<Vulnerability>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for DetectionFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(items) = &self.anomaly_analyses {
+            let path = attr_path(path, "anomaly_analyses");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "evidences", self.evidences.is_some());
+        if let Some(items) = &self.evidences {
+            let path = attr_path(path, "evidences");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        if let Some(items) = &self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for DetectionFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(items) = &mut self.anomaly_analyses {
+            let path = attr_path(path, "anomaly_analyses");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2004) => Some("Detection Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.evidences {
+            let path = attr_path(path, "evidences");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200400) => Some("Detection Finding: Unknown"),
+                Some(200401) => Some("Detection Finding: Create"),
+                Some(200402) => Some("Detection Finding: Update"),
+                Some(200403) => Some("Detection Finding: Close"),
+                Some(200499) => Some("Detection Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+        if let Some(items) = &mut self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl DetectionFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for DetectionFinding {
+    const CLASS_UID: i64 = 2004;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "detection_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Detection Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl DetectionFinding {
+    pub fn builder() -> DetectionFindingBuilder {
+        DetectionFindingBuilder {
+            inner: DetectionFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`DetectionFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DetectionFindingBuilder {
+    inner: DetectionFinding,
+}
+impl DetectionFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<DetectionFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "Anomaly Analyses (optional)"]
+    pub fn anomaly_analyses(mut self, value: Vec<AnomalyAnalysis>) -> Self {
+        self.inner.anomaly_analyses = Some(value);
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (optional)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Evidence Artifacts (recommended)"]
+    pub fn evidences(mut self, value: Vec<Evidences>) -> Self {
+        self.inner.evidences = Some(value);
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (optional)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (optional)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (optional)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Affected Resources (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<DetectionFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Vulnerabilities (optional)"]
+    pub fn vulnerabilities(mut self, value: Vec<Vulnerability>) -> Self {
+        self.inner.vulnerabilities = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<DetectionFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "DetectionFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> DetectionFinding {
+        self.inner
+    }
+}
+impl KnownFields for DetectionFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "anomaly_analyses" => {
+                    find_unknown_in_array::<AnomalyAnalysis>(value, &attr_path(path, key), unknown)
+                }
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "evidences" => {
+                    find_unknown_in_array::<Evidences>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "vulnerabilities" => {
+                    find_unknown_in_array::<Vulnerability>(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for DetectionFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "anomaly_analyses" => segment.object_in(self.anomaly_analyses.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "evidences" => segment.object_in(self.evidences.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            "vulnerabilities" => segment.object_in(self.vulnerabilities.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "anomaly_analyses" => segment.set_object_in(&mut self.anomaly_analyses, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "evidences" => segment.set_object_in(&mut self.evidences, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            "vulnerabilities" => segment.set_object_in(&mut self.vulnerabilities, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for DetectionFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        for value in self.anomaly_analyses.iter().flatten() {
+            value.collect_observables(&attr_path(path, "anomaly_analyses"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        for value in self.evidences.iter().flatten() {
+            value.collect_observables(&attr_path(path, "evidences"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+        for value in self.vulnerabilities.iter().flatten() {
+            value.collect_observables(&attr_path(path, "vulnerabilities"), found);
+        }
+    }
+}
+#[doc = "IAM Analysis Finding\n\nThis finding represents an IAM analysis result, which evaluates IAM policies, access patterns, and IAM configurations for potential security risks. The analysis can focus on either an identity (user, role, service account) or a resource to assess permissions, access patterns, and security posture within the IAM domain. <br><strong>Note:</strong> Use <code>permission_analysis_results</code> for identity-centric analysis (evaluating what an identity can do) and <code>access_analysis_result</code> for resource-centric analysis (evaluating who can access a resource). These complement each other for comprehensive IAM security assessment.<br><strong>Note:</strong> If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2008] Category: findings | Name: iam_analysis_finding\n\n**Constraints:**\n* at_least_one: `[access_analysis_result`,`applications`,`identity_activity_metrics`,`permission_analysis_results]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct IamAnalysisFinding {
+    #[doc = "Access Analysis Result\n\nDescribes access relationships and pathways between identities, resources, focusing on who can access what and through which mechanisms. This evaluates access levels (read/write/admin), access types (direct, cross-account, public, federated), and the conditions under which access is granted. Use this for resource-centric security assessments such as external access discovery, public exposure analysis, etc.\n\noptional"]
+    #[serde(rename = "access_analysis_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_analysis_result: Option<Box<AccessAnalysisResult>>,
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<IamAnalysisFindingActivityId>,
+    #[doc = "Activity\n\nThe finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Applications\n\nDetails about applications, services, or systems that are accessible based on the IAM analysis. For identity-centric analysis, this represents applications the identity can access. For resource-centric analysis, this represents applications that can access the resource.\n\nrecommended"]
+    #[serde(rename = "applications")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications: Option<Vec<Application>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>IAM Analysis Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nDescribes the affected device/host. If applicable, it can be used in conjunction with <code>Resource(s)</code>. <p> e.g. Specific details about an AWS EC2 instance, that is affected by the Finding.</p>\n\noptional"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Identity Activity Metrics\n\nDescribes usage activity and other metrics of an Identity i.e. AWS IAM User, GCP IAM Principal, etc.\n\nrecommended"]
+    #[serde(rename = "identity_activity_metrics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_activity_metrics: Option<Box<IdentityActivityMetrics>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Permission Analysis Results\n\nDescribes analysis results of permissions, policies directly associated with an identity (user, role, or service account). This evaluates what permissions an identity has been granted through attached policies, which privileges are actively used versus unused, and identifies potential over-privileged access. Use this for identity-centric security assessments such as privilege audits, dormant permission discovery, and least-privilege compliance analysis.\n\nrecommended"]
+    #[serde(rename = "permission_analysis_results")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_analysis_results: Option<Vec<PermissionAnalysisResult>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Resources Array\n\nDetails about resources involved in the IAM analysis. For identity-centric analysis, this represents resources the identity can access. For resource-centric analysis, this represents the resource being analyzed and related resources in the access chain.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<IamAnalysisFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nDetails about the identity (user, role, service account, or other principal) that is the subject of the IAM analysis. This provides context about the identity being evaluated for security risks and access patterns.\n\nrecommended"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for IamAnalysisFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        if let Some(value) = &self.access_analysis_result {
+            value.validate_at(&attr_path(path, "access_analysis_result"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        report.check_recommended(path, "applications", self.applications.is_some());
+        if let Some(items) = &self.applications {
+            let path = attr_path(path, "applications");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(
+            path,
+            "identity_activity_metrics",
+            self.identity_activity_metrics.is_some(),
+        );
+        if let Some(value) = &self.identity_activity_metrics {
+            value.validate_at(&attr_path(path, "identity_activity_metrics"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(
+            path,
+            "permission_analysis_results",
+            self.permission_analysis_results.is_some(),
+        );
+        if let Some(items) = &self.permission_analysis_results {
+            let path = attr_path(path, "permission_analysis_results");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_recommended(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                (
+                    "access_analysis_result",
+                    self.access_analysis_result.is_some(),
+                ),
+                ("applications", self.applications.is_some()),
+                (
+                    "identity_activity_metrics",
+                    self.identity_activity_metrics.is_some(),
+                ),
+                (
+                    "permission_analysis_results",
+                    self.permission_analysis_results.is_some(),
+                ),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for IamAnalysisFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        if let Some(value) = &mut self.access_analysis_result {
+            value.normalize_at(&attr_path(path, "access_analysis_result"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.applications {
+            let path = attr_path(path, "applications");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2008) => Some("IAM Analysis Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.identity_activity_metrics {
+            value.normalize_at(&attr_path(path, "identity_activity_metrics"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.permission_analysis_results {
+            let path = attr_path(path, "permission_analysis_results");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200800) => Some("IAM Analysis Finding: Unknown"),
+                Some(200801) => Some("IAM Analysis Finding: Create"),
+                Some(200802) => Some("IAM Analysis Finding: Update"),
+                Some(200803) => Some("IAM Analysis Finding: Close"),
+                Some(200899) => Some("IAM Analysis Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+    }
+}
+impl IamAnalysisFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for IamAnalysisFinding {
+    const CLASS_UID: i64 = 2008;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "iam_analysis_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "IAM Analysis Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl IamAnalysisFinding {
+    pub fn builder() -> IamAnalysisFindingBuilder {
+        IamAnalysisFindingBuilder {
+            inner: IamAnalysisFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`IamAnalysisFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IamAnalysisFindingBuilder {
+    inner: IamAnalysisFinding,
+}
+impl IamAnalysisFindingBuilder {
+    #[doc = "Access Analysis Result (optional)"]
+    pub fn access_analysis_result(mut self, value: AccessAnalysisResult) -> Self {
+        self.inner.access_analysis_result = Some(Box::new(value));
+        self
+    }
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<IamAnalysisFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Applications (recommended)"]
+    pub fn applications(mut self, value: Vec<Application>) -> Self {
+        self.inner.applications = Some(value);
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (optional)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Identity Activity Metrics (recommended)"]
+    pub fn identity_activity_metrics(mut self, value: IdentityActivityMetrics) -> Self {
+        self.inner.identity_activity_metrics = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Permission Analysis Results (recommended)"]
+    pub fn permission_analysis_results(mut self, value: Vec<PermissionAnalysisResult>) -> Self {
+        self.inner.permission_analysis_results = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Resources Array (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<IamAnalysisFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (recommended)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<IamAnalysisFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "IamAnalysisFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> IamAnalysisFinding {
+        self.inner
+    }
+}
+impl KnownFields for IamAnalysisFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "access_analysis_result" => {
+                    AccessAnalysisResult::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "applications" => {
+                    find_unknown_in_array::<Application>(value, &attr_path(path, key), unknown)
+                }
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "identity_activity_metrics" => IdentityActivityMetrics::find_unknown_fields(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "permission_analysis_results" => find_unknown_in_array::<PermissionAnalysisResult>(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for IamAnalysisFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "access_analysis_result" => segment.object(self.access_analysis_result.as_deref()),
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "applications" => segment.object_in(self.applications.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "identity_activity_metrics" => {
+                segment.object(self.identity_activity_metrics.as_deref())
+            }
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "permission_analysis_results" => {
+                segment.object_in(self.permission_analysis_results.as_deref())
+            }
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "access_analysis_result" => segment.set_object(&mut self.access_analysis_result, value),
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "applications" => segment.set_object_in(&mut self.applications, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "identity_activity_metrics" => {
+                segment.set_object(&mut self.identity_activity_metrics, value)
+            }
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "permission_analysis_results" => {
+                segment.set_object_in(&mut self.permission_analysis_results, value)
+            }
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for IamAnalysisFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.access_analysis_result {
+            value.collect_observables(&attr_path(path, "access_analysis_result"), found);
+        }
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.applications.iter().flatten() {
+            value.collect_observables(&attr_path(path, "applications"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.identity_activity_metrics {
+            value.collect_observables(&attr_path(path, "identity_activity_metrics"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        for value in self.permission_analysis_results.iter().flatten() {
+            value.collect_observables(&attr_path(path, "permission_analysis_results"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+    }
+}
+#[doc = "Incident Finding\n\nAn Incident Finding reports the creation, update, or closure of security incidents as a result of detections and/or analytics. <br><strong>Note: </strong><code>Incident Finding</code> implicitly includes the <code>incident</code> profile and it should be added to the <code>metadata.profiles[]</code> array.\n\n[UID:2005] Category: findings | Name: incident_finding\n\n**Constraints:**\n* at_least_one: `[assignee`,`assignee_group]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct IncidentFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the Incident activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<IncidentFindingActivityId>,
+    #[doc = "Activity\n\nThe Incident activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of <a target='_blank' href='https://attack.mitre.org'>MITRE ATT&CK®</a> objects describing the tactics, techniques & sub-techniques associated to the Incident.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Incident Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nAdditional user supplied details for updating or closing the incident.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Description\n\nThe short description of the Incident.\n\nrecommended"]
+    #[serde(rename = "desc")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the incident.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the incident.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Finding Information List\n\nA list of <code>finding_info</code> objects associated to an incident.\n\nrequired"]
+    #[serde(rename = "finding_info_list")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info_list: Option<Vec<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the incident.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the incident.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Incident normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Incident.\n\nrequired"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<IncidentFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for IncidentFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "desc", self.desc.is_some());
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info_list", self.finding_info_list.is_some());
+        if let Some(items) = &self.finding_info_list {
+            let path = attr_path(path, "finding_info_list");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_required(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                ("assignee", self.assignee.is_some()),
+                ("assignee_group", self.assignee_group.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for IncidentFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2005) => Some("Incident Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.finding_info_list {
+            let path = attr_path(path, "finding_info_list");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200500) => Some("Incident Finding: Unknown"),
+                Some(200501) => Some("Incident Finding: Create"),
+                Some(200502) => Some("Incident Finding: Update"),
+                Some(200503) => Some("Incident Finding: Close"),
+                Some(200599) => Some("Incident Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+    }
+}
+impl IncidentFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for IncidentFinding {
+    const CLASS_UID: i64 = 2005;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "incident_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Incident Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl IncidentFinding {
+    pub fn builder() -> IncidentFindingBuilder {
+        IncidentFindingBuilder {
+            inner: IncidentFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`IncidentFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IncidentFindingBuilder {
+    inner: IncidentFinding,
+}
+impl IncidentFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<IncidentFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Description (recommended)"]
+    pub fn desc(mut self, value: impl Into<String>) -> Self {
+        self.inner.desc = Some(value.into());
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Finding Information List (required)"]
+    pub fn finding_info_list(mut self, value: Vec<FindingInfo>) -> Self {
+        self.inner.finding_info_list = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (required)"]
+    pub fn status_id(mut self, value: impl Into<IncidentFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<IncidentFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "IncidentFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info_list", self.inner.finding_info_list.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("status_id", self.inner.status_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> IncidentFinding {
+        self.inner
+    }
+}
+impl KnownFields for IncidentFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info_list" => {
+                    find_unknown_in_array::<FindingInfo>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "desc"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for IncidentFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "desc" => segment.leaf(self.desc.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "finding_info_list" => segment.object_in(self.finding_info_list.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "desc" => segment.set_leaf(&mut self.desc, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "finding_info_list" => segment.set_object_in(&mut self.finding_info_list, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for IncidentFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        for value in self.finding_info_list.iter().flatten() {
+            value.collect_observables(&attr_path(path, "finding_info_list"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+    }
+}
+#[doc = "Security Finding\n\nSecurity Finding events describe findings, detections, anomalies, alerts and/or actions performed by security products\n\n[UID:2001] Category: findings | Name: security_finding"]
+#[deprecated(
+    note = "Use the new specific classes according to the use-case: <code>Vulnerability Finding, Compliance Finding, Detection Finding, Incident Finding, Data Security Finding.</code> (Since 1.1.0)"
+)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct SecurityFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<SecurityFindingActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "Analytic\n\nThe analytic technique used to analyze and derive insights from the data or information that led to the finding or conclusion.\n\nrecommended"]
+    #[serde(rename = "analytic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analytic: Option<Box<Analytic>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of <a target='_blank' href='https://attack.mitre.org'>MITRE ATT&CK®</a> objects describing the tactics, techniques & sub-techniques associated to the Finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "CIS CSC\n\nThe CIS Critical Security Controls is a list of top 20 actions and practices an organization’s security team can take on such that cyber attacks or malware, are minimized and prevented.\n\noptional"]
+    #[serde(rename = "cis_csc")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cis_csc: Option<Vec<CisCsc>>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Security Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Compliance\n\nThe compliance object provides context to compliance findings (e.g., a check against a specific regulatory or best practice framework such as CIS, NIST etc.) and contains compliance related details.\n\noptional"]
+    #[serde(rename = "compliance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<Box<Compliance>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\nrecommended"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Data Sources\n\nA list of data sources utilized in generation of the finding.\n\noptional"]
+    #[serde(rename = "data_sources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_sources: Option<Vec<String>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Evidence\n\nThe data the finding exposes to the analyst.\n\noptional"]
+    #[serde(rename = "evidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<serde_json::Value>,
+    #[doc = "Finding\n\nThe Finding object provides details about a finding/detection generated by a security tool.\n\nrequired"]
+    #[serde(rename = "finding")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding: Option<Box<Finding>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Kill Chain\n\nThe <a target='_blank' href='https://www.lockheedmartin.com/en-us/capabilities/cyber/cyber-kill-chain.html'>Cyber Kill Chain®</a> provides a detailed description of each phase and its associated activities within the broader context of a cyber attack.\n\noptional"]
+    #[serde(rename = "kill_chain")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kill_chain: Option<Vec<KillChainPhase>>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "NIST List\n\nThe NIST Cybersecurity Framework recommendations for managing the cybersecurity risk.\n\noptional"]
+    #[serde(rename = "nist")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nist: Option<Vec<String>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Process\n\nThe process object.\n\noptional"]
+    #[serde(rename = "process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<Box<Process>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Resources Array\n\nDescribes details about resources that were affected by the activity/event.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\nrecommended"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\nrecommended"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\nrecommended"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "State\n\nThe normalized state of a security finding.\n\noptional"]
+    #[serde(rename = "state")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[doc = "State ID\n\nThe normalized state identifier of a security finding.\n\nrequired"]
+    #[serde(rename = "state_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<StateId>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vulnerabilities\n\nThis object describes vulnerabilities reported in a security finding.\n\noptional"]
+    #[serde(rename = "vulnerabilities")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<Vulnerability>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for SecurityFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        report.check_recommended(path, "analytic", self.analytic.is_some());
+        if let Some(value) = &self.analytic {
+            value.validate_at(&attr_path(path, "analytic"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        if let Some(items) = &self.cis_csc {
+            let path = attr_path(path, "cis_csc");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        if let Some(value) = &self.compliance {
+            value.validate_at(&attr_path(path, "compliance"), report);
+        }
+        report.check_recommended(path, "confidence", self.confidence.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "confidence_score", self.confidence_score.is_some());
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding", self.finding.is_some());
+        if let Some(value) = &self.finding {
+            value.validate_at(&attr_path(path, "finding"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.kill_chain {
+            let path = attr_path(path, "kill_chain");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.process {
+            value.validate_at(&attr_path(path, "process"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "risk_level", self.risk_level.is_some());
+        report.check_recommended(path, "risk_level_id", self.risk_level_id.is_some());
+        report.check_recommended(path, "risk_score", self.risk_score.is_some());
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_required(path, "state_id", self.state_id.is_some());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(items) = &self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for SecurityFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.analytic {
+            value.normalize_at(&attr_path(path, "analytic"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        if let Some(items) = &mut self.cis_csc {
+            let path = attr_path(path, "cis_csc");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2001) => Some("Security Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        if let Some(value) = &mut self.compliance {
+            value.normalize_at(&attr_path(path, "compliance"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding {
+            value.normalize_at(&attr_path(path, "finding"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.kill_chain {
+            let path = attr_path(path, "kill_chain");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.process {
+            value.normalize_at(&attr_path(path, "process"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "state",
+            self.state_id.map(i64::from),
+            self.state_id.and_then(|id| id.caption()),
+            &mut self.state,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200100) => Some("Security Finding: Unknown"),
+                Some(200101) => Some("Security Finding: Create"),
+                Some(200102) => Some("Security Finding: Update"),
+                Some(200103) => Some("Security Finding: Close"),
+                Some(200199) => Some("Security Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(items) = &mut self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl SecurityFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for SecurityFinding {
+    const CLASS_UID: i64 = 2001;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "security_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Security Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl SecurityFinding {
+    pub fn builder() -> SecurityFindingBuilder {
+        SecurityFindingBuilder {
+            inner: SecurityFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`SecurityFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SecurityFindingBuilder {
+    inner: SecurityFinding,
+}
+impl SecurityFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<SecurityFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "Analytic (recommended)"]
+    pub fn analytic(mut self, value: Analytic) -> Self {
+        self.inner.analytic = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "CIS CSC (optional)"]
+    pub fn cis_csc(mut self, value: Vec<CisCsc>) -> Self {
+        self.inner.cis_csc = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Compliance (optional)"]
+    pub fn compliance(mut self, value: Compliance) -> Self {
+        self.inner.compliance = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (recommended)"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (recommended)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Data Sources (optional)"]
+    pub fn data_sources(mut self, value: Vec<String>) -> Self {
+        self.inner.data_sources = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Evidence (optional)"]
+    pub fn evidence(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.evidence = Some(value.into());
+        self
+    }
+    #[doc = "Finding (required)"]
+    pub fn finding(mut self, value: Finding) -> Self {
+        self.inner.finding = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Kill Chain (optional)"]
+    pub fn kill_chain(mut self, value: Vec<KillChainPhase>) -> Self {
+        self.inner.kill_chain = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "NIST List (optional)"]
+    pub fn nist(mut self, value: Vec<String>) -> Self {
+        self.inner.nist = Some(value);
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Process (optional)"]
+    pub fn process(mut self, value: Process) -> Self {
+        self.inner.process = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Resources Array (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (recommended)"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (recommended)"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (recommended)"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "State (optional)"]
+    pub fn state(mut self, value: impl Into<String>) -> Self {
+        self.inner.state = Some(value.into());
+        self
+    }
+    #[doc = "State ID (required)"]
+    pub fn state_id(mut self, value: impl Into<StateId>) -> Self {
+        self.inner.state_id = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vulnerabilities (optional)"]
+    pub fn vulnerabilities(mut self, value: Vec<Vulnerability>) -> Self {
+        self.inner.vulnerabilities = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<SecurityFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "SecurityFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding", self.inner.finding.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("state_id", self.inner.state_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> SecurityFinding {
+        self.inner
+    }
+}
+impl KnownFields for SecurityFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "analytic" => Analytic::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cis_csc" => find_unknown_in_array::<CisCsc>(value, &attr_path(path, key), unknown),
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "compliance" => {
+                    Compliance::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "finding" => Finding::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "kill_chain" => {
+                    find_unknown_in_array::<KillChainPhase>(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "process" => Process::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "vulnerabilities" => {
+                    find_unknown_in_array::<Vulnerability>(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "data_sources" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "evidence"
+                | "impact" | "impact_id" | "impact_score" | "is_alert" | "message" | "nist"
+                | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "state" | "state_id" | "status" | "status_code"
+                | "status_detail" | "status_id" | "time" | "time_dt" | "timezone_offset"
+                | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for SecurityFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "analytic" => segment.object(self.analytic.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "cis_csc" => segment.object_in(self.cis_csc.as_deref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "compliance" => segment.object(self.compliance.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "data_sources" => segment.leaf_in(self.data_sources.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "evidence" => segment.leaf(self.evidence.as_ref()),
+            "finding" => segment.object(self.finding.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "kill_chain" => segment.object_in(self.kill_chain.as_deref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "nist" => segment.leaf_in(self.nist.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "process" => segment.object(self.process.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "state" => segment.leaf(self.state.as_ref()),
+            "state_id" => segment.leaf(self.state_id.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vulnerabilities" => segment.object_in(self.vulnerabilities.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "analytic" => segment.set_object(&mut self.analytic, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "cis_csc" => segment.set_object_in(&mut self.cis_csc, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "compliance" => segment.set_object(&mut self.compliance, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "data_sources" => segment.set_leaf_in(&mut self.data_sources, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "evidence" => segment.set_leaf(&mut self.evidence, value),
+            "finding" => segment.set_object(&mut self.finding, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "kill_chain" => segment.set_object_in(&mut self.kill_chain, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "nist" => segment.set_leaf_in(&mut self.nist, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "process" => segment.set_object(&mut self.process, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "state" => segment.set_leaf(&mut self.state, value),
+            "state_id" => segment.set_leaf(&mut self.state_id, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vulnerabilities" => segment.set_object_in(&mut self.vulnerabilities, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for SecurityFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.analytic {
+            value.collect_observables(&attr_path(path, "analytic"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        for value in self.cis_csc.iter().flatten() {
+            value.collect_observables(&attr_path(path, "cis_csc"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.compliance {
+            value.collect_observables(&attr_path(path, "compliance"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.finding {
+            value.collect_observables(&attr_path(path, "finding"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.kill_chain.iter().flatten() {
+            value.collect_observables(&attr_path(path, "kill_chain"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.process {
+            value.collect_observables(&attr_path(path, "process"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        for value in self.vulnerabilities.iter().flatten() {
+            value.collect_observables(&attr_path(path, "vulnerabilities"), found);
+        }
+    }
+}
+#[doc = "Vulnerability Finding\n\nThe Vulnerability Finding event is a notification about weakness in an information system, system security procedures, internal controls, or implementation that could be exploited or triggered by a threat source. Note: if the event producer is a security control, the <code>security_control</code> profile should be applied and its <code>attacks</code> information, if present, should be duplicated into the <code>finding_info</code> object. <br><strong>Note: </strong>If the Finding is an incident, i.e. requires incident workflow, also apply the <code>incident</code> profile  or aggregate this finding into an <code>Incident Finding</code>.\n\n[UID:2002] Category: findings | Name: vulnerability_finding"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct VulnerabilityFinding {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the finding activity.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<VulnerabilityFindingActivityId>,
+    #[doc = "Activity\n\nThe finding activity name, as defined by the <code>activity_id</code>.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "Assignee\n\nThe details of the user assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    #[doc = "Assignee Group\n\nThe details of the group assigned to an Incident.\n\noptional"]
+    #[serde(rename = "assignee_group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_group: Option<Box<Group>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Findings</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Vulnerability Finding</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nA user provided comment about the finding.\n\noptional"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nDescribes the affected device/host. If applicable, it can be used in conjunction with <code>Resource(s)</code>. <p> e.g. Specific details about an AWS EC2 instance, that is affected by the Finding.</p>\n\noptional"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe time of the most recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Finding Information\n\nDescribes the supporting information about a generated finding.\n\nrequired"]
+    #[serde(rename = "finding_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_info: Option<Box<FindingInfo>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Impact\n\nThe impact , normalized to the caption of the impact_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "impact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[doc = "Impact ID\n\nThe normalized impact of the incident or finding. Per NIST, this is the magnitude of harm that can be expected to result from the consequences of unauthorized disclosure, modification, destruction, or loss of information or information system availability.\n\nrecommended"]
+    #[serde(rename = "impact_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_id: Option<ImpactId>,
+    #[doc = "Impact Score\n\nThe impact as an integer value of the finding, valid range 0-100.\n\nrecommended"]
+    #[serde(rename = "impact_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_score: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Suspected Breach\n\nA determination based on analytics as to whether a potential breach was found.\n\noptional"]
+    #[serde(rename = "is_suspected_breach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_suspected_breach: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Priority\n\nThe priority, normalized to the caption of the priority_id value. In the case of 'Other', it is defined by the event source.\n\noptional"]
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[doc = "Priority ID\n\nThe normalized priority. Priority identifies the relative importance of the incident or finding. It is a measurement of urgency.\n\nrecommended"]
+    #[serde(rename = "priority_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_id: Option<PriorityId>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Resource\n\nDescribes details about the resource that is affected by the vulnerability/vulnerabilities.\n\nrecommended"]
+    #[serde(rename = "resource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<Box<ResourceDetails>>,
+    #[doc = "Affected Resources\n\nDescribes details about the resource/resources that are affected by the vulnerability/vulnerabilities.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source URL\n\nA Url link used to access the original incident.\n\nrecommended"]
+    #[serde(rename = "src_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_url: Option<String>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe time of the least recent event included in the finding.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe normalized status of the Finding set by the consumer normalized to the caption of the status_id value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized status identifier of the Finding, set by the consumer.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<VulnerabilityFindingStatusId>,
+    #[doc = "Ticket\n\nThe linked ticket in the ticketing system.\n\noptional"]
+    #[serde(rename = "ticket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Box<Ticket>>,
+    #[doc = "Tickets\n\nThe associated ticket(s) in the ticketing system. Each ticket contains details like ticket ID, status, etc.\n\noptional"]
+    #[serde(rename = "tickets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tickets: Option<Vec<Ticket>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "Vendor Attributes\n\nThe Vendor Attributes object can be used to represent values of attributes populated by the Vendor/Finding Provider. It can help distinguish between the vendor-provided values and consumer-updated values, of key attributes like <code>severity_id</code>.<br>The original finding producer should not populate this object. It should be populated by consuming systems that support data mutability.\n\noptional"]
+    #[serde(rename = "vendor_attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_attributes: Option<Box<VendorAttributes>>,
+    #[doc = "Verdict\n\nThe verdict assigned to an Incident finding.\n\nrecommended"]
+    #[serde(rename = "verdict")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    #[doc = "Verdict ID\n\nThe normalized verdict of an Incident.\n\nrecommended"]
+    #[serde(rename = "verdict_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict_id: Option<VerdictId>,
+    #[doc = "Vulnerabilities\n\nThis object describes vulnerabilities reported in a security finding.\n\nrequired"]
+    #[serde(rename = "vulnerabilities")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<Vulnerability>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for VulnerabilityFinding {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(value) = &self.assignee {
+            value.validate_at(&attr_path(path, "assignee"), report);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.validate_at(&attr_path(path, "assignee_group"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "finding_info", self.finding_info.is_some());
+        if let Some(value) = &self.finding_info {
+            value.validate_at(&attr_path(path, "finding_info"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "impact", self.impact.is_some());
+        report.check_recommended(path, "impact_id", self.impact_id.is_some());
+        report.check_recommended(path, "impact_score", self.impact_score.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "priority_id", self.priority_id.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resource", self.resource.is_some());
+        if let Some(value) = &self.resource {
+            value.validate_at(&attr_path(path, "resource"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_url", self.src_url.is_some());
+        report.check_string_format(path, "src_url", StringFormat::Url, self.src_url.as_deref());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        if let Some(value) = &self.ticket {
+            value.validate_at(&attr_path(path, "ticket"), report);
+        }
+        if let Some(items) = &self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        if let Some(value) = &self.vendor_attributes {
+            value.validate_at(&attr_path(path, "vendor_attributes"), report);
+        }
+        report.check_recommended(path, "verdict", self.verdict.is_some());
+        report.check_recommended(path, "verdict_id", self.verdict_id.is_some());
+        report.check_required(path, "vulnerabilities", self.vulnerabilities.is_some());
+        if let Some(items) = &self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for VulnerabilityFinding {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee {
+            value.normalize_at(&attr_path(path, "assignee"), conflicts);
+        }
+        if let Some(value) = &mut self.assignee_group {
+            value.normalize_at(&attr_path(path, "assignee_group"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(2) => Some("Findings"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(2002) => Some("Vulnerability Finding"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.finding_info {
+            value.normalize_at(&attr_path(path, "finding_info"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "impact",
+            self.impact_id.map(i64::from),
+            self.impact_id.and_then(|id| id.caption()),
+            &mut self.impact,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "priority",
+            self.priority_id.map(i64::from),
+            self.priority_id.and_then(|id| id.caption()),
+            &mut self.priority,
+        );
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.resource {
+            value.normalize_at(&attr_path(path, "resource"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.ticket {
+            value.normalize_at(&attr_path(path, "ticket"), conflicts);
+        }
+        if let Some(items) = &mut self.tickets {
+            let path = attr_path(path, "tickets");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(200200) => Some("Vulnerability Finding: Unknown"),
+                Some(200201) => Some("Vulnerability Finding: Create"),
+                Some(200202) => Some("Vulnerability Finding: Update"),
+                Some(200203) => Some("Vulnerability Finding: Close"),
+                Some(200299) => Some("Vulnerability Finding: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.vendor_attributes {
+            value.normalize_at(&attr_path(path, "vendor_attributes"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "verdict",
+            self.verdict_id.map(i64::from),
+            self.verdict_id.and_then(|id| id.caption()),
+            &mut self.verdict,
+        );
+        if let Some(items) = &mut self.vulnerabilities {
+            let path = attr_path(path, "vulnerabilities");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+    }
+}
+impl VulnerabilityFinding {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for VulnerabilityFinding {
+    const CLASS_UID: i64 = 2002;
+    const CATEGORY_UID: i64 = 2;
+    const CLASS_NAME: &'static str = "vulnerability_finding";
+    const CATEGORY_NAME: &'static str = "findings";
+    const CAPTION: &'static str = "Vulnerability Finding";
+    const CATEGORY_CAPTION: &'static str = "Findings";
+}
+impl VulnerabilityFinding {
+    pub fn builder() -> VulnerabilityFindingBuilder {
+        VulnerabilityFindingBuilder {
+            inner: VulnerabilityFinding::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`VulnerabilityFinding`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VulnerabilityFindingBuilder {
+    inner: VulnerabilityFinding,
+}
+impl VulnerabilityFindingBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<VulnerabilityFindingActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee (optional)"]
+    pub fn assignee(mut self, value: User) -> Self {
+        self.inner.assignee = Some(Box::new(value));
+        self
+    }
+    #[doc = "Assignee Group (optional)"]
+    pub fn assignee_group(mut self, value: Group) -> Self {
+        self.inner.assignee_group = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (optional)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (optional)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Finding Information (required)"]
+    pub fn finding_info(mut self, value: FindingInfo) -> Self {
+        self.inner.finding_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Impact (recommended)"]
+    pub fn impact(mut self, value: impl Into<String>) -> Self {
+        self.inner.impact = Some(value.into());
+        self
+    }
+    #[doc = "Impact ID (recommended)"]
+    pub fn impact_id(mut self, value: impl Into<ImpactId>) -> Self {
+        self.inner.impact_id = Some(value.into());
+        self
+    }
+    #[doc = "Impact Score (recommended)"]
+    pub fn impact_score(mut self, value: i64) -> Self {
+        self.inner.impact_score = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Suspected Breach (optional)"]
+    pub fn is_suspected_breach(mut self, value: bool) -> Self {
+        self.inner.is_suspected_breach = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Priority (optional)"]
+    pub fn priority(mut self, value: impl Into<String>) -> Self {
+        self.inner.priority = Some(value.into());
+        self
+    }
+    #[doc = "Priority ID (recommended)"]
+    pub fn priority_id(mut self, value: impl Into<PriorityId>) -> Self {
+        self.inner.priority_id = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Resource (recommended)"]
+    pub fn resource(mut self, value: ResourceDetails) -> Self {
+        self.inner.resource = Some(Box::new(value));
+        self
+    }
+    #[doc = "Affected Resources (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source URL (recommended)"]
+    pub fn src_url(mut self, value: impl Into<String>) -> Self {
+        self.inner.src_url = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (optional)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<VulnerabilityFindingStatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Ticket (optional)"]
+    pub fn ticket(mut self, value: Ticket) -> Self {
+        self.inner.ticket = Some(Box::new(value));
+        self
+    }
+    #[doc = "Tickets (optional)"]
+    pub fn tickets(mut self, value: Vec<Ticket>) -> Self {
+        self.inner.tickets = Some(value);
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Vendor Attributes (optional)"]
+    pub fn vendor_attributes(mut self, value: VendorAttributes) -> Self {
+        self.inner.vendor_attributes = Some(Box::new(value));
+        self
+    }
+    #[doc = "Verdict (recommended)"]
+    pub fn verdict(mut self, value: impl Into<String>) -> Self {
+        self.inner.verdict = Some(value.into());
+        self
+    }
+    #[doc = "Verdict ID (recommended)"]
+    pub fn verdict_id(mut self, value: impl Into<VerdictId>) -> Self {
+        self.inner.verdict_id = Some(value.into());
+        self
+    }
+    #[doc = "Vulnerabilities (required)"]
+    pub fn vulnerabilities(mut self, value: Vec<Vulnerability>) -> Self {
+        self.inner.vulnerabilities = Some(value);
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<VulnerabilityFinding, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "VulnerabilityFinding",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("finding_info", self.inner.finding_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("vulnerabilities", self.inner.vulnerabilities.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> VulnerabilityFinding {
+        self.inner
+    }
+}
+impl KnownFields for VulnerabilityFinding {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "assignee_group" => {
+                    Group::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "finding_info" => {
+                    FindingInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resource" => {
+                    ResourceDetails::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "ticket" => Ticket::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "tickets" => find_unknown_in_array::<Ticket>(value, &attr_path(path, key), unknown),
+                "vendor_attributes" => {
+                    VendorAttributes::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "vulnerabilities" => {
+                    find_unknown_in_array::<Vulnerability>(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "comment"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "impact"
+                | "impact_id"
+                | "impact_score"
+                | "is_alert"
+                | "is_suspected_breach"
+                | "message"
+                | "priority"
+                | "priority_id"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "src_url"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped"
+                | "verdict"
+                | "verdict_id" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for VulnerabilityFinding {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "assignee" => segment.object(self.assignee.as_deref()),
+            "assignee_group" => segment.object(self.assignee_group.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "finding_info" => segment.object(self.finding_info.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "impact" => segment.leaf(self.impact.as_ref()),
+            "impact_id" => segment.leaf(self.impact_id.as_ref()),
+            "impact_score" => segment.leaf(self.impact_score.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_suspected_breach" => segment.leaf(self.is_suspected_breach.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "priority" => segment.leaf(self.priority.as_ref()),
+            "priority_id" => segment.leaf(self.priority_id.as_ref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resource" => segment.object(self.resource.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_url" => segment.leaf(self.src_url.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "ticket" => segment.object(self.ticket.as_deref()),
+            "tickets" => segment.object_in(self.tickets.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "vendor_attributes" => segment.object(self.vendor_attributes.as_deref()),
+            "verdict" => segment.leaf(self.verdict.as_ref()),
+            "verdict_id" => segment.leaf(self.verdict_id.as_ref()),
+            "vulnerabilities" => segment.object_in(self.vulnerabilities.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "assignee" => segment.set_object(&mut self.assignee, value),
+            "assignee_group" => segment.set_object(&mut self.assignee_group, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "finding_info" => segment.set_object(&mut self.finding_info, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "impact" => segment.set_leaf(&mut self.impact, value),
+            "impact_id" => segment.set_leaf(&mut self.impact_id, value),
+            "impact_score" => segment.set_leaf(&mut self.impact_score, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_suspected_breach" => segment.set_leaf(&mut self.is_suspected_breach, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "priority" => segment.set_leaf(&mut self.priority, value),
+            "priority_id" => segment.set_leaf(&mut self.priority_id, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resource" => segment.set_object(&mut self.resource, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_url" => segment.set_leaf(&mut self.src_url, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "ticket" => segment.set_object(&mut self.ticket, value),
+            "tickets" => segment.set_object_in(&mut self.tickets, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "vendor_attributes" => segment.set_object(&mut self.vendor_attributes, value),
+            "verdict" => segment.set_leaf(&mut self.verdict, value),
+            "verdict_id" => segment.set_leaf(&mut self.verdict_id, value),
+            "vulnerabilities" => segment.set_object_in(&mut self.vulnerabilities, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for VulnerabilityFinding {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        if let Some(value) = &self.assignee {
+            value.collect_observables(&attr_path(path, "assignee"), found);
+        }
+        if let Some(value) = &self.assignee_group {
+            value.collect_observables(&attr_path(path, "assignee_group"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.finding_info {
+            value.collect_observables(&attr_path(path, "finding_info"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.resource {
+            value.collect_observables(&attr_path(path, "resource"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        found.add(path, "src_url", 6, self.src_url.as_ref());
+        if let Some(value) = &self.ticket {
+            value.collect_observables(&attr_path(path, "ticket"), found);
+        }
+        for value in self.tickets.iter().flatten() {
+            value.collect_observables(&attr_path(path, "tickets"), found);
+        }
+        if let Some(value) = &self.vendor_attributes {
+            value.collect_observables(&attr_path(path, "vendor_attributes"), found);
+        }
+        for value in self.vulnerabilities.iter().flatten() {
+            value.collect_observables(&attr_path(path, "vulnerabilities"), found);
+        }
+    }
+}
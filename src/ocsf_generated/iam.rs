@@ -0,0 +1,7754 @@
+#![doc = "Classes of the `iam` category, enabled by the `iam` cargo feature"]
+#![allow(deprecated)]
+#![allow(unused_imports)]
+#![allow(clippy::only_used_in_recursion)]
+use super::*;
+#[doc = "Account Change\n\nAccount Change events report when specific user account management tasks are performed, such as a user/role being created, changed, deleted, renamed, disabled, enabled, locked out or unlocked.\n\n[UID:3001] Category: iam | Name: account_change"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct AccountChange {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<AccountChangeActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authentication Factors\n\nDetails about the authentication factors associated with the MFA Factor Enable/Disable activities.\n\noptional"]
+    #[serde(rename = "auth_factors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_factors: Option<Vec<AuthFactor>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Account Change</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policies\n\nDetails about the IAM policies associated with the Attach/Detach Policy activities.\n\noptional"]
+    #[serde(rename = "policies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<Policy>>,
+    #[doc = "Policy\n\nDetails about the IAM policy associated to the Attach/Detach Policy activities.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nThe user that was a target of an activity.\n\nrequired"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = "User Result\n\nThe result of the user account change. It should contain the new values of the changed attributes.\n\nrecommended"]
+    #[serde(rename = "user_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_result: Option<Box<User>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for AccountChange {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.auth_factors {
+            let path = attr_path(path, "auth_factors");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.policies {
+            let path = attr_path(path, "policies");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        report.check_recommended(path, "user_result", self.user_result.is_some());
+        if let Some(value) = &self.user_result {
+            value.validate_at(&attr_path(path, "user_result"), report);
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for AccountChange {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.auth_factors {
+            let path = attr_path(path, "auth_factors");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3001) => Some("Account Change"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.policies {
+            let path = attr_path(path, "policies");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300100) => Some("Account Change: Unknown"),
+                Some(300101) => Some("Account Change: Create"),
+                Some(300102) => Some("Account Change: Enable"),
+                Some(300103) => Some("Account Change: Password Change"),
+                Some(300104) => Some("Account Change: Password Reset"),
+                Some(300105) => Some("Account Change: Disable"),
+                Some(300106) => Some("Account Change: Delete"),
+                Some(300107) => Some("Account Change: Attach Policy"),
+                Some(300108) => Some("Account Change: Detach Policy"),
+                Some(300109) => Some("Account Change: Lock"),
+                Some(300110) => Some("Account Change: MFA Factor Enable"),
+                Some(300111) => Some("Account Change: MFA Factor Disable"),
+                Some(300112) => Some("Account Change: Unlock"),
+                Some(300199) => Some("Account Change: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+        if let Some(value) = &mut self.user_result {
+            value.normalize_at(&attr_path(path, "user_result"), conflicts);
+        }
+    }
+}
+impl AccountChange {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for AccountChange {
+    const CLASS_UID: i64 = 3001;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "account_change";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "Account Change";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl AccountChange {
+    pub fn builder() -> AccountChangeBuilder {
+        AccountChangeBuilder {
+            inner: AccountChange::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`AccountChange`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountChangeBuilder {
+    inner: AccountChange,
+}
+impl AccountChangeBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<AccountChangeActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authentication Factors (optional)"]
+    pub fn auth_factors(mut self, value: Vec<AuthFactor>) -> Self {
+        self.inner.auth_factors = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policies (optional)"]
+    pub fn policies(mut self, value: Vec<Policy>) -> Self {
+        self.inner.policies = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (required)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "User Result (recommended)"]
+    pub fn user_result(mut self, value: User) -> Self {
+        self.inner.user_result = Some(Box::new(value));
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<AccountChange, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "AccountChange",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("user", self.inner.user.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> AccountChange {
+        self.inner
+    }
+}
+impl KnownFields for AccountChange {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "auth_factors" => {
+                    find_unknown_in_array::<AuthFactor>(value, &attr_path(path, key), unknown)
+                }
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policies" => {
+                    find_unknown_in_array::<Policy>(value, &attr_path(path, key), unknown)
+                }
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "user_result" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for AccountChange {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "auth_factors" => segment.object_in(self.auth_factors.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policies" => segment.object_in(self.policies.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            "user_result" => segment.object(self.user_result.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "auth_factors" => segment.set_object_in(&mut self.auth_factors, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policies" => segment.set_object_in(&mut self.policies, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            "user_result" => segment.set_object(&mut self.user_result, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for AccountChange {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.auth_factors.iter().flatten() {
+            value.collect_observables(&attr_path(path, "auth_factors"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        for value in self.policies.iter().flatten() {
+            value.collect_observables(&attr_path(path, "policies"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+        if let Some(value) = &self.user_result {
+            value.collect_observables(&attr_path(path, "user_result"), found);
+        }
+    }
+}
+#[doc = "Authentication\n\nAuthentication events report authentication session activities, including user attempts to log on or log off, regardless of success, as well as other key stages within the authentication process. These events are typically generated by authentication services, such as Kerberos, OIDC, or SAML, and may include information about the user, the authentication method used, and the status of the authentication attempt.\n\n[UID:3002] Category: iam | Name: authentication\n\n**Constraints:**\n* at_least_one: `[service`,`dst_endpoint]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct Authentication {
+    #[doc = "Account Switch Type\n\nThe account switch method, normalized to the caption of the account_switch_type_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "account_switch_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_switch_type: Option<String>,
+    #[doc = "Account Switch Type ID\n\nThe normalized identifier of the account switch method.\n\nrecommended"]
+    #[serde(rename = "account_switch_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_switch_type_id: Option<i64>,
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<AuthenticationActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authentication Factors\n\nDescribes a category of methods used for identity verification in an authentication attempt.\n\noptional"]
+    #[serde(rename = "auth_factors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_factors: Option<Vec<AuthFactor>>,
+    #[doc = "Auth Protocol\n\nThe authentication protocol as defined by the caption of <code>auth_protocol_id</code>. In the case of <code>Other</code>, it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "auth_protocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_protocol: Option<String>,
+    #[doc = "Auth Protocol ID\n\nThe normalized identifier of the authentication protocol used to create the user session.\n\nrecommended"]
+    #[serde(rename = "auth_protocol_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_protocol_id: Option<AuthProtocolId>,
+    #[doc = "Authentication Token\n\nThe authentication token, ticket, or assertion, e.g. <code>Kerberos</code>, <code>OIDC</code>, <code>SAML</code>.\n\noptional"]
+    #[serde(rename = "authentication_token")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<Box<AuthenticationToken>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Certificate\n\nThe certificate associated with the authentication or pre-authentication (Kerberos).\n\nrecommended"]
+    #[serde(rename = "certificate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<Box<Certificate>>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Authentication</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nThe endpoint to which the authentication was targeted.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Cleartext Credentials\n\nIndicates whether the credentials were passed in clear text.<p><b>Note:</b> True if the credentials were passed in a clear text protocol such as FTP or TELNET, or if Windows detected that a user's logon password was passed to the authentication package in clear text.</p>\n\noptional"]
+    #[serde(rename = "is_cleartext")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_cleartext: Option<bool>,
+    #[doc = "Multi Factor Authentication\n\nIndicates whether Multi Factor Authentication was used during authentication.\n\nrecommended"]
+    #[serde(rename = "is_mfa")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mfa: Option<bool>,
+    #[doc = "New Logon\n\nIndicates logon is from a device not seen before or a first time account logon.\n\noptional"]
+    #[serde(rename = "is_new_logon")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_new_logon: Option<bool>,
+    #[doc = "Remote\n\nThe attempted authentication is over a remote connection.\n\nrecommended"]
+    #[serde(rename = "is_remote")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_remote: Option<bool>,
+    #[doc = "Logon Process\n\nThe trusted process that validated the authentication credentials.\n\noptional"]
+    #[serde(rename = "logon_process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logon_process: Option<Box<Process>>,
+    #[doc = "Logon Type\n\nThe logon type, normalized to the caption of the logon_type_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "logon_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logon_type: Option<String>,
+    #[doc = "Logon Type ID\n\nThe normalized logon type identifier.\n\nrecommended"]
+    #[serde(rename = "logon_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logon_type_id: Option<LogonTypeId>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Service\n\nThe service or gateway to which the user or process is being authenticated\n\nrecommended"]
+    #[serde(rename = "service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Box<Service>>,
+    #[doc = "Session\n\nThe authenticated user or service session.\n\nrecommended"]
+    #[serde(rename = "session")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<Box<Session>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe details about the authentication request. For example, possible details for Windows logon or logoff events are:<ul><li>Success</li><ul><li>LOGOFF_USER_INITIATED</li><li>LOGOFF_OTHER</li></ul><li>Failure</li><ul><li>USER_DOES_NOT_EXIST</li><li>INVALID_CREDENTIALS</li><li>ACCOUNT_DISABLED</li><li>ACCOUNT_LOCKED_OUT</li><li>PASSWORD_EXPIRED</li></ul></ul>\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nThe subject (user/role or account) to authenticate.\n\nrequired"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for Authentication {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended(
+            path,
+            "account_switch_type",
+            self.account_switch_type.is_some(),
+        );
+        report.check_recommended(
+            path,
+            "account_switch_type_id",
+            self.account_switch_type_id.is_some(),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.auth_factors {
+            let path = attr_path(path, "auth_factors");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "auth_protocol", self.auth_protocol.is_some());
+        report.check_recommended(path, "auth_protocol_id", self.auth_protocol_id.is_some());
+        if let Some(value) = &self.authentication_token {
+            value.validate_at(&attr_path(path, "authentication_token"), report);
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_recommended(path, "certificate", self.certificate.is_some());
+        if let Some(value) = &self.certificate {
+            value.validate_at(&attr_path(path, "certificate"), report);
+        }
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        report.check_recommended(path, "is_mfa", self.is_mfa.is_some());
+        report.check_recommended(path, "is_remote", self.is_remote.is_some());
+        if let Some(value) = &self.logon_process {
+            value.validate_at(&attr_path(path, "logon_process"), report);
+        }
+        report.check_recommended(path, "logon_type", self.logon_type.is_some());
+        report.check_recommended(path, "logon_type_id", self.logon_type_id.is_some());
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "service", self.service.is_some());
+        if let Some(value) = &self.service {
+            value.validate_at(&attr_path(path, "service"), report);
+        }
+        report.check_recommended(path, "session", self.session.is_some());
+        if let Some(value) = &self.session {
+            value.validate_at(&attr_path(path, "session"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                ("service", self.service.is_some()),
+                ("dst_endpoint", self.dst_endpoint.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for Authentication {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.auth_factors {
+            let path = attr_path(path, "auth_factors");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "auth_protocol",
+            self.auth_protocol_id.map(i64::from),
+            self.auth_protocol_id.and_then(|id| id.caption()),
+            &mut self.auth_protocol,
+        );
+        if let Some(value) = &mut self.authentication_token {
+            value.normalize_at(&attr_path(path, "authentication_token"), conflicts);
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        if let Some(value) = &mut self.certificate {
+            value.normalize_at(&attr_path(path, "certificate"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3002) => Some("Authentication"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(value) = &mut self.logon_process {
+            value.normalize_at(&attr_path(path, "logon_process"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "logon_type",
+            self.logon_type_id.map(i64::from),
+            self.logon_type_id.and_then(|id| id.caption()),
+            &mut self.logon_type,
+        );
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.service {
+            value.normalize_at(&attr_path(path, "service"), conflicts);
+        }
+        if let Some(value) = &mut self.session {
+            value.normalize_at(&attr_path(path, "session"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300200) => Some("Authentication: Unknown"),
+                Some(300201) => Some("Authentication: Logon"),
+                Some(300202) => Some("Authentication: Logoff"),
+                Some(300203) => Some("Authentication: Authentication Ticket"),
+                Some(300204) => Some("Authentication: Service Ticket Request"),
+                Some(300205) => Some("Authentication: Service Ticket Renew"),
+                Some(300206) => Some("Authentication: Preauth"),
+                Some(300299) => Some("Authentication: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+    }
+}
+impl Authentication {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for Authentication {
+    const CLASS_UID: i64 = 3002;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "authentication";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "Authentication";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl Authentication {
+    pub fn builder() -> AuthenticationBuilder {
+        AuthenticationBuilder {
+            inner: Authentication::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`Authentication`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuthenticationBuilder {
+    inner: Authentication,
+}
+impl AuthenticationBuilder {
+    #[doc = "Account Switch Type (recommended)"]
+    pub fn account_switch_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.account_switch_type = Some(value.into());
+        self
+    }
+    #[doc = "Account Switch Type ID (recommended)"]
+    pub fn account_switch_type_id(mut self, value: i64) -> Self {
+        self.inner.account_switch_type_id = Some(value);
+        self
+    }
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<AuthenticationActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authentication Factors (optional)"]
+    pub fn auth_factors(mut self, value: Vec<AuthFactor>) -> Self {
+        self.inner.auth_factors = Some(value);
+        self
+    }
+    #[doc = "Auth Protocol (recommended)"]
+    pub fn auth_protocol(mut self, value: impl Into<String>) -> Self {
+        self.inner.auth_protocol = Some(value.into());
+        self
+    }
+    #[doc = "Auth Protocol ID (recommended)"]
+    pub fn auth_protocol_id(mut self, value: impl Into<AuthProtocolId>) -> Self {
+        self.inner.auth_protocol_id = Some(value.into());
+        self
+    }
+    #[doc = "Authentication Token (optional)"]
+    pub fn authentication_token(mut self, value: AuthenticationToken) -> Self {
+        self.inner.authentication_token = Some(Box::new(value));
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Certificate (recommended)"]
+    pub fn certificate(mut self, value: Certificate) -> Self {
+        self.inner.certificate = Some(Box::new(value));
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Cleartext Credentials (optional)"]
+    pub fn is_cleartext(mut self, value: bool) -> Self {
+        self.inner.is_cleartext = Some(value);
+        self
+    }
+    #[doc = "Multi Factor Authentication (recommended)"]
+    pub fn is_mfa(mut self, value: bool) -> Self {
+        self.inner.is_mfa = Some(value);
+        self
+    }
+    #[doc = "New Logon (optional)"]
+    pub fn is_new_logon(mut self, value: bool) -> Self {
+        self.inner.is_new_logon = Some(value);
+        self
+    }
+    #[doc = "Remote (recommended)"]
+    pub fn is_remote(mut self, value: bool) -> Self {
+        self.inner.is_remote = Some(value);
+        self
+    }
+    #[doc = "Logon Process (optional)"]
+    pub fn logon_process(mut self, value: Process) -> Self {
+        self.inner.logon_process = Some(Box::new(value));
+        self
+    }
+    #[doc = "Logon Type (recommended)"]
+    pub fn logon_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.logon_type = Some(value.into());
+        self
+    }
+    #[doc = "Logon Type ID (recommended)"]
+    pub fn logon_type_id(mut self, value: impl Into<LogonTypeId>) -> Self {
+        self.inner.logon_type_id = Some(value.into());
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Service (recommended)"]
+    pub fn service(mut self, value: Service) -> Self {
+        self.inner.service = Some(Box::new(value));
+        self
+    }
+    #[doc = "Session (recommended)"]
+    pub fn session(mut self, value: Session) -> Self {
+        self.inner.session = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (required)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<Authentication, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "Authentication",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("user", self.inner.user.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> Authentication {
+        self.inner
+    }
+}
+impl KnownFields for Authentication {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "auth_factors" => {
+                    find_unknown_in_array::<AuthFactor>(value, &attr_path(path, key), unknown)
+                }
+                "authentication_token" => {
+                    AuthenticationToken::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "certificate" => {
+                    Certificate::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "logon_process" => {
+                    Process::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "service" => Service::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "session" => Session::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "account_switch_type"
+                | "account_switch_type_id"
+                | "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "auth_protocol"
+                | "auth_protocol_id"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "is_alert"
+                | "is_cleartext"
+                | "is_mfa"
+                | "is_new_logon"
+                | "is_remote"
+                | "logon_type"
+                | "logon_type_id"
+                | "message"
+                | "raw_data"
+                | "raw_data_size"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for Authentication {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "account_switch_type" => segment.leaf(self.account_switch_type.as_ref()),
+            "account_switch_type_id" => segment.leaf(self.account_switch_type_id.as_ref()),
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "auth_factors" => segment.object_in(self.auth_factors.as_deref()),
+            "auth_protocol" => segment.leaf(self.auth_protocol.as_ref()),
+            "auth_protocol_id" => segment.leaf(self.auth_protocol_id.as_ref()),
+            "authentication_token" => segment.object(self.authentication_token.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "certificate" => segment.object(self.certificate.as_deref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "is_cleartext" => segment.leaf(self.is_cleartext.as_ref()),
+            "is_mfa" => segment.leaf(self.is_mfa.as_ref()),
+            "is_new_logon" => segment.leaf(self.is_new_logon.as_ref()),
+            "is_remote" => segment.leaf(self.is_remote.as_ref()),
+            "logon_process" => segment.object(self.logon_process.as_deref()),
+            "logon_type" => segment.leaf(self.logon_type.as_ref()),
+            "logon_type_id" => segment.leaf(self.logon_type_id.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "service" => segment.object(self.service.as_deref()),
+            "session" => segment.object(self.session.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "account_switch_type" => segment.set_leaf(&mut self.account_switch_type, value),
+            "account_switch_type_id" => segment.set_leaf(&mut self.account_switch_type_id, value),
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "auth_factors" => segment.set_object_in(&mut self.auth_factors, value),
+            "auth_protocol" => segment.set_leaf(&mut self.auth_protocol, value),
+            "auth_protocol_id" => segment.set_leaf(&mut self.auth_protocol_id, value),
+            "authentication_token" => segment.set_object(&mut self.authentication_token, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "certificate" => segment.set_object(&mut self.certificate, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "is_cleartext" => segment.set_leaf(&mut self.is_cleartext, value),
+            "is_mfa" => segment.set_leaf(&mut self.is_mfa, value),
+            "is_new_logon" => segment.set_leaf(&mut self.is_new_logon, value),
+            "is_remote" => segment.set_leaf(&mut self.is_remote, value),
+            "logon_process" => segment.set_object(&mut self.logon_process, value),
+            "logon_type" => segment.set_leaf(&mut self.logon_type, value),
+            "logon_type_id" => segment.set_leaf(&mut self.logon_type_id, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "service" => segment.set_object(&mut self.service, value),
+            "session" => segment.set_object(&mut self.session, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for Authentication {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.auth_factors.iter().flatten() {
+            value.collect_observables(&attr_path(path, "auth_factors"), found);
+        }
+        if let Some(value) = &self.authentication_token {
+            value.collect_observables(&attr_path(path, "authentication_token"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.certificate {
+            value.collect_observables(&attr_path(path, "certificate"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        if let Some(value) = &self.logon_process {
+            value.collect_observables(&attr_path(path, "logon_process"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.service {
+            value.collect_observables(&attr_path(path, "service"), found);
+        }
+        if let Some(value) = &self.session {
+            value.collect_observables(&attr_path(path, "session"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+    }
+}
+#[doc = "Authorize Session\n\nAuthorize Session events report privileges or groups assigned to a new user session, usually at login time.\n\n[UID:3003] Category: iam | Name: authorize_session\n\n**Constraints:**\n* just_one: `[privileges`,`group]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct AuthorizeSession {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<AuthorizeSessionActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Authorize Session</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nThe Endpoint for which the user session was targeted.\n\noptional"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Group\n\nGroup that was assigned to the new user session.\n\nrecommended"]
+    #[serde(rename = "group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<Box<Group>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Privileges\n\nThe list of sensitive privileges, assigned to the new user session.\n\nrecommended"]
+    #[serde(rename = "privileges")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileges: Option<Vec<String>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Session\n\nThe user session with the assigned privileges.\n\nrecommended"]
+    #[serde(rename = "session")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<Box<Session>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nThe user to which new privileges were assigned.\n\nrequired"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for AuthorizeSession {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "group", self.group.is_some());
+        if let Some(value) = &self.group {
+            value.validate_at(&attr_path(path, "group"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "privileges", self.privileges.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "session", self.session.is_some());
+        if let Some(value) = &self.session {
+            value.validate_at(&attr_path(path, "session"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        report.check_constraint(
+            path,
+            ConstraintRule::JustOne,
+            &[
+                ("privileges", self.privileges.is_some()),
+                ("group", self.group.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for AuthorizeSession {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3003) => Some("Authorize Session"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.group {
+            value.normalize_at(&attr_path(path, "group"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.session {
+            value.normalize_at(&attr_path(path, "session"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300300) => Some("Authorize Session: Unknown"),
+                Some(300301) => Some("Authorize Session: Assign Privileges"),
+                Some(300302) => Some("Authorize Session: Assign Groups"),
+                Some(300399) => Some("Authorize Session: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+    }
+}
+impl AuthorizeSession {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for AuthorizeSession {
+    const CLASS_UID: i64 = 3003;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "authorize_session";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "Authorize Session";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl AuthorizeSession {
+    pub fn builder() -> AuthorizeSessionBuilder {
+        AuthorizeSessionBuilder {
+            inner: AuthorizeSession::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`AuthorizeSession`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuthorizeSessionBuilder {
+    inner: AuthorizeSession,
+}
+impl AuthorizeSessionBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<AuthorizeSessionActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (optional)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Group (recommended)"]
+    pub fn group(mut self, value: Group) -> Self {
+        self.inner.group = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Privileges (recommended)"]
+    pub fn privileges(mut self, value: Vec<String>) -> Self {
+        self.inner.privileges = Some(value);
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Session (recommended)"]
+    pub fn session(mut self, value: Session) -> Self {
+        self.inner.session = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (required)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<AuthorizeSession, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "AuthorizeSession",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("user", self.inner.user.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> AuthorizeSession {
+        self.inner
+    }
+}
+impl KnownFields for AuthorizeSession {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "group" => Group::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "session" => Session::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "privileges"
+                | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for AuthorizeSession {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "group" => segment.object(self.group.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "privileges" => segment.leaf_in(self.privileges.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "session" => segment.object(self.session.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "group" => segment.set_object(&mut self.group, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "privileges" => segment.set_leaf_in(&mut self.privileges, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "session" => segment.set_object(&mut self.session, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for AuthorizeSession {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.group {
+            value.collect_observables(&attr_path(path, "group"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.session {
+            value.collect_observables(&attr_path(path, "session"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+    }
+}
+#[doc = "Entity Management\n\nEntity Management events report activity by a managed client, a micro service, or a user at a management console. The activity can be a create, read, update, and delete operation on a managed entity.\n\n[UID:3004] Category: iam | Name: entity_management"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct EntityManagement {
+    #[doc = "Access List\n\nThe list of requested access rights.\n\noptional"]
+    #[serde(rename = "access_list")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<Vec<String>>,
+    #[doc = "Access Mask\n\nThe access mask in a platform-native format.\n\noptional"]
+    #[serde(rename = "access_mask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_mask: Option<i64>,
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<EntityManagementActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Entity Management</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Comment\n\nThe user provided comment about why the entity was changed.\n\nrecommended"]
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Entity\n\nThe managed entity that is being acted upon.\n\nrequired"]
+    #[serde(rename = "entity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<ManagedEntity>>,
+    #[doc = "Entity Result\n\nThe updated managed entity.\n\nrecommended"]
+    #[serde(rename = "entity_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_result: Option<Box<ManagedEntity>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for EntityManagement {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended(path, "comment", self.comment.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "entity", self.entity.is_some());
+        if let Some(value) = &self.entity {
+            value.validate_at(&attr_path(path, "entity"), report);
+        }
+        report.check_recommended(path, "entity_result", self.entity_result.is_some());
+        if let Some(value) = &self.entity_result {
+            value.validate_at(&attr_path(path, "entity_result"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for EntityManagement {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3004) => Some("Entity Management"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.entity {
+            value.normalize_at(&attr_path(path, "entity"), conflicts);
+        }
+        if let Some(value) = &mut self.entity_result {
+            value.normalize_at(&attr_path(path, "entity_result"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300400) => Some("Entity Management: Unknown"),
+                Some(300401) => Some("Entity Management: Create"),
+                Some(300402) => Some("Entity Management: Read"),
+                Some(300403) => Some("Entity Management: Update"),
+                Some(300404) => Some("Entity Management: Delete"),
+                Some(300405) => Some("Entity Management: Move"),
+                Some(300406) => Some("Entity Management: Enroll"),
+                Some(300407) => Some("Entity Management: Unenroll"),
+                Some(300408) => Some("Entity Management: Enable"),
+                Some(300409) => Some("Entity Management: Disable"),
+                Some(300410) => Some("Entity Management: Activate"),
+                Some(300411) => Some("Entity Management: Deactivate"),
+                Some(300412) => Some("Entity Management: Suspend"),
+                Some(300413) => Some("Entity Management: Resume"),
+                Some(300499) => Some("Entity Management: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl EntityManagement {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for EntityManagement {
+    const CLASS_UID: i64 = 3004;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "entity_management";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "Entity Management";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl EntityManagement {
+    pub fn builder() -> EntityManagementBuilder {
+        EntityManagementBuilder {
+            inner: EntityManagement::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`EntityManagement`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EntityManagementBuilder {
+    inner: EntityManagement,
+}
+impl EntityManagementBuilder {
+    #[doc = "Access List (optional)"]
+    pub fn access_list(mut self, value: Vec<String>) -> Self {
+        self.inner.access_list = Some(value);
+        self
+    }
+    #[doc = "Access Mask (optional)"]
+    pub fn access_mask(mut self, value: i64) -> Self {
+        self.inner.access_mask = Some(value);
+        self
+    }
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<EntityManagementActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Comment (recommended)"]
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Entity (required)"]
+    pub fn entity(mut self, value: ManagedEntity) -> Self {
+        self.inner.entity = Some(Box::new(value));
+        self
+    }
+    #[doc = "Entity Result (recommended)"]
+    pub fn entity_result(mut self, value: ManagedEntity) -> Self {
+        self.inner.entity_result = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<EntityManagement, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "EntityManagement",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("entity", self.inner.entity.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> EntityManagement {
+        self.inner
+    }
+}
+impl KnownFields for EntityManagement {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "entity" => {
+                    ManagedEntity::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "entity_result" => {
+                    ManagedEntity::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "access_list" | "access_mask" | "action" | "action_id" | "activity_id"
+                | "activity_name" | "category_name" | "category_uid" | "class_name"
+                | "class_uid" | "comment" | "confidence" | "confidence_id" | "confidence_score"
+                | "count" | "disposition" | "disposition_id" | "duration" | "end_time"
+                | "end_time_dt" | "is_alert" | "message" | "raw_data" | "raw_data_size"
+                | "risk_details" | "risk_level" | "risk_level_id" | "risk_score" | "severity"
+                | "severity_id" | "start_time" | "start_time_dt" | "status" | "status_code"
+                | "status_detail" | "status_id" | "time" | "time_dt" | "timezone_offset"
+                | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for EntityManagement {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "access_list" => segment.leaf_in(self.access_list.as_deref()),
+            "access_mask" => segment.leaf(self.access_mask.as_ref()),
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "comment" => segment.leaf(self.comment.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "entity" => segment.object(self.entity.as_deref()),
+            "entity_result" => segment.object(self.entity_result.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "access_list" => segment.set_leaf_in(&mut self.access_list, value),
+            "access_mask" => segment.set_leaf(&mut self.access_mask, value),
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "comment" => segment.set_leaf(&mut self.comment, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "entity" => segment.set_object(&mut self.entity, value),
+            "entity_result" => segment.set_object(&mut self.entity_result, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for EntityManagement {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.entity {
+            value.collect_observables(&attr_path(path, "entity"), found);
+        }
+        if let Some(value) = &self.entity_result {
+            value.collect_observables(&attr_path(path, "entity_result"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+    }
+}
+#[doc = "Group Management\n\nGroup Management events report management updates to a group, including updates to membership and permissions.\n\n[UID:3006] Category: iam | Name: group_management"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct GroupManagement {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<GroupManagementActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Group Management</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Group\n\nGroup that was the target of the event.\n\nrequired"]
+    #[serde(rename = "group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<Box<Group>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Privileges\n\nA list of privileges assigned to the group.\n\nrecommended"]
+    #[serde(rename = "privileges")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileges: Option<Vec<String>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Resource\n\nResource that the privileges give access to.\n\nrecommended"]
+    #[serde(rename = "resource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<Box<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Subgroup\n\nA subgroup that was added to or removed from the group.\n\nrecommended"]
+    #[serde(rename = "subgroup")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subgroup: Option<Box<Group>>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nA user that was added to or removed from the group.\n\nrecommended"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for GroupManagement {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_required(path, "group", self.group.is_some());
+        if let Some(value) = &self.group {
+            value.validate_at(&attr_path(path, "group"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_recommended(path, "privileges", self.privileges.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resource", self.resource.is_some());
+        if let Some(value) = &self.resource {
+            value.validate_at(&attr_path(path, "resource"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_recommended(path, "subgroup", self.subgroup.is_some());
+        if let Some(value) = &self.subgroup {
+            value.validate_at(&attr_path(path, "subgroup"), report);
+        }
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_recommended(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for GroupManagement {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3006) => Some("Group Management"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.group {
+            value.normalize_at(&attr_path(path, "group"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.resource {
+            value.normalize_at(&attr_path(path, "resource"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        if let Some(value) = &mut self.subgroup {
+            value.normalize_at(&attr_path(path, "subgroup"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300600) => Some("Group Management: Unknown"),
+                Some(300601) => Some("Group Management: Assign Privileges"),
+                Some(300602) => Some("Group Management: Revoke Privileges"),
+                Some(300603) => Some("Group Management: Add User"),
+                Some(300604) => Some("Group Management: Remove User"),
+                Some(300605) => Some("Group Management: Delete"),
+                Some(300606) => Some("Group Management: Create"),
+                Some(300699) => Some("Group Management: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+    }
+}
+impl GroupManagement {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for GroupManagement {
+    const CLASS_UID: i64 = 3006;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "group_management";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "Group Management";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl GroupManagement {
+    pub fn builder() -> GroupManagementBuilder {
+        GroupManagementBuilder {
+            inner: GroupManagement::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`GroupManagement`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GroupManagementBuilder {
+    inner: GroupManagement,
+}
+impl GroupManagementBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<GroupManagementActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Group (required)"]
+    pub fn group(mut self, value: Group) -> Self {
+        self.inner.group = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Privileges (recommended)"]
+    pub fn privileges(mut self, value: Vec<String>) -> Self {
+        self.inner.privileges = Some(value);
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Resource (recommended)"]
+    pub fn resource(mut self, value: ResourceDetails) -> Self {
+        self.inner.resource = Some(Box::new(value));
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Subgroup (recommended)"]
+    pub fn subgroup(mut self, value: Group) -> Self {
+        self.inner.subgroup = Some(Box::new(value));
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (recommended)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<GroupManagement, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "GroupManagement",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("group", self.inner.group.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> GroupManagement {
+        self.inner
+    }
+}
+impl KnownFields for GroupManagement {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "group" => Group::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resource" => {
+                    ResourceDetails::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "subgroup" => Group::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "privileges"
+                | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for GroupManagement {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "group" => segment.object(self.group.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "privileges" => segment.leaf_in(self.privileges.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resource" => segment.object(self.resource.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "subgroup" => segment.object(self.subgroup.as_deref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "group" => segment.set_object(&mut self.group, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "privileges" => segment.set_leaf_in(&mut self.privileges, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resource" => segment.set_object(&mut self.resource, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "subgroup" => segment.set_object(&mut self.subgroup, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for GroupManagement {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.group {
+            value.collect_observables(&attr_path(path, "group"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.resource {
+            value.collect_observables(&attr_path(path, "resource"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.subgroup {
+            value.collect_observables(&attr_path(path, "subgroup"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+    }
+}
+#[doc = "User Access Management\n\nUser Access Management events report management updates to a user's privileges.\n\n[UID:3005] Category: iam | Name: user_access"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct UserAccess {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<UserAccessActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Identity & Access Management</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>User Access Management</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "HTTP Request\n\nDetails about the underlying HTTP request.\n\noptional"]
+    #[serde(rename = "http_request")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_request: Option<Box<HttpRequest>>,
+    #[doc = "HTTP Response\n\nDetails about the underlying HTTP response.\n\noptional"]
+    #[serde(rename = "http_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response: Option<Box<HttpResponse>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Privileges\n\nList of privileges assigned to a user.\n\nrequired"]
+    #[serde(rename = "privileges")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileges: Option<Vec<String>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Resource\n\nResource that the privileges give access to.\n\nrecommended"]
+    #[serde(rename = "resource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<Box<ResourceDetails>>,
+    #[doc = "Resources Array\n\nResources that the privileges give access to.\n\nrecommended"]
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceDetails>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nDetails about the source of the IAM activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = "User\n\nUser to which privileges were assigned.\n\nrequired"]
+    #[serde(rename = "user")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for UserAccess {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        if let Some(value) = &self.http_request {
+            value.validate_at(&attr_path(path, "http_request"), report);
+        }
+        if let Some(value) = &self.http_response {
+            value.validate_at(&attr_path(path, "http_response"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_required(path, "privileges", self.privileges.is_some());
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(path, "resource", self.resource.is_some());
+        if let Some(value) = &self.resource {
+            value.validate_at(&attr_path(path, "resource"), report);
+        }
+        report.check_recommended(path, "resources", self.resources.is_some());
+        if let Some(items) = &self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_required(path, "user", self.user.is_some());
+        if let Some(value) = &self.user {
+            value.validate_at(&attr_path(path, "user"), report);
+        }
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for UserAccess {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(3) => Some("Identity & Access Management"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(3005) => Some("User Access Management"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.http_request {
+            value.normalize_at(&attr_path(path, "http_request"), conflicts);
+        }
+        if let Some(value) = &mut self.http_response {
+            value.normalize_at(&attr_path(path, "http_response"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.resource {
+            value.normalize_at(&attr_path(path, "resource"), conflicts);
+        }
+        if let Some(items) = &mut self.resources {
+            let path = attr_path(path, "resources");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(300500) => Some("User Access Management: Unknown"),
+                Some(300501) => Some("User Access Management: Assign Privileges"),
+                Some(300502) => Some("User Access Management: Revoke Privileges"),
+                Some(300599) => Some("User Access Management: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+        if let Some(value) = &mut self.user {
+            value.normalize_at(&attr_path(path, "user"), conflicts);
+        }
+    }
+}
+impl UserAccess {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for UserAccess {
+    const CLASS_UID: i64 = 3005;
+    const CATEGORY_UID: i64 = 3;
+    const CLASS_NAME: &'static str = "user_access";
+    const CATEGORY_NAME: &'static str = "iam";
+    const CAPTION: &'static str = "User Access Management";
+    const CATEGORY_CAPTION: &'static str = "Identity & Access Management";
+}
+impl UserAccess {
+    pub fn builder() -> UserAccessBuilder {
+        UserAccessBuilder {
+            inner: UserAccess::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`UserAccess`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UserAccessBuilder {
+    inner: UserAccess,
+}
+impl UserAccessBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<UserAccessActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Request (optional)"]
+    pub fn http_request(mut self, value: HttpRequest) -> Self {
+        self.inner.http_request = Some(Box::new(value));
+        self
+    }
+    #[doc = "HTTP Response (optional)"]
+    pub fn http_response(mut self, value: HttpResponse) -> Self {
+        self.inner.http_response = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Privileges (required)"]
+    pub fn privileges(mut self, value: Vec<String>) -> Self {
+        self.inner.privileges = Some(value);
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Resource (recommended)"]
+    pub fn resource(mut self, value: ResourceDetails) -> Self {
+        self.inner.resource = Some(Box::new(value));
+        self
+    }
+    #[doc = "Resources Array (recommended)"]
+    pub fn resources(mut self, value: Vec<ResourceDetails>) -> Self {
+        self.inner.resources = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "User (required)"]
+    pub fn user(mut self, value: User) -> Self {
+        self.inner.user = Some(Box::new(value));
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<UserAccess, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "UserAccess",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("privileges", self.inner.privileges.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+                ("user", self.inner.user.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> UserAccess {
+        self.inner
+    }
+}
+impl KnownFields for UserAccess {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_request" => {
+                    HttpRequest::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "http_response" => {
+                    HttpResponse::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resource" => {
+                    ResourceDetails::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "resources" => {
+                    find_unknown_in_array::<ResourceDetails>(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "user" => User::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "privileges"
+                | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for UserAccess {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "http_request" => segment.object(self.http_request.as_deref()),
+            "http_response" => segment.object(self.http_response.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "privileges" => segment.leaf_in(self.privileges.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "resource" => segment.object(self.resource.as_deref()),
+            "resources" => segment.object_in(self.resources.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            "user" => segment.object(self.user.as_deref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "http_request" => segment.set_object(&mut self.http_request, value),
+            "http_response" => segment.set_object(&mut self.http_response, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "privileges" => segment.set_leaf_in(&mut self.privileges, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "resource" => segment.set_object(&mut self.resource, value),
+            "resources" => segment.set_object_in(&mut self.resources, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            "user" => segment.set_object(&mut self.user, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for UserAccess {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.http_request {
+            value.collect_observables(&attr_path(path, "http_request"), found);
+        }
+        if let Some(value) = &self.http_response {
+            value.collect_observables(&attr_path(path, "http_response"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.resource {
+            value.collect_observables(&attr_path(path, "resource"), found);
+        }
+        for value in self.resources.iter().flatten() {
+            value.collect_observables(&attr_path(path, "resources"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+        if let Some(value) = &self.user {
+            value.collect_observables(&attr_path(path, "user"), found);
+        }
+    }
+}
@@ -0,0 +1,4722 @@
+#![doc = "Classes of the `remediation` category, enabled by the `remediation` cargo feature"]
+#![allow(deprecated)]
+#![allow(unused_imports)]
+#![allow(clippy::only_used_in_recursion)]
+use super::*;
+#[doc = "File Remediation Activity\n\nFile Remediation Activity events report on attempts at remediating files. It follows the MITRE countermeasures defined by the D3FEND™ <a target='_blank' href='https://d3fend.mitre.org/'>Matrix</a>. Sub-techniques will include File, such as File Removal or Restore File.\n\n[UID:7002] Category: remediation | Name: file_remediation_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct FileRemediationActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nMatches the MITRE D3FEND™ Tactic. Note: the Model and Detect Tactics are not supported as remediations by the OCSF Remediation event class.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<FileRemediationActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Remediation</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>File Remediation Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Command UID\n\nThe unique identifier of the remediation command that pertains to this event.\n\nrequired"]
+    #[serde(rename = "command_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_uid: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Countermeasures\n\nThe MITRE D3FEND™ Matrix Countermeasures associated with a remediation.\n\nrecommended"]
+    #[serde(rename = "countermeasures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countermeasures: Option<Vec<D3fend>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "File\n\nThe file that pertains to the remediation event.\n\nrequired"]
+    #[serde(rename = "file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<File>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Scan\n\nThe remediation scan that pertains to this event.\n\noptional"]
+    #[serde(rename = "scan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<Box<Scan>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for FileRemediationActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_required(path, "command_uid", self.command_uid.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "countermeasures", self.countermeasures.is_some());
+        if let Some(items) = &self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "file", self.file.is_some());
+        if let Some(value) = &self.file {
+            value.validate_at(&attr_path(path, "file"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        if let Some(value) = &self.scan {
+            value.validate_at(&attr_path(path, "scan"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for FileRemediationActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(7) => Some("Remediation"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(7002) => Some("File Remediation Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(items) = &mut self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.file {
+            value.normalize_at(&attr_path(path, "file"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.scan {
+            value.normalize_at(&attr_path(path, "scan"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(700200) => Some("File Remediation Activity: Unknown"),
+                Some(700201) => Some("File Remediation Activity: Isolate"),
+                Some(700202) => Some("File Remediation Activity: Evict"),
+                Some(700203) => Some("File Remediation Activity: Restore"),
+                Some(700204) => Some("File Remediation Activity: Harden"),
+                Some(700205) => Some("File Remediation Activity: Detect"),
+                Some(700299) => Some("File Remediation Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl FileRemediationActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for FileRemediationActivity {
+    const CLASS_UID: i64 = 7002;
+    const CATEGORY_UID: i64 = 7;
+    const CLASS_NAME: &'static str = "file_remediation_activity";
+    const CATEGORY_NAME: &'static str = "remediation";
+    const CAPTION: &'static str = "File Remediation Activity";
+    const CATEGORY_CAPTION: &'static str = "Remediation";
+}
+impl FileRemediationActivity {
+    pub fn builder() -> FileRemediationActivityBuilder {
+        FileRemediationActivityBuilder {
+            inner: FileRemediationActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`FileRemediationActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileRemediationActivityBuilder {
+    inner: FileRemediationActivity,
+}
+impl FileRemediationActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<FileRemediationActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Command UID (required)"]
+    pub fn command_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.command_uid = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Countermeasures (recommended)"]
+    pub fn countermeasures(mut self, value: Vec<D3fend>) -> Self {
+        self.inner.countermeasures = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "File (required)"]
+    pub fn file(mut self, value: File) -> Self {
+        self.inner.file = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Scan (optional)"]
+    pub fn scan(mut self, value: Scan) -> Self {
+        self.inner.scan = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<FileRemediationActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "FileRemediationActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("command_uid", self.inner.command_uid.is_some()),
+                ("file", self.inner.file.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> FileRemediationActivity {
+        self.inner
+    }
+}
+impl KnownFields for FileRemediationActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "countermeasures" => {
+                    find_unknown_in_array::<D3fend>(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "scan" => Scan::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "command_uid" | "confidence"
+                | "confidence_id" | "confidence_score" | "count" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "is_alert"
+                | "message" | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for FileRemediationActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "command_uid" => segment.leaf(self.command_uid.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "countermeasures" => segment.object_in(self.countermeasures.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "file" => segment.object(self.file.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "scan" => segment.object(self.scan.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "command_uid" => segment.set_leaf(&mut self.command_uid, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "countermeasures" => segment.set_object_in(&mut self.countermeasures, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "file" => segment.set_object(&mut self.file, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "scan" => segment.set_object(&mut self.scan, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for FileRemediationActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        for value in self.countermeasures.iter().flatten() {
+            value.collect_observables(&attr_path(path, "countermeasures"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.file {
+            value.collect_observables(&attr_path(path, "file"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        if let Some(value) = &self.scan {
+            value.collect_observables(&attr_path(path, "scan"), found);
+        }
+    }
+}
+#[doc = "Network Remediation Activity\n\nNetwork Remediation Activity events report on attempts at remediating computer networks. It follows the MITRE countermeasures defined by the D3FEND™ <a target='_blank' href='https://d3fend.mitre.org/'>Matrix</a>. Techniques and Sub-techniques will include Network, such as Network Isolation or Network Traffic Filtering.\n\n[UID:7004] Category: remediation | Name: network_remediation_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct NetworkRemediationActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nMatches the MITRE D3FEND™ Tactic. Note: the Model and Detect Tactics are not supported as remediations by the OCSF Remediation event class.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<NetworkRemediationActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Remediation</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Network Remediation Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Command UID\n\nThe unique identifier of the remediation command that pertains to this event.\n\nrequired"]
+    #[serde(rename = "command_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_uid: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Connection Info\n\nThe network connection that pertains to the remediation event.\n\nrequired"]
+    #[serde(rename = "connection_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_info: Option<Box<NetworkConnectionInfo>>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Countermeasures\n\nThe MITRE D3FEND™ Matrix Countermeasures associated with a remediation.\n\nrecommended"]
+    #[serde(rename = "countermeasures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countermeasures: Option<Vec<D3fend>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Scan\n\nThe remediation scan that pertains to this event.\n\noptional"]
+    #[serde(rename = "scan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<Box<Scan>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for NetworkRemediationActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_required(path, "command_uid", self.command_uid.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "connection_info", self.connection_info.is_some());
+        if let Some(value) = &self.connection_info {
+            value.validate_at(&attr_path(path, "connection_info"), report);
+        }
+        report.check_recommended(path, "countermeasures", self.countermeasures.is_some());
+        if let Some(items) = &self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        if let Some(value) = &self.scan {
+            value.validate_at(&attr_path(path, "scan"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for NetworkRemediationActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(7) => Some("Remediation"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(7004) => Some("Network Remediation Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.connection_info {
+            value.normalize_at(&attr_path(path, "connection_info"), conflicts);
+        }
+        if let Some(items) = &mut self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.scan {
+            value.normalize_at(&attr_path(path, "scan"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(700400) => Some("Network Remediation Activity: Unknown"),
+                Some(700401) => Some("Network Remediation Activity: Isolate"),
+                Some(700402) => Some("Network Remediation Activity: Evict"),
+                Some(700403) => Some("Network Remediation Activity: Restore"),
+                Some(700404) => Some("Network Remediation Activity: Harden"),
+                Some(700405) => Some("Network Remediation Activity: Detect"),
+                Some(700499) => Some("Network Remediation Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl NetworkRemediationActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for NetworkRemediationActivity {
+    const CLASS_UID: i64 = 7004;
+    const CATEGORY_UID: i64 = 7;
+    const CLASS_NAME: &'static str = "network_remediation_activity";
+    const CATEGORY_NAME: &'static str = "remediation";
+    const CAPTION: &'static str = "Network Remediation Activity";
+    const CATEGORY_CAPTION: &'static str = "Remediation";
+}
+impl NetworkRemediationActivity {
+    pub fn builder() -> NetworkRemediationActivityBuilder {
+        NetworkRemediationActivityBuilder {
+            inner: NetworkRemediationActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`NetworkRemediationActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NetworkRemediationActivityBuilder {
+    inner: NetworkRemediationActivity,
+}
+impl NetworkRemediationActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<NetworkRemediationActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Command UID (required)"]
+    pub fn command_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.command_uid = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Connection Info (required)"]
+    pub fn connection_info(mut self, value: NetworkConnectionInfo) -> Self {
+        self.inner.connection_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Countermeasures (recommended)"]
+    pub fn countermeasures(mut self, value: Vec<D3fend>) -> Self {
+        self.inner.countermeasures = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Scan (optional)"]
+    pub fn scan(mut self, value: Scan) -> Self {
+        self.inner.scan = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<NetworkRemediationActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "NetworkRemediationActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("command_uid", self.inner.command_uid.is_some()),
+                ("connection_info", self.inner.connection_info.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> NetworkRemediationActivity {
+        self.inner
+    }
+}
+impl KnownFields for NetworkRemediationActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "connection_info" => NetworkConnectionInfo::find_unknown_fields(
+                    value,
+                    &attr_path(path, key),
+                    unknown,
+                ),
+                "countermeasures" => {
+                    find_unknown_in_array::<D3fend>(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "scan" => Scan::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "command_uid" | "confidence"
+                | "confidence_id" | "confidence_score" | "count" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "is_alert"
+                | "message" | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for NetworkRemediationActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "command_uid" => segment.leaf(self.command_uid.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "connection_info" => segment.object(self.connection_info.as_deref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "countermeasures" => segment.object_in(self.countermeasures.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "scan" => segment.object(self.scan.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "command_uid" => segment.set_leaf(&mut self.command_uid, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "connection_info" => segment.set_object(&mut self.connection_info, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "countermeasures" => segment.set_object_in(&mut self.countermeasures, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "scan" => segment.set_object(&mut self.scan, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for NetworkRemediationActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.connection_info {
+            value.collect_observables(&attr_path(path, "connection_info"), found);
+        }
+        for value in self.countermeasures.iter().flatten() {
+            value.collect_observables(&attr_path(path, "countermeasures"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        if let Some(value) = &self.scan {
+            value.collect_observables(&attr_path(path, "scan"), found);
+        }
+    }
+}
+#[doc = "Process Remediation Activity\n\nProcess Remediation Activity events report on attempts at remediating processes. It follows the MITRE countermeasures defined by the D3FEND™ <a target='_blank' href='https://d3fend.mitre.org/'>Matrix</a>. Sub-techniques will include Process, such as Process Termination or Kernel-based Process Isolation.\n\n[UID:7003] Category: remediation | Name: process_remediation_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ProcessRemediationActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nMatches the MITRE D3FEND™ Tactic. Note: the Model and Detect Tactics are not supported as remediations by the OCSF Remediation event class.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ProcessRemediationActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Remediation</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Process Remediation Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Command UID\n\nThe unique identifier of the remediation command that pertains to this event.\n\nrequired"]
+    #[serde(rename = "command_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_uid: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Countermeasures\n\nThe MITRE D3FEND™ Matrix Countermeasures associated with a remediation.\n\nrecommended"]
+    #[serde(rename = "countermeasures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countermeasures: Option<Vec<D3fend>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Process\n\nThe process that pertains to the remediation event.\n\nrequired"]
+    #[serde(rename = "process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<Box<Process>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Scan\n\nThe remediation scan that pertains to this event.\n\noptional"]
+    #[serde(rename = "scan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<Box<Scan>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ProcessRemediationActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_required(path, "command_uid", self.command_uid.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "countermeasures", self.countermeasures.is_some());
+        if let Some(items) = &self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_required(path, "process", self.process.is_some());
+        if let Some(value) = &self.process {
+            value.validate_at(&attr_path(path, "process"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        if let Some(value) = &self.scan {
+            value.validate_at(&attr_path(path, "scan"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ProcessRemediationActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(7) => Some("Remediation"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(7003) => Some("Process Remediation Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(items) = &mut self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.process {
+            value.normalize_at(&attr_path(path, "process"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.scan {
+            value.normalize_at(&attr_path(path, "scan"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(700300) => Some("Process Remediation Activity: Unknown"),
+                Some(700301) => Some("Process Remediation Activity: Isolate"),
+                Some(700302) => Some("Process Remediation Activity: Evict"),
+                Some(700303) => Some("Process Remediation Activity: Restore"),
+                Some(700304) => Some("Process Remediation Activity: Harden"),
+                Some(700305) => Some("Process Remediation Activity: Detect"),
+                Some(700399) => Some("Process Remediation Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ProcessRemediationActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ProcessRemediationActivity {
+    const CLASS_UID: i64 = 7003;
+    const CATEGORY_UID: i64 = 7;
+    const CLASS_NAME: &'static str = "process_remediation_activity";
+    const CATEGORY_NAME: &'static str = "remediation";
+    const CAPTION: &'static str = "Process Remediation Activity";
+    const CATEGORY_CAPTION: &'static str = "Remediation";
+}
+impl ProcessRemediationActivity {
+    pub fn builder() -> ProcessRemediationActivityBuilder {
+        ProcessRemediationActivityBuilder {
+            inner: ProcessRemediationActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ProcessRemediationActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProcessRemediationActivityBuilder {
+    inner: ProcessRemediationActivity,
+}
+impl ProcessRemediationActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ProcessRemediationActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Command UID (required)"]
+    pub fn command_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.command_uid = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Countermeasures (recommended)"]
+    pub fn countermeasures(mut self, value: Vec<D3fend>) -> Self {
+        self.inner.countermeasures = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Process (required)"]
+    pub fn process(mut self, value: Process) -> Self {
+        self.inner.process = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Scan (optional)"]
+    pub fn scan(mut self, value: Scan) -> Self {
+        self.inner.scan = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ProcessRemediationActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ProcessRemediationActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("command_uid", self.inner.command_uid.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("process", self.inner.process.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ProcessRemediationActivity {
+        self.inner
+    }
+}
+impl KnownFields for ProcessRemediationActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "countermeasures" => {
+                    find_unknown_in_array::<D3fend>(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "process" => Process::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "scan" => Scan::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "command_uid" | "confidence"
+                | "confidence_id" | "confidence_score" | "count" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "is_alert"
+                | "message" | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ProcessRemediationActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "command_uid" => segment.leaf(self.command_uid.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "countermeasures" => segment.object_in(self.countermeasures.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "process" => segment.object(self.process.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "scan" => segment.object(self.scan.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "command_uid" => segment.set_leaf(&mut self.command_uid, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "countermeasures" => segment.set_object_in(&mut self.countermeasures, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "process" => segment.set_object(&mut self.process, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "scan" => segment.set_object(&mut self.scan, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ProcessRemediationActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        for value in self.countermeasures.iter().flatten() {
+            value.collect_observables(&attr_path(path, "countermeasures"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.process {
+            value.collect_observables(&attr_path(path, "process"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        if let Some(value) = &self.scan {
+            value.collect_observables(&attr_path(path, "scan"), found);
+        }
+    }
+}
+#[doc = "Remediation Activity\n\nRemediation Activity events report on attempts at remediating a compromised device or computer network. It follows the MITRE countermeasures defined by the D3FEND™ <a target='_blank' href='https://d3fend.mitre.org/'>Matrix</a>.\n\n[UID:7001] Category: remediation | Name: remediation_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RemediationActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nMatches the MITRE D3FEND™ Tactic. Note: the Model and Detect Tactics are not supported as remediations by the OCSF Remediation event class.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<RemediationActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>Remediation</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Remediation Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Command UID\n\nThe unique identifier of the remediation command that pertains to this event.\n\nrequired"]
+    #[serde(rename = "command_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_uid: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Countermeasures\n\nThe MITRE D3FEND™ Matrix Countermeasures associated with a remediation.\n\nrecommended"]
+    #[serde(rename = "countermeasures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countermeasures: Option<Vec<D3fend>>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Remediation Guidance\n\nDescribes the recommended remediation steps to address identified issue(s).\n\noptional"]
+    #[serde(rename = "remediation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Box<Remediation>>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Scan\n\nThe remediation scan that pertains to this event.\n\noptional"]
+    #[serde(rename = "scan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<Box<Scan>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for RemediationActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_required(path, "command_uid", self.command_uid.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "countermeasures", self.countermeasures.is_some());
+        if let Some(items) = &self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        if let Some(value) = &self.remediation {
+            value.validate_at(&attr_path(path, "remediation"), report);
+        }
+        if let Some(value) = &self.scan {
+            value.validate_at(&attr_path(path, "scan"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for RemediationActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(7) => Some("Remediation"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(7001) => Some("Remediation Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(items) = &mut self.countermeasures {
+            let path = attr_path(path, "countermeasures");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        if let Some(value) = &mut self.remediation {
+            value.normalize_at(&attr_path(path, "remediation"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.scan {
+            value.normalize_at(&attr_path(path, "scan"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(700100) => Some("Remediation Activity: Unknown"),
+                Some(700101) => Some("Remediation Activity: Isolate"),
+                Some(700102) => Some("Remediation Activity: Evict"),
+                Some(700103) => Some("Remediation Activity: Restore"),
+                Some(700104) => Some("Remediation Activity: Harden"),
+                Some(700105) => Some("Remediation Activity: Detect"),
+                Some(700199) => Some("Remediation Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl RemediationActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for RemediationActivity {
+    const CLASS_UID: i64 = 7001;
+    const CATEGORY_UID: i64 = 7;
+    const CLASS_NAME: &'static str = "remediation_activity";
+    const CATEGORY_NAME: &'static str = "remediation";
+    const CAPTION: &'static str = "Remediation Activity";
+    const CATEGORY_CAPTION: &'static str = "Remediation";
+}
+impl RemediationActivity {
+    pub fn builder() -> RemediationActivityBuilder {
+        RemediationActivityBuilder {
+            inner: RemediationActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`RemediationActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RemediationActivityBuilder {
+    inner: RemediationActivity,
+}
+impl RemediationActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<RemediationActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Command UID (required)"]
+    pub fn command_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.command_uid = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Countermeasures (recommended)"]
+    pub fn countermeasures(mut self, value: Vec<D3fend>) -> Self {
+        self.inner.countermeasures = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Remediation Guidance (optional)"]
+    pub fn remediation(mut self, value: Remediation) -> Self {
+        self.inner.remediation = Some(Box::new(value));
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Scan (optional)"]
+    pub fn scan(mut self, value: Scan) -> Self {
+        self.inner.scan = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<RemediationActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "RemediationActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("command_uid", self.inner.command_uid.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> RemediationActivity {
+        self.inner
+    }
+}
+impl KnownFields for RemediationActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "countermeasures" => {
+                    find_unknown_in_array::<D3fend>(value, &attr_path(path, key), unknown)
+                }
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "remediation" => {
+                    Remediation::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "scan" => Scan::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "command_uid" | "confidence"
+                | "confidence_id" | "confidence_score" | "count" | "disposition"
+                | "disposition_id" | "duration" | "end_time" | "end_time_dt" | "is_alert"
+                | "message" | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for RemediationActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "command_uid" => segment.leaf(self.command_uid.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "countermeasures" => segment.object_in(self.countermeasures.as_deref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "remediation" => segment.object(self.remediation.as_deref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "scan" => segment.object(self.scan.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "command_uid" => segment.set_leaf(&mut self.command_uid, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "countermeasures" => segment.set_object_in(&mut self.countermeasures, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "remediation" => segment.set_object(&mut self.remediation, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "scan" => segment.set_object(&mut self.scan, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for RemediationActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        for value in self.countermeasures.iter().flatten() {
+            value.collect_observables(&attr_path(path, "countermeasures"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.remediation {
+            value.collect_observables(&attr_path(path, "remediation"), found);
+        }
+        if let Some(value) = &self.scan {
+            value.collect_observables(&attr_path(path, "scan"), found);
+        }
+    }
+}
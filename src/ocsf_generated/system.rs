@@ -0,0 +1,11454 @@
+#![doc = "Classes of the `system` category, enabled by the `system` cargo feature"]
+#![allow(deprecated)]
+#![allow(unused_imports)]
+#![allow(clippy::only_used_in_recursion)]
+use super::*;
+#[doc = "Event Log Activity\n\nEvent Log Activity events report actions pertaining to the system's event logging service(s), such as disabling logging or clearing the log data.\n\n[UID:1008] Category: system | Name: event_log_actvity\n\n**Constraints:**\n* at_least_one: `[log_file`,`log_name`,`log_provider`,`log_type`,`log_type_id]`\n"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct EventLogActvity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<EventLogActvityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity.\n\nrecommended"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Event Log Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nThe device that reported the event.\n\nrecommended (`host` profile)"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Destination Endpoint\n\nThe <p style='display:inline;color:red'>targeted</p> endpoint for the event log activity.\n\nrecommended"]
+    #[serde(rename = "dst_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "File\n\nThe file <p style='display:inline;color:red'>targeted by</p> the activity. Example: <code>/var/log/audit.log</code>\n\nrecommended"]
+    #[serde(rename = "file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<File>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Log File\n\nThe event log file the activity pertains to.\n\nrecommended"]
+    #[serde(rename = "log_file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<Box<File>>,
+    #[doc = "Log Name\n\nThe name of the event log <p style='display:inline;color:red'>targeted by</p> the activity. Example: Windows <code>Security</code>.\n\nrecommended"]
+    #[serde(rename = "log_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_name: Option<String>,
+    #[doc = "Log Provider\n\nThe logging provider or logging service <p style='display:inline;color:red'>targeted by</p> the activity.<br />Example: <code>Microsoft-Windows-Security-Auditing</code>, <code>Auditd</code>, or <code>Syslog</code>.\n\nrecommended"]
+    #[serde(rename = "log_provider")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_provider: Option<String>,
+    #[doc = "Log Type\n\nThe log type, normalized to the caption of the <code>log_type_id</code> value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "log_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_type: Option<String>,
+    #[doc = "Log Type ID\n\nThe normalized log type identifier.\n\nrecommended"]
+    #[serde(rename = "log_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_type_id: Option<i64>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Source Endpoint\n\nThe source endpoint for the event log activity.\n\nrecommended"]
+    #[serde(rename = "src_endpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_endpoint: Option<Box<NetworkEndpoint>>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br />Example: <code>0</code>, <code>8</code>, or <code>21</code> for <a target='_blank' href='https://learn.microsoft.com/en-us/previous-versions/windows/desktop/eventlogprov/cleareventlog-method-in-class-win32-nteventlogfile'>Windows ClearEventLog</a>.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event outcome.<br />Example: <code>Success</code>, <code>Privilege Missing</code>, or <code>Invalid Parameter</code> for <a target='_blank' href='https://learn.microsoft.com/en-us/previous-versions/windows/desktop/eventlogprov/cleareventlog-method-in-class-win32-nteventlogfile'>Windows ClearEventLog</a>.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for EventLogActvity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_recommended(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended_in_profile(path, "host", "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_recommended(path, "dst_endpoint", self.dst_endpoint.is_some());
+        if let Some(value) = &self.dst_endpoint {
+            value.validate_at(&attr_path(path, "dst_endpoint"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "file", self.file.is_some());
+        if let Some(value) = &self.file {
+            value.validate_at(&attr_path(path, "file"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        report.check_recommended(path, "log_file", self.log_file.is_some());
+        if let Some(value) = &self.log_file {
+            value.validate_at(&attr_path(path, "log_file"), report);
+        }
+        report.check_recommended(path, "log_name", self.log_name.is_some());
+        report.check_recommended(path, "log_provider", self.log_provider.is_some());
+        report.check_recommended(path, "log_type", self.log_type.is_some());
+        report.check_recommended(path, "log_type_id", self.log_type_id.is_some());
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "src_endpoint", self.src_endpoint.is_some());
+        if let Some(value) = &self.src_endpoint {
+            value.validate_at(&attr_path(path, "src_endpoint"), report);
+        }
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_constraint(
+            path,
+            ConstraintRule::AtLeastOne,
+            &[
+                ("log_file", self.log_file.is_some()),
+                ("log_name", self.log_name.is_some()),
+                ("log_provider", self.log_provider.is_some()),
+                ("log_type", self.log_type.is_some()),
+                ("log_type_id", self.log_type_id.is_some()),
+            ],
+        );
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for EventLogActvity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1008) => Some("Event Log Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.dst_endpoint {
+            value.normalize_at(&attr_path(path, "dst_endpoint"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.file {
+            value.normalize_at(&attr_path(path, "file"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.log_file {
+            value.normalize_at(&attr_path(path, "log_file"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        if let Some(value) = &mut self.src_endpoint {
+            value.normalize_at(&attr_path(path, "src_endpoint"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100800) => Some("Event Log Activity: Unknown"),
+                Some(100801) => Some("Event Log Activity: Clear"),
+                Some(100802) => Some("Event Log Activity: Delete"),
+                Some(100803) => Some("Event Log Activity: Export"),
+                Some(100804) => Some("Event Log Activity: Archive"),
+                Some(100805) => Some("Event Log Activity: Rotate"),
+                Some(100806) => Some("Event Log Activity: Start"),
+                Some(100807) => Some("Event Log Activity: Stop"),
+                Some(100808) => Some("Event Log Activity: Restart"),
+                Some(100809) => Some("Event Log Activity: Enable"),
+                Some(100810) => Some("Event Log Activity: Disable"),
+                Some(100899) => Some("Event Log Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl EventLogActvity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for EventLogActvity {
+    const CLASS_UID: i64 = 1008;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "event_log_actvity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Event Log Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl EventLogActvity {
+    pub fn builder() -> EventLogActvityBuilder {
+        EventLogActvityBuilder {
+            inner: EventLogActvity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`EventLogActvity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EventLogActvityBuilder {
+    inner: EventLogActvity,
+}
+impl EventLogActvityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<EventLogActvityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (recommended)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (recommended (`host` profile))"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Destination Endpoint (recommended)"]
+    pub fn dst_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.dst_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "File (recommended)"]
+    pub fn file(mut self, value: File) -> Self {
+        self.inner.file = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Log File (recommended)"]
+    pub fn log_file(mut self, value: File) -> Self {
+        self.inner.log_file = Some(Box::new(value));
+        self
+    }
+    #[doc = "Log Name (recommended)"]
+    pub fn log_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.log_name = Some(value.into());
+        self
+    }
+    #[doc = "Log Provider (recommended)"]
+    pub fn log_provider(mut self, value: impl Into<String>) -> Self {
+        self.inner.log_provider = Some(value.into());
+        self
+    }
+    #[doc = "Log Type (recommended)"]
+    pub fn log_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.log_type = Some(value.into());
+        self
+    }
+    #[doc = "Log Type ID (recommended)"]
+    pub fn log_type_id(mut self, value: i64) -> Self {
+        self.inner.log_type_id = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Source Endpoint (recommended)"]
+    pub fn src_endpoint(mut self, value: NetworkEndpoint) -> Self {
+        self.inner.src_endpoint = Some(Box::new(value));
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<EventLogActvity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "EventLogActvity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> EventLogActvity {
+        self.inner
+    }
+}
+impl KnownFields for EventLogActvity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "dst_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "log_file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "src_endpoint" => {
+                    NetworkEndpoint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "log_name" | "log_provider"
+                | "log_type" | "log_type_id" | "message" | "raw_data" | "raw_data_size"
+                | "risk_details" | "risk_level" | "risk_level_id" | "risk_score" | "severity"
+                | "severity_id" | "start_time" | "start_time_dt" | "status" | "status_code"
+                | "status_detail" | "status_id" | "time" | "time_dt" | "timezone_offset"
+                | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for EventLogActvity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "dst_endpoint" => segment.object(self.dst_endpoint.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "file" => segment.object(self.file.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "log_file" => segment.object(self.log_file.as_deref()),
+            "log_name" => segment.leaf(self.log_name.as_ref()),
+            "log_provider" => segment.leaf(self.log_provider.as_ref()),
+            "log_type" => segment.leaf(self.log_type.as_ref()),
+            "log_type_id" => segment.leaf(self.log_type_id.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "src_endpoint" => segment.object(self.src_endpoint.as_deref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "dst_endpoint" => segment.set_object(&mut self.dst_endpoint, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "file" => segment.set_object(&mut self.file, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "log_file" => segment.set_object(&mut self.log_file, value),
+            "log_name" => segment.set_leaf(&mut self.log_name, value),
+            "log_provider" => segment.set_leaf(&mut self.log_provider, value),
+            "log_type" => segment.set_leaf(&mut self.log_type, value),
+            "log_type_id" => segment.set_leaf(&mut self.log_type_id, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "src_endpoint" => segment.set_object(&mut self.src_endpoint, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for EventLogActvity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.dst_endpoint {
+            value.collect_observables(&attr_path(path, "dst_endpoint"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.file {
+            value.collect_observables(&attr_path(path, "file"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.log_file {
+            value.collect_observables(&attr_path(path, "log_file"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.src_endpoint {
+            value.collect_observables(&attr_path(path, "src_endpoint"), found);
+        }
+    }
+}
+#[doc = "File System Activity\n\nFile System Activity events report when a process performs an action on a file or folder.\n\n[UID:1001] Category: system | Name: file_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct FileActivity {
+    #[doc = "Access Mask\n\nThe access mask in a platform-native format.\n\noptional"]
+    #[serde(rename = "access_mask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_mask: Option<i64>,
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<FileActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity on the <code>file</code> object\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>File System Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Component\n\n<p>The name or relative pathname of a sub-component of the data object, if applicable. </p>For example: <code>attachment.doc</code>, <code>attachment.zip/bad.doc</code>, or <code>part.mime/part.cab/part.uue/part.doc</code>.\n\nrecommended"]
+    #[serde(rename = "component")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Connection Identifier\n\nThe network connection identifier.\n\noptional"]
+    #[serde(rename = "connection_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_uid: Option<String>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Create Mask\n\nThe original Windows mask that is required to create the object.\n\nrecommended"]
+    #[serde(rename = "create_mask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_mask: Option<String>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "File\n\nThe file that is the target of the activity.\n\nrequired"]
+    #[serde(rename = "file")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<File>>,
+    #[doc = "File Diff\n\nFile content differences used for change detection. For example, a common use case is to identify itemized changes within INI or configuration/property setting values.\n\nrecommended"]
+    #[serde(rename = "file_diff")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_diff: Option<String>,
+    #[doc = "File Result\n\nThe resulting file object when the activity was allowed and successful.\n\nrecommended"]
+    #[serde(rename = "file_result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_result: Option<Box<File>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for FileActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended(path, "component", self.component.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_recommended(path, "create_mask", self.create_mask.is_some());
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "file", self.file.is_some());
+        if let Some(value) = &self.file {
+            value.validate_at(&attr_path(path, "file"), report);
+        }
+        report.check_recommended(path, "file_diff", self.file_diff.is_some());
+        report.check_recommended(path, "file_result", self.file_result.is_some());
+        if let Some(value) = &self.file_result {
+            value.validate_at(&attr_path(path, "file_result"), report);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for FileActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1001) => Some("File System Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.file {
+            value.normalize_at(&attr_path(path, "file"), conflicts);
+        }
+        if let Some(value) = &mut self.file_result {
+            value.normalize_at(&attr_path(path, "file_result"), conflicts);
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100100) => Some("File System Activity: Unknown"),
+                Some(100101) => Some("File System Activity: Create"),
+                Some(100102) => Some("File System Activity: Read"),
+                Some(100103) => Some("File System Activity: Update"),
+                Some(100104) => Some("File System Activity: Delete"),
+                Some(100105) => Some("File System Activity: Rename"),
+                Some(100106) => Some("File System Activity: Set Attributes"),
+                Some(100107) => Some("File System Activity: Set Security"),
+                Some(100108) => Some("File System Activity: Get Attributes"),
+                Some(100109) => Some("File System Activity: Get Security"),
+                Some(100110) => Some("File System Activity: Encrypt"),
+                Some(100111) => Some("File System Activity: Decrypt"),
+                Some(100112) => Some("File System Activity: Mount"),
+                Some(100113) => Some("File System Activity: Unmount"),
+                Some(100114) => Some("File System Activity: Open"),
+                Some(100199) => Some("File System Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl FileActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for FileActivity {
+    const CLASS_UID: i64 = 1001;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "file_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "File System Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl FileActivity {
+    pub fn builder() -> FileActivityBuilder {
+        FileActivityBuilder {
+            inner: FileActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`FileActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileActivityBuilder {
+    inner: FileActivity,
+}
+impl FileActivityBuilder {
+    #[doc = "Access Mask (optional)"]
+    pub fn access_mask(mut self, value: i64) -> Self {
+        self.inner.access_mask = Some(value);
+        self
+    }
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<FileActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Component (recommended)"]
+    pub fn component(mut self, value: impl Into<String>) -> Self {
+        self.inner.component = Some(value.into());
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Connection Identifier (optional)"]
+    pub fn connection_uid(mut self, value: impl Into<String>) -> Self {
+        self.inner.connection_uid = Some(value.into());
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Create Mask (recommended)"]
+    pub fn create_mask(mut self, value: impl Into<String>) -> Self {
+        self.inner.create_mask = Some(value.into());
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "File (required)"]
+    pub fn file(mut self, value: File) -> Self {
+        self.inner.file = Some(Box::new(value));
+        self
+    }
+    #[doc = "File Diff (recommended)"]
+    pub fn file_diff(mut self, value: impl Into<String>) -> Self {
+        self.inner.file_diff = Some(value.into());
+        self
+    }
+    #[doc = "File Result (recommended)"]
+    pub fn file_result(mut self, value: File) -> Self {
+        self.inner.file_result = Some(Box::new(value));
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<FileActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "FileActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("file", self.inner.file.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> FileActivity {
+        self.inner
+    }
+}
+impl KnownFields for FileActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "file" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "file_result" => File::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "access_mask" | "action" | "action_id" | "activity_id" | "activity_name"
+                | "category_name" | "category_uid" | "class_name" | "class_uid" | "component"
+                | "confidence" | "confidence_id" | "confidence_score" | "connection_uid"
+                | "count" | "create_mask" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "file_diff" | "is_alert" | "message"
+                | "raw_data" | "raw_data_size" | "risk_details" | "risk_level"
+                | "risk_level_id" | "risk_score" | "severity" | "severity_id" | "start_time"
+                | "start_time_dt" | "status" | "status_code" | "status_detail" | "status_id"
+                | "time" | "time_dt" | "timezone_offset" | "type_name" | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for FileActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "access_mask" => segment.leaf(self.access_mask.as_ref()),
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "component" => segment.leaf(self.component.as_ref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "connection_uid" => segment.leaf(self.connection_uid.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "create_mask" => segment.leaf(self.create_mask.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "file" => segment.object(self.file.as_deref()),
+            "file_diff" => segment.leaf(self.file_diff.as_ref()),
+            "file_result" => segment.object(self.file_result.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "access_mask" => segment.set_leaf(&mut self.access_mask, value),
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "component" => segment.set_leaf(&mut self.component, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "connection_uid" => segment.set_leaf(&mut self.connection_uid, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "create_mask" => segment.set_leaf(&mut self.create_mask, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "file" => segment.set_object(&mut self.file, value),
+            "file_diff" => segment.set_leaf(&mut self.file_diff, value),
+            "file_result" => segment.set_object(&mut self.file_result, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for FileActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.file {
+            value.collect_observables(&attr_path(path, "file"), found);
+        }
+        if let Some(value) = &self.file_result {
+            value.collect_observables(&attr_path(path, "file_result"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Kernel Activity\n\nKernel Activity events report when an process creates, reads, or deletes a kernel resource.\n\n[UID:1003] Category: system | Name: kernel_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct KernelActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<KernelActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Kernel Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Kernel\n\nThe target kernel resource.\n\nrequired"]
+    #[serde(rename = "kernel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<Box<Kernel>>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for KernelActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        report.check_required(path, "kernel", self.kernel.is_some());
+        if let Some(value) = &self.kernel {
+            value.validate_at(&attr_path(path, "kernel"), report);
+        }
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for KernelActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1003) => Some("Kernel Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.kernel {
+            value.normalize_at(&attr_path(path, "kernel"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100300) => Some("Kernel Activity: Unknown"),
+                Some(100301) => Some("Kernel Activity: Create"),
+                Some(100302) => Some("Kernel Activity: Read"),
+                Some(100303) => Some("Kernel Activity: Delete"),
+                Some(100304) => Some("Kernel Activity: Invoke"),
+                Some(100399) => Some("Kernel Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl KernelActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for KernelActivity {
+    const CLASS_UID: i64 = 1003;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "kernel_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Kernel Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl KernelActivity {
+    pub fn builder() -> KernelActivityBuilder {
+        KernelActivityBuilder {
+            inner: KernelActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`KernelActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct KernelActivityBuilder {
+    inner: KernelActivity,
+}
+impl KernelActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<KernelActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Kernel (required)"]
+    pub fn kernel(mut self, value: Kernel) -> Self {
+        self.inner.kernel = Some(Box::new(value));
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<KernelActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "KernelActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("kernel", self.inner.kernel.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> KernelActivity {
+        self.inner
+    }
+}
+impl KnownFields for KernelActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "kernel" => Kernel::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for KernelActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "kernel" => segment.object(self.kernel.as_deref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "kernel" => segment.set_object(&mut self.kernel, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for KernelActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.kernel {
+            value.collect_observables(&attr_path(path, "kernel"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Kernel Extension Activity\n\nKernel Extension events report when a driver/extension is loaded or unloaded into the kernel\n\n[UID:1002] Category: system | Name: kernel_extension_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct KernelExtensionActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<KernelExtensionActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor process that loaded or unloaded the driver/extension.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Kernel Extension Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Kernel Driver\n\nThe driver that was loaded/unloaded into the kernel\n\nrequired"]
+    #[serde(rename = "driver")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<Box<KernelDriver>>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for KernelExtensionActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_required(path, "driver", self.driver.is_some());
+        if let Some(value) = &self.driver {
+            value.validate_at(&attr_path(path, "driver"), report);
+        }
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for KernelExtensionActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1002) => Some("Kernel Extension Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(value) = &mut self.driver {
+            value.normalize_at(&attr_path(path, "driver"), conflicts);
+        }
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100200) => Some("Kernel Extension Activity: Unknown"),
+                Some(100201) => Some("Kernel Extension Activity: Load"),
+                Some(100202) => Some("Kernel Extension Activity: Unload"),
+                Some(100299) => Some("Kernel Extension Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl KernelExtensionActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for KernelExtensionActivity {
+    const CLASS_UID: i64 = 1002;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "kernel_extension_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Kernel Extension Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl KernelExtensionActivity {
+    pub fn builder() -> KernelExtensionActivityBuilder {
+        KernelExtensionActivityBuilder {
+            inner: KernelExtensionActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`KernelExtensionActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct KernelExtensionActivityBuilder {
+    inner: KernelExtensionActivity,
+}
+impl KernelExtensionActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<KernelExtensionActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Kernel Driver (required)"]
+    pub fn driver(mut self, value: KernelDriver) -> Self {
+        self.inner.driver = Some(Box::new(value));
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<KernelExtensionActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "KernelExtensionActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("driver", self.inner.driver.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> KernelExtensionActivity {
+        self.inner
+    }
+}
+impl KnownFields for KernelExtensionActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "driver" => {
+                    KernelDriver::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for KernelExtensionActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "driver" => segment.object(self.driver.as_deref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "driver" => segment.set_object(&mut self.driver, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for KernelExtensionActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        if let Some(value) = &self.driver {
+            value.collect_observables(&attr_path(path, "driver"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Memory Activity\n\nMemory Activity events report when a process has memory allocated, read/modified, or other manipulation activities - such as a buffer overflow or turning off data execution protection (DEP).\n\n[UID:1004] Category: system | Name: memory_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct MemoryActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<MemoryActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "Actual Permissions\n\nThe permissions that were granted to access memory.\n\nrecommended"]
+    #[serde(rename = "actual_permissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_permissions: Option<i64>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Base Address\n\nThe memory address that was access or requested.\n\nrecommended"]
+    #[serde(rename = "base_address")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_address: Option<String>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Memory Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Process\n\nThe process that had memory allocated, read/written, or had other manipulation activities performed on it.\n\nrequired"]
+    #[serde(rename = "process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<Box<Process>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Requested Permissions\n\nThe permissions mask that was requested to access memory.\n\nrecommended"]
+    #[serde(rename = "requested_permissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_permissions: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Size\n\nThe memory size that was access or requested.\n\nrecommended"]
+    #[serde(rename = "size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for MemoryActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        report.check_recommended(
+            path,
+            "actual_permissions",
+            self.actual_permissions.is_some(),
+        );
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "base_address", self.base_address.is_some());
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_required(path, "process", self.process.is_some());
+        if let Some(value) = &self.process {
+            value.validate_at(&attr_path(path, "process"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(
+            path,
+            "requested_permissions",
+            self.requested_permissions.is_some(),
+        );
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_recommended(path, "size", self.size.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for MemoryActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1004) => Some("Memory Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.process {
+            value.normalize_at(&attr_path(path, "process"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100400) => Some("Memory Activity: Unknown"),
+                Some(100401) => Some("Memory Activity: Allocate Page"),
+                Some(100402) => Some("Memory Activity: Modify Page"),
+                Some(100403) => Some("Memory Activity: Delete Page"),
+                Some(100404) => Some("Memory Activity: Buffer Overflow"),
+                Some(100405) => Some("Memory Activity: Disable DEP"),
+                Some(100406) => Some("Memory Activity: Enable DEP"),
+                Some(100407) => Some("Memory Activity: Read"),
+                Some(100408) => Some("Memory Activity: Write"),
+                Some(100409) => Some("Memory Activity: Map View"),
+                Some(100499) => Some("Memory Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl MemoryActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for MemoryActivity {
+    const CLASS_UID: i64 = 1004;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "memory_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Memory Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl MemoryActivity {
+    pub fn builder() -> MemoryActivityBuilder {
+        MemoryActivityBuilder {
+            inner: MemoryActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`MemoryActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemoryActivityBuilder {
+    inner: MemoryActivity,
+}
+impl MemoryActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<MemoryActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "Actual Permissions (recommended)"]
+    pub fn actual_permissions(mut self, value: i64) -> Self {
+        self.inner.actual_permissions = Some(value);
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Base Address (recommended)"]
+    pub fn base_address(mut self, value: impl Into<String>) -> Self {
+        self.inner.base_address = Some(value.into());
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Process (required)"]
+    pub fn process(mut self, value: Process) -> Self {
+        self.inner.process = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Requested Permissions (recommended)"]
+    pub fn requested_permissions(mut self, value: i64) -> Self {
+        self.inner.requested_permissions = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Size (recommended)"]
+    pub fn size(mut self, value: i64) -> Self {
+        self.inner.size = Some(value);
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<MemoryActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "MemoryActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("process", self.inner.process.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> MemoryActivity {
+        self.inner
+    }
+}
+impl KnownFields for MemoryActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "process" => Process::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "actual_permissions"
+                | "base_address"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "is_alert"
+                | "message"
+                | "raw_data"
+                | "raw_data_size"
+                | "requested_permissions"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "size"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for MemoryActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "actual_permissions" => segment.leaf(self.actual_permissions.as_ref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "base_address" => segment.leaf(self.base_address.as_ref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "process" => segment.object(self.process.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "requested_permissions" => segment.leaf(self.requested_permissions.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "size" => segment.leaf(self.size.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "actual_permissions" => segment.set_leaf(&mut self.actual_permissions, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "base_address" => segment.set_leaf(&mut self.base_address, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "process" => segment.set_object(&mut self.process, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "requested_permissions" => segment.set_leaf(&mut self.requested_permissions, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "size" => segment.set_leaf(&mut self.size, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for MemoryActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.process {
+            value.collect_observables(&attr_path(path, "process"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Module Activity\n\nModule Activity events report when an endpoint process acts on a <code>module</code>.\n\n[UID:1005] Category: system | Name: module_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ModuleActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ModuleActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity on the target <code>module</code>. For example, the process that loaded a module into memory.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Module Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Module\n\nThe module that was loaded, unloaded, or invoked.\n\nrequired"]
+    #[serde(rename = "module")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module: Option<Box<Module>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ModuleActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_required(path, "module", self.module.is_some());
+        if let Some(value) = &self.module {
+            value.validate_at(&attr_path(path, "module"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ModuleActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1005) => Some("Module Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(value) = &mut self.module {
+            value.normalize_at(&attr_path(path, "module"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100500) => Some("Module Activity: Unknown"),
+                Some(100501) => Some("Module Activity: Load"),
+                Some(100502) => Some("Module Activity: Unload"),
+                Some(100599) => Some("Module Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ModuleActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ModuleActivity {
+    const CLASS_UID: i64 = 1005;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "module_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Module Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl ModuleActivity {
+    pub fn builder() -> ModuleActivityBuilder {
+        ModuleActivityBuilder {
+            inner: ModuleActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ModuleActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModuleActivityBuilder {
+    inner: ModuleActivity,
+}
+impl ModuleActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ModuleActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Module (required)"]
+    pub fn module(mut self, value: Module) -> Self {
+        self.inner.module = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ModuleActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ModuleActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("module", self.inner.module.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ModuleActivity {
+        self.inner
+    }
+}
+impl KnownFields for ModuleActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "module" => Module::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ModuleActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "module" => segment.object(self.module.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "module" => segment.set_object(&mut self.module, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ModuleActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        if let Some(value) = &self.module {
+            value.collect_observables(&attr_path(path, "module"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Peripheral Activity\n\nPeripheral Activity events log a system's interactions with external, connectable, and detachable hardware. These events provide visibility into the external devices connected to and used by a system.\n\n[UID:1010] Category: system | Name: peripheral_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct PeripheralActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<PeripheralActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Peripheral Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Peripheral Device\n\nThe peripheral device that is the subject of the activity.\n\nrequired"]
+    #[serde(rename = "peripheral_device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peripheral_device: Option<Box<PeripheralDevice>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for PeripheralActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "peripheral_device", self.peripheral_device.is_some());
+        if let Some(value) = &self.peripheral_device {
+            value.validate_at(&attr_path(path, "peripheral_device"), report);
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for PeripheralActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1010) => Some("Peripheral Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.peripheral_device {
+            value.normalize_at(&attr_path(path, "peripheral_device"), conflicts);
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(101000) => Some("Peripheral Activity: Unknown"),
+                Some(101001) => Some("Peripheral Activity: Connect"),
+                Some(101002) => Some("Peripheral Activity: Disconnect"),
+                Some(101099) => Some("Peripheral Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl PeripheralActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for PeripheralActivity {
+    const CLASS_UID: i64 = 1010;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "peripheral_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Peripheral Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl PeripheralActivity {
+    pub fn builder() -> PeripheralActivityBuilder {
+        PeripheralActivityBuilder {
+            inner: PeripheralActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`PeripheralActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PeripheralActivityBuilder {
+    inner: PeripheralActivity,
+}
+impl PeripheralActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<PeripheralActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Peripheral Device (required)"]
+    pub fn peripheral_device(mut self, value: PeripheralDevice) -> Self {
+        self.inner.peripheral_device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<PeripheralActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "PeripheralActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("peripheral_device", self.inner.peripheral_device.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> PeripheralActivity {
+        self.inner
+    }
+}
+impl KnownFields for PeripheralActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "peripheral_device" => {
+                    PeripheralDevice::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for PeripheralActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "peripheral_device" => segment.object(self.peripheral_device.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "peripheral_device" => segment.set_object(&mut self.peripheral_device, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for PeripheralActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.peripheral_device {
+            value.collect_observables(&attr_path(path, "peripheral_device"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Process Activity\n\nProcess Activity events report when a process launches, injects, opens or terminates another process, successful or otherwise.\n\n[UID:1007] Category: system | Name: process_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ProcessActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ProcessActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity on the target <code>process</code>. For example, the process that started a new process or injected code into another process.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "Actual Permissions\n\nThe permissions that were granted to the process in a platform-native format.\n\nrecommended"]
+    #[serde(rename = "actual_permissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_permissions: Option<i64>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Process Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Exit Code\n\nThe exit code reported by a process when it terminates. The convention is that zero indicates success and any non-zero exit code indicates that some error occurred.\n\nrecommended"]
+    #[serde(rename = "exit_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Injection Type\n\nThe process injection method, normalized to the caption of the injection_type_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "injection_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injection_type: Option<String>,
+    #[doc = "Injection Type ID\n\nThe normalized identifier of the process injection method.\n\nrecommended"]
+    #[serde(rename = "injection_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injection_type_id: Option<i64>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Launch Type\n\nThe specific type of <code>Launch</code> activity, normalized to the caption of the <code>launch_type_id</code> value. In the case of <code>Other</code> it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "launch_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_type: Option<String>,
+    #[doc = "Launch Type ID\n\nThe normalized identifier for the specific type of <code>Launch</code> activity.\n\nrecommended"]
+    #[serde(rename = "launch_type_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_type_id: Option<i64>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Module\n\nThe module that was injected by the actor process.\n\nrecommended"]
+    #[serde(rename = "module")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module: Option<Box<Module>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Process\n\nThe process that was launched, injected into, opened, or terminated.\n\nrequired"]
+    #[serde(rename = "process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<Box<Process>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Requested Permissions\n\nThe permissions mask that was requested by the process.\n\nrecommended"]
+    #[serde(rename = "requested_permissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_permissions: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ProcessActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        report.check_recommended(
+            path,
+            "actual_permissions",
+            self.actual_permissions.is_some(),
+        );
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_recommended(path, "exit_code", self.exit_code.is_some());
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended(path, "injection_type", self.injection_type.is_some());
+        report.check_recommended(path, "injection_type_id", self.injection_type_id.is_some());
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        report.check_recommended(path, "launch_type", self.launch_type.is_some());
+        report.check_recommended(path, "launch_type_id", self.launch_type_id.is_some());
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "module", self.module.is_some());
+        if let Some(value) = &self.module {
+            value.validate_at(&attr_path(path, "module"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        report.check_required(path, "process", self.process.is_some());
+        if let Some(value) = &self.process {
+            value.validate_at(&attr_path(path, "process"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_recommended(
+            path,
+            "requested_permissions",
+            self.requested_permissions.is_some(),
+        );
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ProcessActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1007) => Some("Process Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(value) = &mut self.module {
+            value.normalize_at(&attr_path(path, "module"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.process {
+            value.normalize_at(&attr_path(path, "process"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100700) => Some("Process Activity: Unknown"),
+                Some(100701) => Some("Process Activity: Launch"),
+                Some(100702) => Some("Process Activity: Terminate"),
+                Some(100703) => Some("Process Activity: Open"),
+                Some(100704) => Some("Process Activity: Inject"),
+                Some(100705) => Some("Process Activity: Set User ID"),
+                Some(100799) => Some("Process Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ProcessActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ProcessActivity {
+    const CLASS_UID: i64 = 1007;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "process_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Process Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl ProcessActivity {
+    pub fn builder() -> ProcessActivityBuilder {
+        ProcessActivityBuilder {
+            inner: ProcessActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ProcessActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProcessActivityBuilder {
+    inner: ProcessActivity,
+}
+impl ProcessActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ProcessActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "Actual Permissions (recommended)"]
+    pub fn actual_permissions(mut self, value: i64) -> Self {
+        self.inner.actual_permissions = Some(value);
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Exit Code (recommended)"]
+    pub fn exit_code(mut self, value: i64) -> Self {
+        self.inner.exit_code = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Injection Type (recommended)"]
+    pub fn injection_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.injection_type = Some(value.into());
+        self
+    }
+    #[doc = "Injection Type ID (recommended)"]
+    pub fn injection_type_id(mut self, value: i64) -> Self {
+        self.inner.injection_type_id = Some(value);
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Launch Type (recommended)"]
+    pub fn launch_type(mut self, value: impl Into<String>) -> Self {
+        self.inner.launch_type = Some(value.into());
+        self
+    }
+    #[doc = "Launch Type ID (recommended)"]
+    pub fn launch_type_id(mut self, value: i64) -> Self {
+        self.inner.launch_type_id = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Module (recommended)"]
+    pub fn module(mut self, value: Module) -> Self {
+        self.inner.module = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Process (required)"]
+    pub fn process(mut self, value: Process) -> Self {
+        self.inner.process = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Requested Permissions (recommended)"]
+    pub fn requested_permissions(mut self, value: i64) -> Self {
+        self.inner.requested_permissions = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ProcessActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ProcessActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("process", self.inner.process.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ProcessActivity {
+        self.inner
+    }
+}
+impl KnownFields for ProcessActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "module" => Module::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "process" => Process::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action"
+                | "action_id"
+                | "activity_id"
+                | "activity_name"
+                | "actual_permissions"
+                | "category_name"
+                | "category_uid"
+                | "class_name"
+                | "class_uid"
+                | "confidence"
+                | "confidence_id"
+                | "confidence_score"
+                | "count"
+                | "disposition"
+                | "disposition_id"
+                | "duration"
+                | "end_time"
+                | "end_time_dt"
+                | "exit_code"
+                | "injection_type"
+                | "injection_type_id"
+                | "is_alert"
+                | "launch_type"
+                | "launch_type_id"
+                | "message"
+                | "raw_data"
+                | "raw_data_size"
+                | "requested_permissions"
+                | "risk_details"
+                | "risk_level"
+                | "risk_level_id"
+                | "risk_score"
+                | "severity"
+                | "severity_id"
+                | "start_time"
+                | "start_time_dt"
+                | "status"
+                | "status_code"
+                | "status_detail"
+                | "status_id"
+                | "time"
+                | "time_dt"
+                | "timezone_offset"
+                | "type_name"
+                | "type_uid"
+                | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ProcessActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "actual_permissions" => segment.leaf(self.actual_permissions.as_ref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "exit_code" => segment.leaf(self.exit_code.as_ref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "injection_type" => segment.leaf(self.injection_type.as_ref()),
+            "injection_type_id" => segment.leaf(self.injection_type_id.as_ref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "launch_type" => segment.leaf(self.launch_type.as_ref()),
+            "launch_type_id" => segment.leaf(self.launch_type_id.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "module" => segment.object(self.module.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "process" => segment.object(self.process.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "requested_permissions" => segment.leaf(self.requested_permissions.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "actual_permissions" => segment.set_leaf(&mut self.actual_permissions, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "exit_code" => segment.set_leaf(&mut self.exit_code, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "injection_type" => segment.set_leaf(&mut self.injection_type, value),
+            "injection_type_id" => segment.set_leaf(&mut self.injection_type_id, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "launch_type" => segment.set_leaf(&mut self.launch_type, value),
+            "launch_type_id" => segment.set_leaf(&mut self.launch_type_id, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "module" => segment.set_object(&mut self.module, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "process" => segment.set_object(&mut self.process, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "requested_permissions" => segment.set_leaf(&mut self.requested_permissions, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ProcessActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        if let Some(value) = &self.module {
+            value.collect_observables(&attr_path(path, "module"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.process {
+            value.collect_observables(&attr_path(path, "process"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Scheduled Job Activity\n\nScheduled Job Activity events report activities related to scheduled jobs or tasks.\n\n[UID:1006] Category: system | Name: scheduled_job_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ScheduledJobActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ScheduledJobActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor that performed the activity on the <code>job</code> object.\n\noptional (`host` profile)"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Scheduled Job Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Job\n\nThe job object that pertains to the event.\n\nrequired"]
+    #[serde(rename = "job")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job: Option<Box<Job>>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ScheduledJobActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        report.check_required(path, "job", self.job.is_some());
+        if let Some(value) = &self.job {
+            value.validate_at(&attr_path(path, "job"), report);
+        }
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ScheduledJobActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1006) => Some("Scheduled Job Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(value) = &mut self.job {
+            value.normalize_at(&attr_path(path, "job"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100600) => Some("Scheduled Job Activity: Unknown"),
+                Some(100601) => Some("Scheduled Job Activity: Create"),
+                Some(100602) => Some("Scheduled Job Activity: Update"),
+                Some(100603) => Some("Scheduled Job Activity: Delete"),
+                Some(100604) => Some("Scheduled Job Activity: Enable"),
+                Some(100605) => Some("Scheduled Job Activity: Disable"),
+                Some(100606) => Some("Scheduled Job Activity: Start"),
+                Some(100699) => Some("Scheduled Job Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ScheduledJobActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ScheduledJobActivity {
+    const CLASS_UID: i64 = 1006;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "scheduled_job_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Scheduled Job Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl ScheduledJobActivity {
+    pub fn builder() -> ScheduledJobActivityBuilder {
+        ScheduledJobActivityBuilder {
+            inner: ScheduledJobActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ScheduledJobActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScheduledJobActivityBuilder {
+    inner: ScheduledJobActivity,
+}
+impl ScheduledJobActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ScheduledJobActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (optional (`host` profile))"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Job (required)"]
+    pub fn job(mut self, value: Job) -> Self {
+        self.inner.job = Some(Box::new(value));
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ScheduledJobActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ScheduledJobActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("job", self.inner.job.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ScheduledJobActivity {
+        self.inner
+    }
+}
+impl KnownFields for ScheduledJobActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "job" => Job::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ScheduledJobActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "job" => segment.object(self.job.as_deref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "job" => segment.set_object(&mut self.job, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ScheduledJobActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        if let Some(value) = &self.job {
+            value.collect_observables(&attr_path(path, "job"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+    }
+}
+#[doc = "Script Activity\n\nScript Activity events report when a process executes a script.\n\n[UID:1009] Category: system | Name: script_activity"]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ScriptActivity {
+    #[doc = "Action\n\nThe normalized caption of <code>action_id</code>.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[doc = "Action ID\n\nThe action taken by a control or other policy-based system leading to an outcome or disposition. An unknown action may still correspond to a known disposition. Refer to <code>disposition_id</code> for the outcome of the action.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "action_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<ActionId>,
+    #[doc = "Activity ID\n\nThe normalized identifier of the activity that triggered the event.\n\nrequired"]
+    #[serde(rename = "activity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<ScriptActivityActivityId>,
+    #[doc = "Activity\n\nThe event activity name, as defined by the activity_id.\n\noptional"]
+    #[serde(rename = "activity_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    #[doc = "Actor\n\nThe actor object describes details about the user/role/process that was the source of the activity. Note that this is not the threat actor of a campaign but may be part of a campaign.\n\nrequired"]
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<Actor>>,
+    #[doc = "API Details\n\nDescribes details about a typical API (Application Programming Interface) call.\n\noptional (`cloud` profile)"]
+    #[serde(rename = "api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Box<Api>>,
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details\n\nAn array of MITRE ATT&CK® objects describing identified tactics, techniques & sub-techniques. The objects are compatible with MITRE ATLAS™ tactics, techniques & sub-techniques.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "attacks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacks: Option<Vec<Attack>>,
+    #[doc = "Authorization Information\n\nProvides details about an authorization, such as authorization outcome, and any associated policies related to the activity/event.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "authorizations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    #[doc = "Category\n\nThe event category name, as defined by category_uid value: <code>System Activity</code>.\n\noptional"]
+    #[serde(rename = "category_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[doc = "Category ID\n\nThe category unique identifier of the event.\n\nrequired"]
+    #[serde(rename = "category_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_uid: Option<i64>,
+    #[doc = "Class\n\nThe event class name, as defined by class_uid value: <code>Script Activity</code>.\n\noptional"]
+    #[serde(rename = "class_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[doc = "Class ID\n\nThe unique identifier of a class. A class describes the attributes available in an event.\n\nrequired"]
+    #[serde(rename = "class_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_uid: Option<i64>,
+    #[doc = "Cloud\n\nDescribes details about the Cloud environment where the event or finding was created.\n\nrequired (`cloud` profile)"]
+    #[serde(rename = "cloud")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<Box<Cloud>>,
+    #[doc = "Confidence\n\nThe confidence, normalized to the caption of the confidence_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "confidence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[doc = "Confidence ID\n\nThe normalized confidence refers to the accuracy of the rule that created the finding. A rule with a low confidence means that the finding scope is wide and may create finding reports that may not be malicious in nature.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "confidence_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_id: Option<ConfidenceId>,
+    #[doc = "Confidence Score\n\nThe confidence score as reported by the event source.\n\noptional"]
+    #[serde(rename = "confidence_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<i64>,
+    #[doc = "Count\n\nThe number of times that events in the same logical group occurred during the event <strong>Start Time</strong> to <strong>End Time</strong> period.\n\noptional"]
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[doc = "Device\n\nAn addressable device, computer system or host.\n\nrequired"]
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Box<Device>>,
+    #[doc = "Disposition\n\nThe disposition name, normalized to the caption of the disposition_id value. In the case of 'Other', it is defined by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "disposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[doc = "Disposition ID\n\nDescribes the outcome or action taken by a security control, such as access control checks, malware detections or various types of policy violations.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "disposition_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition_id: Option<DispositionId>,
+    #[doc = "Duration Milliseconds\n\nThe event duration or aggregate time, the amount of time the event covers from <code>start_time</code> to <code>end_time</code> in milliseconds.\n\noptional"]
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "end_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    #[doc = "End Time\n\nThe end time of a time period, or the time of the most recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "end_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time_dt: Option<DateTime>,
+    #[doc = "Enrichments\n\nThe additional information from an external data source, which is associated with the event or a finding. For example add location information for the IP address in the DNS answers:</p><code>[{\"name\": \"answers.ip\", \"value\": \"92.24.47.250\", \"type\": \"location\", \"data\": {\"city\": \"Socotra\", \"continent\": \"Asia\", \"coordinates\": [-25.4153, 17.0743], \"country\": \"YE\", \"desc\": \"Yemen\"}}]</code>\n\noptional"]
+    #[serde(rename = "enrichments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<Enrichment>>,
+    #[doc = "Firewall Rule\n\nThe firewall rule that pertains to the control that triggered the event, if applicable.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "firewall_rule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firewall_rule: Option<Box<FirewallRule>>,
+    #[doc = "Alert\n\nIndicates that the event is considered to be an alertable signal. Should be set to <code>true</code> if <code>disposition_id = Alert</code> among other dispositions, and/or <code>risk_level_id</code> or <code>severity_id</code> of the event is elevated. Not all control events will be alertable, for example if <code>disposition_id = Exonerated</code> or <code>disposition_id = Allowed</code>.\n\nrecommended (`security_control` profile)"]
+    #[serde(rename = "is_alert")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_alert: Option<bool>,
+    #[doc = "Malware\n\nA list of Malware objects, describing details about the identified malware.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware: Option<Vec<Malware>>,
+    #[doc = "Malware Scan Info\n\nDescribes details about the scan job that identified malware on the target system.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "malware_scan_info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub malware_scan_info: Option<Box<MalwareScanInfo>>,
+    #[doc = "Message\n\nThe description of the event/finding, as defined by the source.\n\nrecommended"]
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[doc = "Metadata\n\nThe metadata associated with the event or a finding.\n\nrequired"]
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    #[doc = "Observables\n\nThe observables associated with the event or a finding.\n\nrecommended"]
+    #[serde(rename = "observables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observables: Option<Vec<Observable>>,
+    #[doc = "OSINT\n\nThe OSINT (Open Source Intelligence) object contains details related to an indicator such as the indicator itself, related indicators, geolocation, registrar information, subdomains, analyst commentary, and other contextual information. This information can be used to further enrich a detection or finding by providing decisioning support to other analysts and engineers.\n\nrequired (`osint` profile)"]
+    #[serde(rename = "osint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osint: Option<Vec<Osint>>,
+    #[doc = "Policy\n\nThe policy that pertains to the control that triggered the event, if applicable. For example the name of an anti-malware policy or an access control policy.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Box<Policy>>,
+    #[doc = "Raw Data\n\nThe raw event/finding data as received from the source.\n\noptional"]
+    #[serde(rename = "raw_data")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data: Option<String>,
+    #[doc = "Raw Data Hash\n\nThe hash, which describes the content of the raw_data field.\n\noptional"]
+    #[serde(rename = "raw_data_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_hash: Option<Box<Fingerprint>>,
+    #[doc = "Raw Data Size\n\nThe size of the raw data which was transformed into an OCSF event, in bytes.\n\noptional"]
+    #[serde(rename = "raw_data_size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_data_size: Option<i64>,
+    #[doc = "Risk Details\n\nDescribes the risk associated with the finding.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_details")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_details: Option<String>,
+    #[doc = "Risk Level\n\nThe risk level, normalized to the caption of the risk_level_id value.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<String>,
+    #[doc = "Risk Level ID\n\nThe normalized risk level id.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_level_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_id: Option<RiskLevelId>,
+    #[doc = "Risk Score\n\nThe risk score as reported by the event source.\n\noptional (`security_control` profile)"]
+    #[serde(rename = "risk_score")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i64>,
+    #[doc = "Script\n\nThe script that was the target of the activity.\n\nrequired"]
+    #[serde(rename = "script")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<Box<Script>>,
+    #[doc = "Severity\n\nThe event/finding severity, normalized to the caption of the <code>severity_id</code> value. In the case of 'Other', it is defined by the source.\n\noptional"]
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[doc = "Severity ID\n\n<p>The normalized identifier of the event/finding severity.</p>The normalized severity is a measurement the effort and expense required to manage and resolve an event or incident. Smaller numerical values represent lower impact events, and larger numerical values represent higher impact events.\n\nrequired"]
+    #[serde(rename = "severity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<SeverityId>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional"]
+    #[serde(rename = "start_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    #[doc = "Start Time\n\nThe start time of a time period, or the time of the least recent event included in the aggregate event.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "start_time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_dt: Option<DateTime>,
+    #[doc = "Status\n\nThe event status, normalized to the caption of the status_id value. In the case of 'Other', it is defined by the event source.\n\nrecommended"]
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Status Code\n\nThe event status code, as reported by the event source.<br /><br />For example, in a Windows Failed Authentication event, this would be the value of 'Failure Code', e.g. 0x18.\n\nrecommended"]
+    #[serde(rename = "status_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+    #[doc = "Status Detail\n\nThe status detail contains additional information about the event/finding outcome.\n\nrecommended"]
+    #[serde(rename = "status_detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    #[doc = "Status ID\n\nThe normalized identifier of the event status.\n\nrecommended"]
+    #[serde(rename = "status_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<StatusId>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\nrequired"]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[doc = "Event Time\n\nThe normalized event occurrence time or the finding creation time.\n\noptional (`datetime` profile)"]
+    #[serde(rename = "time_dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_dt: Option<DateTime>,
+    #[doc = "Timezone Offset\n\nThe number of minutes that the reported event <code>time</code> is ahead or behind UTC, in the range -1,080 to +1,080.\n\nrecommended"]
+    #[serde(rename = "timezone_offset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_offset: Option<i64>,
+    #[doc = "Type Name\n\nThe event/finding type name, as defined by the type_uid.\n\noptional"]
+    #[serde(rename = "type_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[doc = "Type ID\n\nThe event/finding type ID. It identifies the event's semantics and structure. The value is calculated by the logging system as: <code>class_uid * 100 + activity_id</code>.\n\nrequired"]
+    #[serde(rename = "type_uid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_uid: Option<i64>,
+    #[doc = "Unmapped Data\n\nThe attributes that are not mapped to the event schema. The names and values of those attributes are specific to the event source.\n\noptional"]
+    #[serde(rename = "unmapped")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<serde_json::Value>,
+    #[doc = r" Keys that the schema does not define, kept so they survive a round-trip"]
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, Value>,
+}
+impl Validate for ScriptActivity {
+    fn validate_at(&self, path: &str, report: &mut ValidationReport) {
+        report.apply_profiles(
+            self.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.profiles.as_deref()),
+        );
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "action_id",
+            self.action_id.is_some(),
+        );
+        report.check_required(path, "activity_id", self.activity_id.is_some());
+        report.check_required(path, "actor", self.actor.is_some());
+        if let Some(value) = &self.actor {
+            value.validate_at(&attr_path(path, "actor"), report);
+        }
+        if let Some(value) = &self.api {
+            value.validate_at(&attr_path(path, "api"), report);
+        }
+        if let Some(items) = &self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(items) = &self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required(path, "category_uid", self.category_uid.is_some());
+        report.check_required(path, "class_uid", self.class_uid.is_some());
+        report.check_required_in_profile(path, "cloud", "cloud", self.cloud.is_some());
+        if let Some(value) = &self.cloud {
+            value.validate_at(&attr_path(path, "cloud"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "confidence_id",
+            self.confidence_id.is_some(),
+        );
+        report.check_required(path, "device", self.device.is_some());
+        if let Some(value) = &self.device {
+            value.validate_at(&attr_path(path, "device"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "disposition_id",
+            self.disposition_id.is_some(),
+        );
+        report.check_format(path, "end_time_dt", self.end_time_dt.as_ref());
+        if let Some(items) = &self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.validate_at(&attr_path(path, "firewall_rule"), report);
+        }
+        report.check_recommended_in_profile(
+            path,
+            "security_control",
+            "is_alert",
+            self.is_alert.is_some(),
+        );
+        if let Some(items) = &self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.validate_at(&attr_path(path, "malware_scan_info"), report);
+        }
+        report.check_recommended(path, "message", self.message.is_some());
+        report.check_required(path, "metadata", self.metadata.is_some());
+        if let Some(value) = &self.metadata {
+            value.validate_at(&attr_path(path, "metadata"), report);
+        }
+        report.check_recommended(path, "observables", self.observables.is_some());
+        if let Some(items) = &self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        report.check_required_in_profile(path, "osint", "osint", self.osint.is_some());
+        if let Some(items) = &self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter().enumerate() {
+                item.validate_at(&index_path(&path, i), report);
+            }
+        }
+        if let Some(value) = &self.policy {
+            value.validate_at(&attr_path(path, "policy"), report);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.validate_at(&attr_path(path, "raw_data_hash"), report);
+        }
+        report.check_required(path, "script", self.script.is_some());
+        if let Some(value) = &self.script {
+            value.validate_at(&attr_path(path, "script"), report);
+        }
+        report.check_required(path, "severity_id", self.severity_id.is_some());
+        report.check_format(path, "start_time_dt", self.start_time_dt.as_ref());
+        report.check_recommended(path, "status", self.status.is_some());
+        report.check_recommended(path, "status_code", self.status_code.is_some());
+        report.check_recommended(path, "status_detail", self.status_detail.is_some());
+        report.check_recommended(path, "status_id", self.status_id.is_some());
+        report.check_required(path, "time", self.time.is_some());
+        report.check_format(path, "time_dt", self.time_dt.as_ref());
+        report.check_recommended(path, "timezone_offset", self.timezone_offset.is_some());
+        report.check_required(path, "type_uid", self.type_uid.is_some());
+        report.check_type_uid(path, self.type_uid, self.derived_type_uid());
+    }
+}
+impl Normalize for ScriptActivity {
+    fn normalize_at(&mut self, path: &str, conflicts: &mut Vec<CaptionConflict>) {
+        fill_sibling(
+            conflicts,
+            path,
+            "action",
+            self.action_id.map(i64::from),
+            self.action_id.and_then(|id| id.caption()),
+            &mut self.action,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "activity_name",
+            self.activity_id.map(i64::from),
+            self.activity_id.and_then(|id| id.caption()),
+            &mut self.activity_name,
+        );
+        if let Some(value) = &mut self.actor {
+            value.normalize_at(&attr_path(path, "actor"), conflicts);
+        }
+        if let Some(value) = &mut self.api {
+            value.normalize_at(&attr_path(path, "api"), conflicts);
+        }
+        if let Some(items) = &mut self.attacks {
+            let path = attr_path(path, "attacks");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.authorizations {
+            let path = attr_path(path, "authorizations");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "category_name",
+            self.category_uid,
+            match self.category_uid {
+                Some(1) => Some("System Activity"),
+                _ => None,
+            },
+            &mut self.category_name,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "class_name",
+            self.class_uid,
+            match self.class_uid {
+                Some(1009) => Some("Script Activity"),
+                _ => None,
+            },
+            &mut self.class_name,
+        );
+        if let Some(value) = &mut self.cloud {
+            value.normalize_at(&attr_path(path, "cloud"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "confidence",
+            self.confidence_id.map(i64::from),
+            self.confidence_id.and_then(|id| id.caption()),
+            &mut self.confidence,
+        );
+        if let Some(value) = &mut self.device {
+            value.normalize_at(&attr_path(path, "device"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "disposition",
+            self.disposition_id.map(i64::from),
+            self.disposition_id.and_then(|id| id.caption()),
+            &mut self.disposition,
+        );
+        if let Some(items) = &mut self.enrichments {
+            let path = attr_path(path, "enrichments");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.firewall_rule {
+            value.normalize_at(&attr_path(path, "firewall_rule"), conflicts);
+        }
+        if let Some(items) = &mut self.malware {
+            let path = attr_path(path, "malware");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.malware_scan_info {
+            value.normalize_at(&attr_path(path, "malware_scan_info"), conflicts);
+        }
+        if let Some(value) = &mut self.metadata {
+            value.normalize_at(&attr_path(path, "metadata"), conflicts);
+        }
+        if let Some(items) = &mut self.observables {
+            let path = attr_path(path, "observables");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(items) = &mut self.osint {
+            let path = attr_path(path, "osint");
+            for (i, item) in items.iter_mut().enumerate() {
+                item.normalize_at(&index_path(&path, i), conflicts);
+            }
+        }
+        if let Some(value) = &mut self.policy {
+            value.normalize_at(&attr_path(path, "policy"), conflicts);
+        }
+        if let Some(value) = &mut self.raw_data_hash {
+            value.normalize_at(&attr_path(path, "raw_data_hash"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "risk_level",
+            self.risk_level_id.map(i64::from),
+            self.risk_level_id.and_then(|id| id.caption()),
+            &mut self.risk_level,
+        );
+        if let Some(value) = &mut self.script {
+            value.normalize_at(&attr_path(path, "script"), conflicts);
+        }
+        fill_sibling(
+            conflicts,
+            path,
+            "severity",
+            self.severity_id.map(i64::from),
+            self.severity_id.and_then(|id| id.caption()),
+            &mut self.severity,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "status",
+            self.status_id.map(i64::from),
+            self.status_id.and_then(|id| id.caption()),
+            &mut self.status,
+        );
+        fill_sibling(
+            conflicts,
+            path,
+            "type_name",
+            self.type_uid,
+            match self.type_uid {
+                Some(100900) => Some("Script Activity: Unknown"),
+                Some(100901) => Some("Script Activity: Execute"),
+                Some(100999) => Some("Script Activity: Other"),
+                _ => None,
+            },
+            &mut self.type_name,
+        );
+    }
+}
+impl ScriptActivity {
+    #[doc = r" Creates an event with `class_uid`, `category_uid`, `class_name` and `category_name`"]
+    #[doc = r" set for this class"]
+    pub fn new_event() -> Self {
+        Self {
+            class_uid: Some(Self::CLASS_UID),
+            category_uid: Some(Self::CATEGORY_UID),
+            class_name: Some(Self::CAPTION.to_string()),
+            category_name: Some(Self::CATEGORY_CAPTION.to_string()),
+            ..Default::default()
+        }
+    }
+    #[doc = r" `class_uid * 100 + activity_id`, or `None` without an `activity_id`"]
+    pub fn derived_type_uid(&self) -> Option<i64> {
+        self.activity_id
+            .map(|activity_id| Self::CLASS_UID * 100 + i64::from(activity_id))
+    }
+    #[doc = r" The class caption and the `activity_id` caption, e.g. `Account Change: Create`."]
+    #[doc = r" `None` without an `activity_id` or when the schema has no caption for it."]
+    pub fn derived_type_name(&self) -> Option<String> {
+        self.activity_id
+            .and_then(|activity_id| activity_id.caption())
+            .map(|caption| format!("{}: {}", Self::CAPTION, caption))
+    }
+    #[doc = r" Sets `type_uid` and `type_name` to the values derived from `activity_id`"]
+    pub fn fill_type(&mut self) {
+        self.type_uid = self.derived_type_uid();
+        self.type_name = self.derived_type_name();
+    }
+}
+impl OcsfClass for ScriptActivity {
+    const CLASS_UID: i64 = 1009;
+    const CATEGORY_UID: i64 = 1;
+    const CLASS_NAME: &'static str = "script_activity";
+    const CATEGORY_NAME: &'static str = "system";
+    const CAPTION: &'static str = "Script Activity";
+    const CATEGORY_CAPTION: &'static str = "System Activity";
+}
+impl ScriptActivity {
+    pub fn builder() -> ScriptActivityBuilder {
+        ScriptActivityBuilder {
+            inner: ScriptActivity::new_event(),
+        }
+    }
+}
+#[doc = "Builder for [`ScriptActivity`]"]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScriptActivityBuilder {
+    inner: ScriptActivity,
+}
+impl ScriptActivityBuilder {
+    #[doc = "Action (optional (`security_control` profile))"]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.action = Some(value.into());
+        self
+    }
+    #[doc = "Action ID (recommended (`security_control` profile))"]
+    pub fn action_id(mut self, value: impl Into<ActionId>) -> Self {
+        self.inner.action_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity ID (required)"]
+    pub fn activity_id(mut self, value: impl Into<ScriptActivityActivityId>) -> Self {
+        self.inner.activity_id = Some(value.into());
+        self
+    }
+    #[doc = "Activity (optional)"]
+    pub fn activity_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.activity_name = Some(value.into());
+        self
+    }
+    #[doc = "Actor (required)"]
+    pub fn actor(mut self, value: Actor) -> Self {
+        self.inner.actor = Some(Box::new(value));
+        self
+    }
+    #[doc = "API Details (optional (`cloud` profile))"]
+    pub fn api(mut self, value: Api) -> Self {
+        self.inner.api = Some(Box::new(value));
+        self
+    }
+    #[doc = "MITRE ATT&CK® and ATLAS™ Details (optional (`security_control` profile))"]
+    pub fn attacks(mut self, value: Vec<Attack>) -> Self {
+        self.inner.attacks = Some(value);
+        self
+    }
+    #[doc = "Authorization Information (optional (`security_control` profile))"]
+    pub fn authorizations(mut self, value: Vec<Authorization>) -> Self {
+        self.inner.authorizations = Some(value);
+        self
+    }
+    #[doc = "Category (optional)"]
+    pub fn category_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.category_name = Some(value.into());
+        self
+    }
+    #[doc = "Category ID (required)"]
+    pub fn category_uid(mut self, value: i64) -> Self {
+        self.inner.category_uid = Some(value);
+        self
+    }
+    #[doc = "Class (optional)"]
+    pub fn class_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.class_name = Some(value.into());
+        self
+    }
+    #[doc = "Class ID (required)"]
+    pub fn class_uid(mut self, value: i64) -> Self {
+        self.inner.class_uid = Some(value);
+        self
+    }
+    #[doc = "Cloud (required (`cloud` profile))"]
+    pub fn cloud(mut self, value: Cloud) -> Self {
+        self.inner.cloud = Some(Box::new(value));
+        self
+    }
+    #[doc = "Confidence (optional (`security_control` profile))"]
+    pub fn confidence(mut self, value: impl Into<String>) -> Self {
+        self.inner.confidence = Some(value.into());
+        self
+    }
+    #[doc = "Confidence ID (recommended (`security_control` profile))"]
+    pub fn confidence_id(mut self, value: impl Into<ConfidenceId>) -> Self {
+        self.inner.confidence_id = Some(value.into());
+        self
+    }
+    #[doc = "Confidence Score (optional)"]
+    pub fn confidence_score(mut self, value: i64) -> Self {
+        self.inner.confidence_score = Some(value);
+        self
+    }
+    #[doc = "Count (optional)"]
+    pub fn count(mut self, value: i64) -> Self {
+        self.inner.count = Some(value);
+        self
+    }
+    #[doc = "Device (required)"]
+    pub fn device(mut self, value: Device) -> Self {
+        self.inner.device = Some(Box::new(value));
+        self
+    }
+    #[doc = "Disposition (optional (`security_control` profile))"]
+    pub fn disposition(mut self, value: impl Into<String>) -> Self {
+        self.inner.disposition = Some(value.into());
+        self
+    }
+    #[doc = "Disposition ID (recommended (`security_control` profile))"]
+    pub fn disposition_id(mut self, value: impl Into<DispositionId>) -> Self {
+        self.inner.disposition_id = Some(value.into());
+        self
+    }
+    #[doc = "Duration Milliseconds (optional)"]
+    pub fn duration(mut self, value: i64) -> Self {
+        self.inner.duration = Some(value);
+        self
+    }
+    #[doc = "End Time (optional)"]
+    pub fn end_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.end_time = Some(value.into());
+        self
+    }
+    #[doc = "End Time (optional (`datetime` profile))"]
+    pub fn end_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.end_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Enrichments (optional)"]
+    pub fn enrichments(mut self, value: Vec<Enrichment>) -> Self {
+        self.inner.enrichments = Some(value);
+        self
+    }
+    #[doc = "Firewall Rule (optional (`security_control` profile))"]
+    pub fn firewall_rule(mut self, value: FirewallRule) -> Self {
+        self.inner.firewall_rule = Some(Box::new(value));
+        self
+    }
+    #[doc = "Alert (recommended (`security_control` profile))"]
+    pub fn is_alert(mut self, value: bool) -> Self {
+        self.inner.is_alert = Some(value);
+        self
+    }
+    #[doc = "Malware (optional (`security_control` profile))"]
+    pub fn malware(mut self, value: Vec<Malware>) -> Self {
+        self.inner.malware = Some(value);
+        self
+    }
+    #[doc = "Malware Scan Info (optional (`security_control` profile))"]
+    pub fn malware_scan_info(mut self, value: MalwareScanInfo) -> Self {
+        self.inner.malware_scan_info = Some(Box::new(value));
+        self
+    }
+    #[doc = "Message (recommended)"]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.inner.message = Some(value.into());
+        self
+    }
+    #[doc = "Metadata (required)"]
+    pub fn metadata(mut self, value: Metadata) -> Self {
+        self.inner.metadata = Some(Box::new(value));
+        self
+    }
+    #[doc = "Observables (recommended)"]
+    pub fn observables(mut self, value: Vec<Observable>) -> Self {
+        self.inner.observables = Some(value);
+        self
+    }
+    #[doc = "OSINT (required (`osint` profile))"]
+    pub fn osint(mut self, value: Vec<Osint>) -> Self {
+        self.inner.osint = Some(value);
+        self
+    }
+    #[doc = "Policy (optional (`security_control` profile))"]
+    pub fn policy(mut self, value: Policy) -> Self {
+        self.inner.policy = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data (optional)"]
+    pub fn raw_data(mut self, value: impl Into<String>) -> Self {
+        self.inner.raw_data = Some(value.into());
+        self
+    }
+    #[doc = "Raw Data Hash (optional)"]
+    pub fn raw_data_hash(mut self, value: Fingerprint) -> Self {
+        self.inner.raw_data_hash = Some(Box::new(value));
+        self
+    }
+    #[doc = "Raw Data Size (optional)"]
+    pub fn raw_data_size(mut self, value: i64) -> Self {
+        self.inner.raw_data_size = Some(value);
+        self
+    }
+    #[doc = "Risk Details (optional (`security_control` profile))"]
+    pub fn risk_details(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_details = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level (optional (`security_control` profile))"]
+    pub fn risk_level(mut self, value: impl Into<String>) -> Self {
+        self.inner.risk_level = Some(value.into());
+        self
+    }
+    #[doc = "Risk Level ID (optional (`security_control` profile))"]
+    pub fn risk_level_id(mut self, value: impl Into<RiskLevelId>) -> Self {
+        self.inner.risk_level_id = Some(value.into());
+        self
+    }
+    #[doc = "Risk Score (optional (`security_control` profile))"]
+    pub fn risk_score(mut self, value: i64) -> Self {
+        self.inner.risk_score = Some(value);
+        self
+    }
+    #[doc = "Script (required)"]
+    pub fn script(mut self, value: Script) -> Self {
+        self.inner.script = Some(Box::new(value));
+        self
+    }
+    #[doc = "Severity (optional)"]
+    pub fn severity(mut self, value: impl Into<String>) -> Self {
+        self.inner.severity = Some(value.into());
+        self
+    }
+    #[doc = "Severity ID (required)"]
+    pub fn severity_id(mut self, value: impl Into<SeverityId>) -> Self {
+        self.inner.severity_id = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional)"]
+    pub fn start_time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.start_time = Some(value.into());
+        self
+    }
+    #[doc = "Start Time (optional (`datetime` profile))"]
+    pub fn start_time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.start_time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Status (recommended)"]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.inner.status = Some(value.into());
+        self
+    }
+    #[doc = "Status Code (recommended)"]
+    pub fn status_code(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_code = Some(value.into());
+        self
+    }
+    #[doc = "Status Detail (recommended)"]
+    pub fn status_detail(mut self, value: impl Into<String>) -> Self {
+        self.inner.status_detail = Some(value.into());
+        self
+    }
+    #[doc = "Status ID (recommended)"]
+    pub fn status_id(mut self, value: impl Into<StatusId>) -> Self {
+        self.inner.status_id = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (required)"]
+    pub fn time(mut self, value: impl Into<Timestamp>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
+    #[doc = "Event Time (optional (`datetime` profile))"]
+    pub fn time_dt(mut self, value: impl Into<DateTime>) -> Self {
+        self.inner.time_dt = Some(value.into());
+        self
+    }
+    #[doc = "Timezone Offset (recommended)"]
+    pub fn timezone_offset(mut self, value: i64) -> Self {
+        self.inner.timezone_offset = Some(value);
+        self
+    }
+    #[doc = "Type Name (optional)"]
+    pub fn type_name(mut self, value: impl Into<String>) -> Self {
+        self.inner.type_name = Some(value.into());
+        self
+    }
+    #[doc = "Type ID (required)"]
+    pub fn type_uid(mut self, value: i64) -> Self {
+        self.inner.type_uid = Some(value);
+        self
+    }
+    #[doc = "Unmapped Data (optional)"]
+    pub fn unmapped(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.inner.unmapped = Some(value.into());
+        self
+    }
+    #[doc = "Fills `type_uid` and `type_name` from `activity_id` when they are not set, then checks that every required attribute is set"]
+    pub fn build(mut self) -> Result<ScriptActivity, BuildError> {
+        if self.inner.type_uid.is_none() {
+            self.inner.type_uid = self.inner.derived_type_uid();
+        }
+        if self.inner.type_name.is_none() {
+            self.inner.type_name = self.inner.derived_type_name();
+        }
+        check_required(
+            "ScriptActivity",
+            &[
+                ("activity_id", self.inner.activity_id.is_some()),
+                ("actor", self.inner.actor.is_some()),
+                ("category_uid", self.inner.category_uid.is_some()),
+                ("class_uid", self.inner.class_uid.is_some()),
+                ("cloud", self.inner.cloud.is_some()),
+                ("device", self.inner.device.is_some()),
+                ("metadata", self.inner.metadata.is_some()),
+                ("osint", self.inner.osint.is_some()),
+                ("script", self.inner.script.is_some()),
+                ("severity_id", self.inner.severity_id.is_some()),
+                ("time", self.inner.time.is_some()),
+                ("type_uid", self.inner.type_uid.is_some()),
+            ],
+        )?;
+        Ok(self.inner)
+    }
+    #[doc = r" Returns the value without checking required attributes"]
+    pub fn build_unchecked(self) -> ScriptActivity {
+        self.inner
+    }
+}
+impl KnownFields for ScriptActivity {
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "actor" => Actor::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "api" => Api::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "attacks" => find_unknown_in_array::<Attack>(value, &attr_path(path, key), unknown),
+                "authorizations" => {
+                    find_unknown_in_array::<Authorization>(value, &attr_path(path, key), unknown)
+                }
+                "cloud" => Cloud::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "device" => Device::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "enrichments" => {
+                    find_unknown_in_array::<Enrichment>(value, &attr_path(path, key), unknown)
+                }
+                "firewall_rule" => {
+                    FirewallRule::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "malware" => {
+                    find_unknown_in_array::<Malware>(value, &attr_path(path, key), unknown)
+                }
+                "malware_scan_info" => {
+                    MalwareScanInfo::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "metadata" => Metadata::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "observables" => {
+                    find_unknown_in_array::<Observable>(value, &attr_path(path, key), unknown)
+                }
+                "osint" => find_unknown_in_array::<Osint>(value, &attr_path(path, key), unknown),
+                "policy" => Policy::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "raw_data_hash" => {
+                    Fingerprint::find_unknown_fields(value, &attr_path(path, key), unknown)
+                }
+                "script" => Script::find_unknown_fields(value, &attr_path(path, key), unknown),
+                "action" | "action_id" | "activity_id" | "activity_name" | "category_name"
+                | "category_uid" | "class_name" | "class_uid" | "confidence" | "confidence_id"
+                | "confidence_score" | "count" | "disposition" | "disposition_id" | "duration"
+                | "end_time" | "end_time_dt" | "is_alert" | "message" | "raw_data"
+                | "raw_data_size" | "risk_details" | "risk_level" | "risk_level_id"
+                | "risk_score" | "severity" | "severity_id" | "start_time" | "start_time_dt"
+                | "status" | "status_code" | "status_detail" | "status_id" | "time" | "time_dt"
+                | "timezone_offset" | "type_name" | "type_uid" | "unmapped" => {}
+                _ => unknown_field(path, key, unknown),
+            }
+        }
+    }
+}
+impl PathAccess for ScriptActivity {
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>> {
+        let segment = Segment::parse(path)?;
+        match segment.name {
+            "action" => segment.leaf(self.action.as_ref()),
+            "action_id" => segment.leaf(self.action_id.as_ref()),
+            "activity_id" => segment.leaf(self.activity_id.as_ref()),
+            "activity_name" => segment.leaf(self.activity_name.as_ref()),
+            "actor" => segment.object(self.actor.as_deref()),
+            "api" => segment.object(self.api.as_deref()),
+            "attacks" => segment.object_in(self.attacks.as_deref()),
+            "authorizations" => segment.object_in(self.authorizations.as_deref()),
+            "category_name" => segment.leaf(self.category_name.as_ref()),
+            "category_uid" => segment.leaf(self.category_uid.as_ref()),
+            "class_name" => segment.leaf(self.class_name.as_ref()),
+            "class_uid" => segment.leaf(self.class_uid.as_ref()),
+            "cloud" => segment.object(self.cloud.as_deref()),
+            "confidence" => segment.leaf(self.confidence.as_ref()),
+            "confidence_id" => segment.leaf(self.confidence_id.as_ref()),
+            "confidence_score" => segment.leaf(self.confidence_score.as_ref()),
+            "count" => segment.leaf(self.count.as_ref()),
+            "device" => segment.object(self.device.as_deref()),
+            "disposition" => segment.leaf(self.disposition.as_ref()),
+            "disposition_id" => segment.leaf(self.disposition_id.as_ref()),
+            "duration" => segment.leaf(self.duration.as_ref()),
+            "end_time" => segment.leaf(self.end_time.as_ref()),
+            "end_time_dt" => segment.leaf(self.end_time_dt.as_ref()),
+            "enrichments" => segment.object_in(self.enrichments.as_deref()),
+            "firewall_rule" => segment.object(self.firewall_rule.as_deref()),
+            "is_alert" => segment.leaf(self.is_alert.as_ref()),
+            "malware" => segment.object_in(self.malware.as_deref()),
+            "malware_scan_info" => segment.object(self.malware_scan_info.as_deref()),
+            "message" => segment.leaf(self.message.as_ref()),
+            "metadata" => segment.object(self.metadata.as_deref()),
+            "observables" => segment.object_in(self.observables.as_deref()),
+            "osint" => segment.object_in(self.osint.as_deref()),
+            "policy" => segment.object(self.policy.as_deref()),
+            "raw_data" => segment.leaf(self.raw_data.as_ref()),
+            "raw_data_hash" => segment.object(self.raw_data_hash.as_deref()),
+            "raw_data_size" => segment.leaf(self.raw_data_size.as_ref()),
+            "risk_details" => segment.leaf(self.risk_details.as_ref()),
+            "risk_level" => segment.leaf(self.risk_level.as_ref()),
+            "risk_level_id" => segment.leaf(self.risk_level_id.as_ref()),
+            "risk_score" => segment.leaf(self.risk_score.as_ref()),
+            "script" => segment.object(self.script.as_deref()),
+            "severity" => segment.leaf(self.severity.as_ref()),
+            "severity_id" => segment.leaf(self.severity_id.as_ref()),
+            "start_time" => segment.leaf(self.start_time.as_ref()),
+            "start_time_dt" => segment.leaf(self.start_time_dt.as_ref()),
+            "status" => segment.leaf(self.status.as_ref()),
+            "status_code" => segment.leaf(self.status_code.as_ref()),
+            "status_detail" => segment.leaf(self.status_detail.as_ref()),
+            "status_id" => segment.leaf(self.status_id.as_ref()),
+            "time" => segment.leaf(self.time.as_ref()),
+            "time_dt" => segment.leaf(self.time_dt.as_ref()),
+            "timezone_offset" => segment.leaf(self.timezone_offset.as_ref()),
+            "type_name" => segment.leaf(self.type_name.as_ref()),
+            "type_uid" => segment.leaf(self.type_uid.as_ref()),
+            "unmapped" => segment.leaf(self.unmapped.as_ref()),
+            _ => None,
+        }
+    }
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError> {
+        let segment = Segment::parse_mut(path)?;
+        match segment.name {
+            "action" => segment.set_leaf(&mut self.action, value),
+            "action_id" => segment.set_leaf(&mut self.action_id, value),
+            "activity_id" => segment.set_leaf(&mut self.activity_id, value),
+            "activity_name" => segment.set_leaf(&mut self.activity_name, value),
+            "actor" => segment.set_object(&mut self.actor, value),
+            "api" => segment.set_object(&mut self.api, value),
+            "attacks" => segment.set_object_in(&mut self.attacks, value),
+            "authorizations" => segment.set_object_in(&mut self.authorizations, value),
+            "category_name" => segment.set_leaf(&mut self.category_name, value),
+            "category_uid" => segment.set_leaf(&mut self.category_uid, value),
+            "class_name" => segment.set_leaf(&mut self.class_name, value),
+            "class_uid" => segment.set_leaf(&mut self.class_uid, value),
+            "cloud" => segment.set_object(&mut self.cloud, value),
+            "confidence" => segment.set_leaf(&mut self.confidence, value),
+            "confidence_id" => segment.set_leaf(&mut self.confidence_id, value),
+            "confidence_score" => segment.set_leaf(&mut self.confidence_score, value),
+            "count" => segment.set_leaf(&mut self.count, value),
+            "device" => segment.set_object(&mut self.device, value),
+            "disposition" => segment.set_leaf(&mut self.disposition, value),
+            "disposition_id" => segment.set_leaf(&mut self.disposition_id, value),
+            "duration" => segment.set_leaf(&mut self.duration, value),
+            "end_time" => segment.set_leaf(&mut self.end_time, value),
+            "end_time_dt" => segment.set_leaf(&mut self.end_time_dt, value),
+            "enrichments" => segment.set_object_in(&mut self.enrichments, value),
+            "firewall_rule" => segment.set_object(&mut self.firewall_rule, value),
+            "is_alert" => segment.set_leaf(&mut self.is_alert, value),
+            "malware" => segment.set_object_in(&mut self.malware, value),
+            "malware_scan_info" => segment.set_object(&mut self.malware_scan_info, value),
+            "message" => segment.set_leaf(&mut self.message, value),
+            "metadata" => segment.set_object(&mut self.metadata, value),
+            "observables" => segment.set_object_in(&mut self.observables, value),
+            "osint" => segment.set_object_in(&mut self.osint, value),
+            "policy" => segment.set_object(&mut self.policy, value),
+            "raw_data" => segment.set_leaf(&mut self.raw_data, value),
+            "raw_data_hash" => segment.set_object(&mut self.raw_data_hash, value),
+            "raw_data_size" => segment.set_leaf(&mut self.raw_data_size, value),
+            "risk_details" => segment.set_leaf(&mut self.risk_details, value),
+            "risk_level" => segment.set_leaf(&mut self.risk_level, value),
+            "risk_level_id" => segment.set_leaf(&mut self.risk_level_id, value),
+            "risk_score" => segment.set_leaf(&mut self.risk_score, value),
+            "script" => segment.set_object(&mut self.script, value),
+            "severity" => segment.set_leaf(&mut self.severity, value),
+            "severity_id" => segment.set_leaf(&mut self.severity_id, value),
+            "start_time" => segment.set_leaf(&mut self.start_time, value),
+            "start_time_dt" => segment.set_leaf(&mut self.start_time_dt, value),
+            "status" => segment.set_leaf(&mut self.status, value),
+            "status_code" => segment.set_leaf(&mut self.status_code, value),
+            "status_detail" => segment.set_leaf(&mut self.status_detail, value),
+            "status_id" => segment.set_leaf(&mut self.status_id, value),
+            "time" => segment.set_leaf(&mut self.time, value),
+            "time_dt" => segment.set_leaf(&mut self.time_dt, value),
+            "timezone_offset" => segment.set_leaf(&mut self.timezone_offset, value),
+            "type_name" => segment.set_leaf(&mut self.type_name, value),
+            "type_uid" => segment.set_leaf(&mut self.type_uid, value),
+            "unmapped" => segment.set_leaf(&mut self.unmapped, value),
+            _ => Err(segment.unknown()),
+        }
+    }
+}
+impl Observables for ScriptActivity {
+    type Observable = Observable;
+    fn collect_observables(&self, path: &str, found: &mut ObservableSet) {
+        if let Some(value) = &self.actor {
+            value.collect_observables(&attr_path(path, "actor"), found);
+        }
+        if let Some(value) = &self.api {
+            value.collect_observables(&attr_path(path, "api"), found);
+        }
+        for value in self.attacks.iter().flatten() {
+            value.collect_observables(&attr_path(path, "attacks"), found);
+        }
+        for value in self.authorizations.iter().flatten() {
+            value.collect_observables(&attr_path(path, "authorizations"), found);
+        }
+        if let Some(value) = &self.cloud {
+            value.collect_observables(&attr_path(path, "cloud"), found);
+        }
+        if let Some(value) = &self.device {
+            value.collect_observables(&attr_path(path, "device"), found);
+        }
+        for value in self.enrichments.iter().flatten() {
+            value.collect_observables(&attr_path(path, "enrichments"), found);
+        }
+        if let Some(value) = &self.firewall_rule {
+            value.collect_observables(&attr_path(path, "firewall_rule"), found);
+        }
+        for value in self.malware.iter().flatten() {
+            value.collect_observables(&attr_path(path, "malware"), found);
+        }
+        if let Some(value) = &self.malware_scan_info {
+            value.collect_observables(&attr_path(path, "malware_scan_info"), found);
+        }
+        if let Some(value) = &self.metadata {
+            value.collect_observables(&attr_path(path, "metadata"), found);
+        }
+        for value in self.osint.iter().flatten() {
+            value.collect_observables(&attr_path(path, "osint"), found);
+        }
+        if let Some(value) = &self.policy {
+            value.collect_observables(&attr_path(path, "policy"), found);
+        }
+        if let Some(value) = &self.raw_data_hash {
+            value.collect_observables(&attr_path(path, "raw_data_hash"), found);
+        }
+        if let Some(value) = &self.script {
+            value.collect_observables(&attr_path(path, "script"), found);
+        }
+    }
+}
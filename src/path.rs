@@ -0,0 +1,508 @@
+//! # Path Access
+//! Reads and writes attributes by their dotted path, such as `actor.user.name` or `observables[0].type_id`.
+//! Arrays are indexed with `[i]`.
+//! ```rust
+//! # #[cfg(feature = "system")]
+//! # {
+//! use ocsf_types::path::{FieldValue, PathAccess};
+//! let mut event = ocsf_types::FileActivity::default();
+//! event.set_path("actor.user.name", FieldValue::from("jdoe")).unwrap();
+//! event.set_path("observables[0].value", FieldValue::from("jdoe")).unwrap();
+//! assert_eq!(event.get_path("actor.user.name").and_then(|v| v.as_str()), Some("jdoe"));
+//! assert!(event.get_path("observables[1].value").is_none());
+//! assert!(event.set_path("actor.usr.name", FieldValue::from("jdoe")).is_err());
+//! # }
+//! ```
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::error::Error;
+use std::fmt;
+
+/// Implemented for every generated struct.
+pub trait PathAccess: fmt::Debug {
+    /// The value at `path`, or `None` when it is not set or the path does not exist
+    fn get_path(&self, path: &str) -> Option<FieldRef<'_>>;
+
+    /// Sets the value at `path`, creating the objects on the way, and array items right after the last one.
+    /// Nothing changes when it fails.
+    fn set_path(&mut self, path: &str, value: FieldValue) -> Result<(), PathError>;
+}
+
+/// A borrowed attribute value
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum FieldRef<'a> {
+    /// String types, including `ip_t`, `mac_t`, `subnet_t` and `datetime_t` as they were found
+    String(&'a str),
+    /// Integer types, including enumerated `*_id` attributes, `port_t` and `timestamp_t`
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Json(&'a Value),
+    Object(&'a dyn PathAccess),
+}
+
+impl<'a> FieldRef<'a> {
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self {
+            FieldRef::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            FieldRef::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            FieldRef::Float(f) => Some(*f),
+            FieldRef::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldRef::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+/// An owned value to set with [`PathAccess::set_path`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum FieldValue {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    /// Sets any attribute, including objects, as if it was deserialized
+    Json(Value),
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::String(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::String(value.to_string())
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        FieldValue::Integer(value)
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        FieldValue::Float(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Boolean(value)
+    }
+}
+
+impl From<Value> for FieldValue {
+    fn from(value: Value) -> Self {
+        FieldValue::Json(value)
+    }
+}
+
+impl From<FieldValue> for Value {
+    fn from(value: FieldValue) -> Self {
+        match value {
+            FieldValue::String(s) => Value::from(s),
+            FieldValue::Integer(i) => Value::from(i),
+            FieldValue::Float(f) => Value::from(f),
+            FieldValue::Boolean(b) => Value::from(b),
+            FieldValue::Json(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathErrorKind {
+    /// The path is empty or not of the form `name`, `name[i]`, `name.rest` or `name[i].rest`
+    Malformed,
+    /// The attribute is not defined by the schema
+    UnknownField,
+    /// The attribute is an array and needs an index, or it is not and cannot take one
+    Index,
+    /// The index is beyond the item right after the last one
+    IndexOutOfBounds { len: usize },
+    /// The path continues past an attribute that is not an object
+    NotAnObject,
+    /// The value cannot be converted to the type of the attribute
+    TypeMismatch { expected: &'static str },
+}
+
+/// Why [`PathAccess::set_path`] failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathError {
+    /// The path up to and including the segment that failed, e.g. `actor.usr`
+    pub path: String,
+    pub kind: PathErrorKind,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            PathErrorKind::Malformed => write!(f, "{}: malformed path", self.path),
+            PathErrorKind::UnknownField => write!(f, "{}: unknown field", self.path),
+            PathErrorKind::Index => write!(f, "{}: wrong use of an index", self.path),
+            PathErrorKind::IndexOutOfBounds { len } => {
+                write!(f, "{}: index out of bounds, length is {}", self.path, len)
+            }
+            PathErrorKind::NotAnObject => write!(f, "{}: not an object", self.path),
+            PathErrorKind::TypeMismatch { expected } => {
+                write!(f, "{}: expected {}", self.path, expected)
+            }
+        }
+    }
+}
+
+impl Error for PathError {}
+
+/// A leaf value that can be read as a [`FieldRef`]
+#[doc(hidden)]
+pub trait AsFieldRef {
+    fn as_field_ref(&self) -> FieldRef<'_>;
+}
+
+/// A leaf value that can be set from a [`FieldValue`], returning the expected type on mismatch
+#[doc(hidden)]
+pub trait FromFieldValue: Sized {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str>;
+}
+
+impl AsFieldRef for String {
+    fn as_field_ref(&self) -> FieldRef<'_> {
+        FieldRef::String(self)
+    }
+}
+
+impl FromFieldValue for String {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+        match value {
+            FieldValue::String(s) | FieldValue::Json(Value::String(s)) => Ok(s),
+            _ => Err("a string"),
+        }
+    }
+}
+
+impl AsFieldRef for i64 {
+    fn as_field_ref(&self) -> FieldRef<'_> {
+        FieldRef::Integer(*self)
+    }
+}
+
+impl FromFieldValue for i64 {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+        match value {
+            FieldValue::Integer(i) => Ok(i),
+            FieldValue::Json(v) => v.as_i64().ok_or("an integer"),
+            _ => Err("an integer"),
+        }
+    }
+}
+
+impl AsFieldRef for f64 {
+    fn as_field_ref(&self) -> FieldRef<'_> {
+        FieldRef::Float(*self)
+    }
+}
+
+impl FromFieldValue for f64 {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+        match value {
+            FieldValue::Float(f) => Ok(f),
+            FieldValue::Integer(i) => Ok(i as f64),
+            FieldValue::Json(v) => v.as_f64().ok_or("a number"),
+            _ => Err("a number"),
+        }
+    }
+}
+
+impl AsFieldRef for bool {
+    fn as_field_ref(&self) -> FieldRef<'_> {
+        FieldRef::Boolean(*self)
+    }
+}
+
+impl FromFieldValue for bool {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+        match value {
+            FieldValue::Boolean(b) => Ok(b),
+            FieldValue::Json(v) => v.as_bool().ok_or("a boolean"),
+            _ => Err("a boolean"),
+        }
+    }
+}
+
+impl AsFieldRef for Value {
+    fn as_field_ref(&self) -> FieldRef<'_> {
+        FieldRef::Json(self)
+    }
+}
+
+impl FromFieldValue for Value {
+    fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+        Ok(Value::from(value))
+    }
+}
+
+/// Implements the traits for the network types, which read as their text or number
+macro_rules! field_value_via {
+    ($name:ty, $variant:ident, $inner:ty, $as:expr) => {
+        impl AsFieldRef for $name {
+            fn as_field_ref(&self) -> FieldRef<'_> {
+                FieldRef::$variant($as(self))
+            }
+        }
+
+        impl FromFieldValue for $name {
+            fn from_field_value(value: FieldValue) -> Result<Self, &'static str> {
+                <$inner>::from_field_value(value).map(<$name>::from)
+            }
+        }
+    };
+}
+
+field_value_via!(crate::IpAddress, String, String, crate::IpAddress::as_str);
+field_value_via!(crate::MacAddress, String, String, crate::MacAddress::as_str);
+field_value_via!(crate::Subnet, String, String, crate::Subnet::as_str);
+field_value_via!(crate::Port, Integer, i64, |port: &crate::Port| i64::from(
+    *port
+));
+field_value_via!(crate::DateTime, String, String, crate::DateTime::as_str);
+field_value_via!(crate::Timestamp, Integer, i64, crate::Timestamp::as_millis);
+
+/// The first segment of a path, `name` or `name[index]`, and the rest after the following `.`
+#[doc(hidden)]
+pub struct Segment<'p> {
+    #[doc(hidden)]
+    pub name: &'p str,
+    index: Option<usize>,
+    rest: Option<&'p str>,
+}
+
+impl<'p> Segment<'p> {
+    #[doc(hidden)]
+    pub fn parse(path: &'p str) -> Option<Self> {
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+        let (name, index) = match head.split_once('[') {
+            Some((name, index)) => (name, Some(index.strip_suffix(']')?.parse().ok()?)),
+            None => (head, None),
+        };
+        (!name.is_empty()).then_some(Segment { name, index, rest })
+    }
+
+    #[doc(hidden)]
+    pub fn parse_mut(path: &'p str) -> Result<Self, PathError> {
+        Segment::parse(path).ok_or_else(|| PathError {
+            path: path.to_string(),
+            kind: PathErrorKind::Malformed,
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn leaf<'a, T: AsFieldRef>(&self, value: Option<&'a T>) -> Option<FieldRef<'a>> {
+        match (self.index, self.rest) {
+            (None, None) => value.map(T::as_field_ref),
+            _ => None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn leaf_in<'a, T: AsFieldRef>(
+        &self,
+        values: Option<&'a [T]>,
+    ) -> Option<FieldRef<'a>> {
+        match (self.index, self.rest) {
+            (Some(i), None) => values?.get(i).map(T::as_field_ref),
+            _ => None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn object<'a, T: PathAccess>(&self, value: Option<&'a T>) -> Option<FieldRef<'a>> {
+        let value = value?;
+        match (self.index, self.rest) {
+            (None, None) => Some(FieldRef::Object(value)),
+            (None, Some(rest)) => value.get_path(rest),
+            _ => None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn object_in<'a, T: PathAccess>(
+        &self,
+        values: Option<&'a [T]>,
+    ) -> Option<FieldRef<'a>> {
+        let value = values?.get(self.index?)?;
+        match self.rest {
+            None => Some(FieldRef::Object(value)),
+            Some(rest) => value.get_path(rest),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn set_leaf<T: FromFieldValue>(
+        &self,
+        slot: &mut Option<T>,
+        value: FieldValue,
+    ) -> Result<(), PathError> {
+        self.check(self.index.is_none(), PathErrorKind::Index)?;
+        self.check(self.rest.is_none(), PathErrorKind::NotAnObject)?;
+        *slot = Some(self.convert(value)?);
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    pub fn set_leaf_in<T: FromFieldValue>(
+        &self,
+        slot: &mut Option<Vec<T>>,
+        value: FieldValue,
+    ) -> Result<(), PathError> {
+        self.check(self.rest.is_none(), PathErrorKind::NotAnObject)?;
+        let index = self.checked_index(slot)?;
+        let value = self.convert(value)?;
+        put(slot.get_or_insert_with(Vec::new), index, value);
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    pub fn set_object<T: PathAccess + Default + DeserializeOwned>(
+        &self,
+        slot: &mut Option<Box<T>>,
+        value: FieldValue,
+    ) -> Result<(), PathError> {
+        self.check(self.index.is_none(), PathErrorKind::Index)?;
+        match self.rest {
+            None => *slot = Some(Box::new(self.deserialize(value)?)),
+            Some(rest) => match slot {
+                Some(object) => object
+                    .set_path(rest, value)
+                    .map_err(|err| self.within(err))?,
+                // The new object only takes the slot once the rest of the path is set in it
+                None => {
+                    let mut object = Box::<T>::default();
+                    object
+                        .set_path(rest, value)
+                        .map_err(|err| self.within(err))?;
+                    *slot = Some(object);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    pub fn set_object_in<T: PathAccess + Default + DeserializeOwned>(
+        &self,
+        slot: &mut Option<Vec<T>>,
+        value: FieldValue,
+    ) -> Result<(), PathError> {
+        let index = self.checked_index(slot)?;
+        match self.rest {
+            None => {
+                let object = self.deserialize(value)?;
+                put(slot.get_or_insert_with(Vec::new), index, object);
+            }
+            Some(rest) => match slot.as_mut().and_then(|items| items.get_mut(index)) {
+                Some(object) => object
+                    .set_path(rest, value)
+                    .map_err(|err| self.within(err))?,
+                // The new item is only added once the rest of the path is set in it
+                None => {
+                    let mut object = T::default();
+                    object
+                        .set_path(rest, value)
+                        .map_err(|err| self.within(err))?;
+                    slot.get_or_insert_with(Vec::new).push(object);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    pub fn unknown(&self) -> PathError {
+        self.error(PathErrorKind::UnknownField)
+    }
+
+    /// The index, which may point at an existing item or right after the last one
+    fn checked_index<T>(&self, slot: &Option<Vec<T>>) -> Result<usize, PathError> {
+        let index = self.index.ok_or_else(|| self.error(PathErrorKind::Index))?;
+        let len = slot.as_ref().map_or(0, Vec::len);
+        if index > len {
+            return Err(self.error(PathErrorKind::IndexOutOfBounds { len }));
+        }
+        Ok(index)
+    }
+
+    fn convert<T: FromFieldValue>(&self, value: FieldValue) -> Result<T, PathError> {
+        T::from_field_value(value)
+            .map_err(|expected| self.error(PathErrorKind::TypeMismatch { expected }))
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, value: FieldValue) -> Result<T, PathError> {
+        serde_json::from_value(Value::from(value)).map_err(|_| {
+            self.error(PathErrorKind::TypeMismatch {
+                expected: "an object",
+            })
+        })
+    }
+
+    fn check(&self, ok: bool, kind: PathErrorKind) -> Result<(), PathError> {
+        if ok { Ok(()) } else { Err(self.error(kind)) }
+    }
+
+    fn head(&self) -> String {
+        match self.index {
+            Some(i) => format!("{}[{}]", self.name, i),
+            None => self.name.to_string(),
+        }
+    }
+
+    fn error(&self, kind: PathErrorKind) -> PathError {
+        PathError {
+            path: self.head(),
+            kind,
+        }
+    }
+
+    /// Prefixes an error from a nested object with this segment
+    fn within(&self, err: PathError) -> PathError {
+        PathError {
+            path: format!("{}.{}", self.head(), err.path),
+            kind: err.kind,
+        }
+    }
+}
+
+/// Replaces the item at `index`, or appends it when `index` is the length
+fn put<T>(items: &mut Vec<T>, index: usize, value: T) {
+    if index == items.len() {
+        items.push(value);
+    } else {
+        items[index] = value;
+    }
+}
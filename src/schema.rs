@@ -0,0 +1,340 @@
+//! # Schema Introspection
+//! The schema the types were generated from, queryable at runtime.
+//! The functions below describe the version the crate root re-exports, [`SCHEMA_VERSION`](crate::SCHEMA_VERSION).
+//! Every version module has its own [`Schema`], e.g. `ocsf_types::v1_7::SCHEMA`.
+//! Like the types, classes and extension attributes are only described when their cargo feature is enabled.
+//! ```rust
+//! use ocsf_types::schema::{self, Requirement};
+//! # #[cfg(feature = "iam")]
+//! # {
+//! let class = schema::class_by_uid(3001).unwrap();
+//! assert_eq!(class.name(), "account_change");
+//! assert_eq!(class.attribute("user").unwrap().object_type(), Some("user"));
+//! # }
+//!
+//! let name = schema::object("file").unwrap().attribute("name").unwrap();
+//! assert_eq!(name.requirement(), Requirement::Required);
+//! assert_eq!(name.type_name(), "file_name_t");
+//! ```
+use crate::ocsf_generated::SCHEMA;
+use crate::strict::KnownFields;
+use crate::validation::Validate;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// Every event class, sorted by name
+pub fn classes() -> &'static [ClassInfo] {
+    SCHEMA.classes()
+}
+
+/// Every object, sorted by name
+pub fn objects() -> &'static [ObjectInfo] {
+    SCHEMA.objects()
+}
+
+/// The class named `name`, e.g. `account_change`
+pub fn class(name: &str) -> Option<&'static ClassInfo> {
+    SCHEMA.class(name)
+}
+
+pub fn class_by_uid(uid: i64) -> Option<&'static ClassInfo> {
+    SCHEMA.class_by_uid(uid)
+}
+
+/// The object named `name`, e.g. `file`, or `win/reg_key` for objects of an extension
+pub fn object(name: &str) -> Option<&'static ObjectInfo> {
+    SCHEMA.object(name)
+}
+
+/// Implemented for the `OcsfEvent` of every generated version, such as [`OcsfEvent`](crate::OcsfEvent),
+/// so that [`migrate`](crate::migrate) can work with the schema it was generated from
+pub trait EventSchema: Serialize + DeserializeOwned + KnownFields + Validate {
+    /// The schema of the version, e.g. `ocsf_types::v1_7::SCHEMA`
+    fn schema() -> &'static Schema;
+}
+
+/// The classes and objects of one OCSF version
+// The fields of the schema types are public for the tables ocsf-codegen generates in other crates
+#[derive(Debug)]
+pub struct Schema {
+    #[doc(hidden)]
+    pub version: &'static str,
+    #[doc(hidden)]
+    pub classes: &'static [ClassInfo],
+    #[doc(hidden)]
+    pub objects: &'static [ObjectInfo],
+}
+
+impl Schema {
+    /// The OCSF version, e.g. `1.7.0`
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Every event class, sorted by name
+    pub fn classes(&self) -> &'static [ClassInfo] {
+        self.classes
+    }
+
+    /// Every object, sorted by name
+    pub fn objects(&self) -> &'static [ObjectInfo] {
+        self.objects
+    }
+
+    /// The class named `name`, e.g. `account_change`
+    pub fn class(&self, name: &str) -> Option<&'static ClassInfo> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+
+    pub fn class_by_uid(&self, uid: i64) -> Option<&'static ClassInfo> {
+        self.classes.iter().find(|class| class.uid == uid)
+    }
+
+    /// The object named `name`, e.g. `file`, or `win/reg_key` for objects of an extension
+    pub fn object(&self, name: &str) -> Option<&'static ObjectInfo> {
+        self.objects.iter().find(|object| object.name == name)
+    }
+}
+
+/// Requirement level of an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    Required,
+    Recommended,
+    Optional,
+}
+
+impl Requirement {
+    /// The level as it appears in the schema
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Requirement::Required => "required",
+            Requirement::Recommended => "recommended",
+            Requirement::Optional => "optional",
+        }
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why and since which version a class, object or attribute is deprecated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deprecation {
+    pub message: &'static str,
+    pub since: &'static str,
+}
+
+#[derive(Debug)]
+pub struct ClassInfo {
+    #[doc(hidden)]
+    pub uid: i64,
+    #[doc(hidden)]
+    pub name: &'static str,
+    #[doc(hidden)]
+    pub caption: &'static str,
+    #[doc(hidden)]
+    pub description: &'static str,
+    #[doc(hidden)]
+    pub category: &'static str,
+    #[doc(hidden)]
+    pub category_uid: i64,
+    #[doc(hidden)]
+    pub deprecated: Option<Deprecation>,
+    #[doc(hidden)]
+    pub profiles: &'static [&'static str],
+    #[doc(hidden)]
+    pub extension: Option<&'static str>,
+    #[doc(hidden)]
+    pub attributes: &'static [AttributeInfo],
+}
+
+impl ClassInfo {
+    pub fn uid(&self) -> i64 {
+        self.uid
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn caption(&self) -> &'static str {
+        self.caption
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// The category name, e.g. `iam`
+    pub fn category(&self) -> &'static str {
+        self.category
+    }
+
+    pub fn category_uid(&self) -> i64 {
+        self.category_uid
+    }
+
+    pub fn deprecated(&self) -> Option<&Deprecation> {
+        self.deprecated.as_ref()
+    }
+
+    /// The profiles the class can apply, e.g. `cloud` or `host`
+    pub fn profiles(&self) -> &'static [&'static str] {
+        self.profiles
+    }
+
+    /// The extension that defines the class, e.g. `win`
+    pub fn extension(&self) -> Option<&'static str> {
+        self.extension
+    }
+
+    /// Sorted by name
+    pub fn attributes(&self) -> &'static [AttributeInfo] {
+        self.attributes
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&'static AttributeInfo> {
+        find_attribute(self.attributes, name)
+    }
+}
+
+#[derive(Debug)]
+pub struct ObjectInfo {
+    #[doc(hidden)]
+    pub name: &'static str,
+    #[doc(hidden)]
+    pub caption: &'static str,
+    #[doc(hidden)]
+    pub description: &'static str,
+    #[doc(hidden)]
+    pub deprecated: Option<Deprecation>,
+    #[doc(hidden)]
+    pub extension: Option<&'static str>,
+    #[doc(hidden)]
+    pub attributes: &'static [AttributeInfo],
+}
+
+impl ObjectInfo {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn caption(&self) -> &'static str {
+        self.caption
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn deprecated(&self) -> Option<&Deprecation> {
+        self.deprecated.as_ref()
+    }
+
+    /// The extension that defines the object, e.g. `win`
+    pub fn extension(&self) -> Option<&'static str> {
+        self.extension
+    }
+
+    /// Sorted by name
+    pub fn attributes(&self) -> &'static [AttributeInfo] {
+        self.attributes
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&'static AttributeInfo> {
+        find_attribute(self.attributes, name)
+    }
+}
+
+#[derive(Debug)]
+pub struct AttributeInfo {
+    #[doc(hidden)]
+    pub name: &'static str,
+    #[doc(hidden)]
+    pub caption: &'static str,
+    #[doc(hidden)]
+    pub description: &'static str,
+    #[doc(hidden)]
+    pub requirement: Requirement,
+    #[doc(hidden)]
+    pub type_name: &'static str,
+    #[doc(hidden)]
+    pub object_type: Option<&'static str>,
+    #[doc(hidden)]
+    pub is_array: bool,
+    #[doc(hidden)]
+    pub deprecated: Option<Deprecation>,
+    #[doc(hidden)]
+    pub profile: Option<&'static str>,
+    #[doc(hidden)]
+    pub extension: Option<&'static str>,
+    #[doc(hidden)]
+    pub enum_values: &'static [(i64, &'static str)],
+}
+
+impl AttributeInfo {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn caption(&self) -> &'static str {
+        self.caption
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn requirement(&self) -> Requirement {
+        self.requirement
+    }
+
+    /// The OCSF type, e.g. `string_t`, or `object_t` for objects
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The object name for `object_t` attributes, e.g. `file`
+    pub fn object_type(&self) -> Option<&'static str> {
+        self.object_type
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.is_array
+    }
+
+    pub fn deprecated(&self) -> Option<&Deprecation> {
+        self.deprecated.as_ref()
+    }
+
+    /// The profile that adds the attribute. Its requirement only holds when the event applies the profile.
+    pub fn profile(&self) -> Option<&'static str> {
+        self.profile
+    }
+
+    /// The extension that adds the attribute to a class or object outside of it, e.g. `win` for `process.hosted_services`.
+    /// The attribute only compiles with the extension's cargo feature.
+    pub fn extension(&self) -> Option<&'static str> {
+        self.extension
+    }
+
+    /// The values the schema enumerates, with their captions, sorted by value
+    pub fn enum_values(&self) -> &'static [(i64, &'static str)] {
+        self.enum_values
+    }
+}
+
+fn find_attribute(
+    attributes: &'static [AttributeInfo],
+    name: &str,
+) -> Option<&'static AttributeInfo> {
+    attributes
+        .binary_search_by(|attr| attr.name.cmp(name))
+        .ok()
+        .map(|i| &attributes[i])
+}
@@ -0,0 +1,115 @@
+//! # Strict Parsing
+//! The generated types ignore keys the schema does not define, and keep values such as an `ip_t`
+//! that is not an address. The functions here reject both instead, reporting the JSON path of each.
+//! ```rust
+//! # #[cfg(feature = "system")]
+//! # {
+//! use ocsf_types::{strict, FileActivity};
+//! let err = strict::from_str::<FileActivity>(r#"{"actvity_id": 1, "file": {"nmae": "a.txt"}}"#).unwrap_err();
+//! assert_eq!(err.to_string(), "unknown fields: actvity_id, file.nmae");
+//! # }
+//! ```
+use crate::validation::{Validate, Violation, ViolationKind, attr_path, index_path};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::error::Error;
+use std::fmt;
+
+/// Implemented for every generated struct, and for [`OcsfEvent`](crate::OcsfEvent).
+pub trait KnownFields {
+    /// Adds the path of every key in `value`, or nested in it, that the schema does not define
+    fn find_unknown_fields(value: &Value, path: &str, unknown: &mut Vec<String>);
+}
+
+#[derive(Debug)]
+pub enum StrictError {
+    /// JSON paths of the keys that the schema does not define, e.g. `src_endpoint.vendor_ext`
+    UnknownFields(Vec<String>),
+    /// Values that are not valid for their schema type, e.g. `src_endpoint.ip` holding `"not an ip"`
+    InvalidValues(Vec<Violation>),
+    /// The input is not valid JSON or does not match the types of the schema
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrictError::UnknownFields(paths) => write!(f, "unknown fields: {}", paths.join(", ")),
+            StrictError::InvalidValues(violations) => {
+                let violations: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                write!(f, "invalid values: {}", violations.join(", "))
+            }
+            StrictError::Json(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for StrictError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StrictError::UnknownFields(_) | StrictError::InvalidValues(_) => None,
+            StrictError::Json(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for StrictError {
+    fn from(err: serde_json::Error) -> Self {
+        StrictError::Json(err)
+    }
+}
+
+/// Like [`serde_json::from_value`], but fails on keys that the schema does not define
+/// and on values that are not valid for their schema type
+pub fn from_value<T: DeserializeOwned + KnownFields + Validate>(
+    value: Value,
+) -> Result<T, StrictError> {
+    let mut unknown = Vec::new();
+    T::find_unknown_fields(&value, "", &mut unknown);
+    if !unknown.is_empty() {
+        return Err(StrictError::UnknownFields(unknown));
+    }
+    let parsed: T = serde_json::from_value(value)?;
+    let invalid: Vec<Violation> = parsed
+        .validate()
+        .violations
+        .into_iter()
+        .filter(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. }))
+        .collect();
+    if !invalid.is_empty() {
+        return Err(StrictError::InvalidValues(invalid));
+    }
+    Ok(parsed)
+}
+
+/// Like [`serde_json::from_str`], but fails on keys that the schema does not define
+/// and on values that are not valid for their schema type
+pub fn from_str<T: DeserializeOwned + KnownFields + Validate>(s: &str) -> Result<T, StrictError> {
+    from_value(serde_json::from_str(s)?)
+}
+
+/// Like [`serde_json::from_slice`], but fails on keys that the schema does not define
+/// and on values that are not valid for their schema type
+pub fn from_slice<T: DeserializeOwned + KnownFields + Validate>(
+    v: &[u8],
+) -> Result<T, StrictError> {
+    from_value(serde_json::from_slice(v)?)
+}
+
+#[doc(hidden)]
+pub fn find_unknown_in_array<T: KnownFields>(
+    value: &Value,
+    path: &str,
+    unknown: &mut Vec<String>,
+) {
+    if let Some(items) = value.as_array() {
+        for (i, item) in items.iter().enumerate() {
+            T::find_unknown_fields(item, &index_path(path, i), unknown);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn unknown_field(path: &str, key: &str, unknown: &mut Vec<String>) {
+    unknown.push(attr_path(path, key));
+}
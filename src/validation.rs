@@ -0,0 +1,368 @@
+//! # Validation
+//! Checks generated types against the requirement levels and class constraints of the OCSF schema,
+//! that an event's `type_uid` matches its `class_uid` and `activity_id`,
+//! and that values such as `ip_t` or `email_t` are well formed.
+//! ```rust
+//! # #[cfg(feature = "system")]
+//! # {
+//! use ocsf_types::{FileActivity, Validate};
+//! let event = FileActivity::default();
+//! let report = event.validate();
+//! assert!(!report.is_valid());
+//! assert!(report.errors().any(|v| v.path == "metadata"));
+//! # }
+//! ```
+use crate::format::{StringFormat, is_valid_file_hash};
+use std::fmt;
+
+/// How serious a [`Violation`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// The record does not conform to the schema
+    Error,
+    /// The record conforms, but is missing information the schema asks for
+    Warning,
+}
+
+/// What rule a [`Violation`] broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// A `required` attribute is missing
+    MissingRequired,
+    /// A `recommended` attribute is missing
+    MissingRecommended,
+    /// A class constraint over several attributes is not met
+    Constraint {
+        rule: ConstraintRule,
+        /// The attributes named by the constraint
+        fields: Vec<String>,
+    },
+    /// The stored `type_uid` is not `class_uid * 100 + activity_id`
+    TypeUidMismatch { expected: i64, found: i64 },
+    /// A value was kept as found, but is not valid for its schema type
+    InvalidFormat {
+        /// The schema type, e.g. `ip_t`
+        expected: &'static str,
+        found: String,
+    },
+}
+
+/// Class constraints defined by the schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintRule {
+    /// `at_least_one`: one or more of the attributes must be present
+    AtLeastOne,
+    /// `just_one`: exactly one of the attributes must be present
+    JustOne,
+}
+
+impl ConstraintRule {
+    /// The rule name as it appears in the schema
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConstraintRule::AtLeastOne => "at_least_one",
+            ConstraintRule::JustOne => "just_one",
+        }
+    }
+
+    fn is_met(&self, present: usize) -> bool {
+        match self {
+            ConstraintRule::AtLeastOne => present >= 1,
+            ConstraintRule::JustOne => present == 1,
+        }
+    }
+}
+
+impl fmt::Display for ConstraintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single schema violation, located by its JSON path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// JSON path of the offending attribute, e.g. `metadata.product.name` or `observables[0].type_id`.
+    /// For constraints this is the path of the object that holds the attributes, empty for the event itself.
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+impl Violation {
+    pub fn level(&self) -> Level {
+        match self.kind {
+            ViolationKind::MissingRequired
+            | ViolationKind::Constraint { .. }
+            | ViolationKind::TypeUidMismatch { .. }
+            | ViolationKind::InvalidFormat { .. } => Level::Error,
+            ViolationKind::MissingRecommended => Level::Warning,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::MissingRequired => {
+                write!(f, "{}: missing required attribute", self.path)
+            }
+            ViolationKind::MissingRecommended => {
+                write!(f, "{}: missing recommended attribute", self.path)
+            }
+            ViolationKind::Constraint { rule, fields } => {
+                write!(
+                    f,
+                    "{}: {} of [{}] not met",
+                    self.path,
+                    rule,
+                    fields.join(", ")
+                )
+            }
+            ViolationKind::TypeUidMismatch { expected, found } => {
+                write!(f, "{}: expected {}, found {}", self.path, expected, found)
+            }
+            ViolationKind::InvalidFormat { expected, found } => {
+                write!(f, "{}: expected {}, found {:?}", self.path, expected, found)
+            }
+        }
+    }
+}
+
+/// All violations found while validating a value
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+    /// The profiles whose attributes are checked, those of `metadata.profiles`
+    profiles: Vec<String>,
+    /// Checks the attributes of every profile, see [`Validate::validate_all_profiles`]
+    all_profiles: bool,
+}
+
+impl ValidationReport {
+    /// `true` when there are no [`Level::Error`] violations. Warnings are allowed.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(|v| v.level() == Level::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.level() == Level::Warning)
+    }
+
+    pub(crate) fn push(&mut self, path: String, kind: ViolationKind) {
+        self.violations.push(Violation { path, kind });
+    }
+
+    #[doc(hidden)]
+    pub fn check_required(&mut self, path: &str, attr: &str, present: bool) {
+        if !present {
+            self.push(attr_path(path, attr), ViolationKind::MissingRequired);
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_recommended(&mut self, path: &str, attr: &str, present: bool) {
+        if !present {
+            self.push(attr_path(path, attr), ViolationKind::MissingRecommended);
+        }
+    }
+
+    /// Checks the attributes of the profiles in `metadata.profiles` from here on
+    #[doc(hidden)]
+    pub fn apply_profiles(&mut self, profiles: Option<&[String]>) {
+        self.profiles = profiles.unwrap_or_default().to_vec();
+    }
+
+    fn is_applied(&self, profile: &str) -> bool {
+        self.all_profiles || self.profiles.iter().any(|p| p == profile)
+    }
+
+    #[doc(hidden)]
+    pub fn check_required_in_profile(
+        &mut self,
+        path: &str,
+        profile: &str,
+        attr: &str,
+        present: bool,
+    ) {
+        if self.is_applied(profile) {
+            self.check_required(path, attr, present);
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_recommended_in_profile(
+        &mut self,
+        path: &str,
+        profile: &str,
+        attr: &str,
+        present: bool,
+    ) {
+        if self.is_applied(profile) {
+            self.check_recommended(path, attr, present);
+        }
+    }
+
+    /// `fields` pairs each attribute named by the constraint with whether it is present
+    #[doc(hidden)]
+    pub fn check_constraint(
+        &mut self,
+        path: &str,
+        rule: ConstraintRule,
+        fields: &[(&str, bool)],
+    ) {
+        let present = fields.iter().filter(|(_, present)| *present).count();
+        if !rule.is_met(present) {
+            let fields = fields.iter().map(|(name, _)| name.to_string()).collect();
+            self.push(path.to_string(), ViolationKind::Constraint { rule, fields });
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_type_uid(&mut self, path: &str, found: Option<i64>, expected: Option<i64>) {
+        if let (Some(found), Some(expected)) = (found, expected)
+            && found != expected
+        {
+            let kind = ViolationKind::TypeUidMismatch { expected, found };
+            self.push(attr_path(path, "type_uid"), kind);
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_format<T: ScalarFormat>(
+        &mut self,
+        path: &str,
+        attr: &str,
+        value: Option<&T>,
+    ) {
+        if let Some(value) = value {
+            self.check_format_at(attr_path(path, attr), value);
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_formats<T: ScalarFormat>(
+        &mut self,
+        path: &str,
+        attr: &str,
+        values: Option<&[T]>,
+    ) {
+        let path = attr_path(path, attr);
+        for (i, value) in values.into_iter().flatten().enumerate() {
+            self.check_format_at(index_path(&path, i), value);
+        }
+    }
+
+    fn check_format_at<T: ScalarFormat>(&mut self, path: String, value: &T) {
+        if !value.is_valid() {
+            self.push_invalid(path, T::TYPE_NAME, value.to_string());
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_string_format(
+        &mut self,
+        path: &str,
+        attr: &str,
+        format: StringFormat,
+        value: Option<&str>,
+    ) {
+        if let Some(value) = value
+            && !format.is_valid(value)
+        {
+            self.push_invalid(attr_path(path, attr), format.type_name(), value.to_string());
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn check_string_formats(
+        &mut self,
+        path: &str,
+        attr: &str,
+        format: StringFormat,
+        values: Option<&[String]>,
+    ) {
+        let path = attr_path(path, attr);
+        for (i, value) in values.into_iter().flatten().enumerate() {
+            if !format.is_valid(value) {
+                self.push_invalid(index_path(&path, i), format.type_name(), value.clone());
+            }
+        }
+    }
+
+    /// Checks a `file_hash_t` against the `algorithm_id` next to it
+    #[doc(hidden)]
+    pub fn check_file_hash(
+        &mut self,
+        path: &str,
+        attr: &str,
+        value: Option<&str>,
+        algorithm_id: Option<i64>,
+    ) {
+        if let Some(value) = value
+            && !is_valid_file_hash(value, algorithm_id)
+        {
+            let expected = StringFormat::FileHash.type_name();
+            self.push_invalid(attr_path(path, attr), expected, value.to_string());
+        }
+    }
+
+    fn push_invalid(&mut self, path: String, expected: &'static str, found: String) {
+        self.push(path, ViolationKind::InvalidFormat { expected, found });
+    }
+}
+
+/// Implemented for every generated struct.
+pub trait Validate {
+    /// Validates this value and everything nested in it.
+    ///
+    /// The attributes a profile adds, such as `cloud`, are only checked when the event lists
+    /// the profile in `metadata.profiles`.
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        self.validate_at("", &mut report);
+        report
+    }
+
+    /// Like [`validate`](Self::validate), but checks the attributes of every profile the class
+    /// supports, as if the event applied them all
+    fn validate_all_profiles(&self) -> ValidationReport {
+        let mut report = ValidationReport {
+            all_profiles: true,
+            ..Default::default()
+        };
+        self.validate_at("", &mut report);
+        report
+    }
+
+    /// Validates this value as if it was found at `path`, adding violations to `report`
+    fn validate_at(&self, path: &str, report: &mut ValidationReport);
+}
+
+/// A scalar that keeps the value it was parsed from, even when that value is not valid for its schema type
+#[doc(hidden)]
+pub trait ScalarFormat: fmt::Display {
+    const TYPE_NAME: &'static str;
+
+    fn is_valid(&self) -> bool;
+}
+
+#[doc(hidden)]
+pub fn attr_path(path: &str, attr: &str) -> String {
+    if path.is_empty() {
+        attr.to_string()
+    } else {
+        format!("{}.{}", path, attr)
+    }
+}
+
+#[doc(hidden)]
+pub fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
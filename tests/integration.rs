@@ -1,4 +1,4 @@
-
+#[cfg(feature = "system")]
 #[test]
 fn test_parse_raw_json() {
     let raw_log = include_str!("data/sample_file_activity.json");
@@ -11,9 +11,10 @@ fn test_parse_raw_json() {
     let activity_id = event.activity_id.expect("Activity ID is missing");
 
     assert_eq!(reporter_name, "jul gtk cleaners");
-    assert_eq!(activity_id, 12);
+    assert_eq!(i64::from(activity_id), 12);
 }
 
+#[cfg(feature = "network")]
 #[test]
 fn test_unknown_fields_don_not_break_parsing() {
     let json = r#"{
@@ -25,6 +26,7 @@ fn test_unknown_fields_don_not_break_parsing() {
     assert!(res.is_ok());
 }
 
+#[cfg(feature = "network")]
 #[test]
 fn unmapped_data_is_stored_in_unmapped_field() {
     let json = r#"{
@@ -51,6 +53,7 @@ fn unmapped_data_is_stored_in_unmapped_field() {
     assert_eq!(unmapped_val2, &vec![1,2,3]);
 }
 
+#[cfg(feature = "network")]
 #[test]
 fn deeply_nested_unmapped_data_is_stored_correctly() {
     let json = r#"{
@@ -68,6 +71,7 @@ fn deeply_nested_unmapped_data_is_stored_correctly() {
 }
 
 
+#[cfg(feature = "discovery")]
 #[test]
 fn missing_option_fields_are_not_present_in_the_event() {
     let raw_log = include_str!("data/sample_device_inventory_info_missing_optional.json");
@@ -77,26 +81,50 @@ fn missing_option_fields_are_not_present_in_the_event() {
     assert!(event.category_name.is_none());
     // just to be safe make sure required fields are present
     assert_eq!(event.category_uid, Some(5));
-    assert_eq!(event.activity_id, Some(1));
+    assert_eq!(event.activity_id.map(i64::from), Some(1));
 }
 
+#[cfg(feature = "discovery")]
 #[test]
 fn observables_are_stored_correctly() {
     let raw_log = include_str!("data/sample_device_inventory_info_missing_optional.json");
     let event: ocsf_types::InventoryInfo = serde_json::from_str(raw_log).expect("Failed to parse InventoryInfo log");
     let observables = event.observables.as_ref().expect("Observables are missing");
-    let observable = observables.get(0).expect("Observable is missing");
+    let observable = observables.first().expect("Observable is missing");
     assert_eq!(observable.name, Some("weeks cam reflects".to_string()));
     assert_eq!(observable.value, Some("alberta dx deliver".to_string()));
     assert_eq!(observable.r#type, Some("Registry Key".to_string()));
-    assert_eq!(observable.type_id, Some(28));
+    assert_eq!(observable.type_id.map(i64::from), Some(28));
     assert_eq!(observable.event_uid, Some("bc63ea5c-e51b-11f0-9e1b-d6ff413579c1".to_string()));
     assert_eq!(observable.reputation.as_ref().expect("Reputation is missing").base_score, Some(86.933));
     assert_eq!(observable.reputation.as_ref().expect("Reputation is missing").provider, Some("mel assume trigger".to_string()));
     assert_eq!(observable.reputation.as_ref().expect("Reputation is missing").score, Some("Malicious".to_string()));
-    assert_eq!(observable.reputation.as_ref().expect("Reputation is missing").score_id, Some(10));
+    assert_eq!(observable.reputation.as_ref().expect("Reputation is missing").score_id.map(i64::from), Some(10));
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn enumerated_ids_are_typed_and_keep_unrecognized_values() {
+    use ocsf_types::{FileActivityActivityId, SeverityId, StatusId};
+    let event: ocsf_types::FileActivity =
+        serde_json::from_str(r#"{"activity_id": 14, "severity_id": 4, "status_id": 1234}"#).unwrap();
+    assert_eq!(event.activity_id, Some(FileActivityActivityId::Open));
+    assert_eq!(event.severity_id, Some(SeverityId::High));
+    assert_eq!(event.status_id, Some(StatusId::Unrecognized(1234)));
+    assert_eq!(SeverityId::High.caption(), Some("High"));
+    assert_eq!(StatusId::Unrecognized(1234).caption(), None);
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["severity_id"], 4);
+    assert_eq!(json["status_id"], 1234);
+    assert_eq!(i64::from(SeverityId::Unknown), 0);
+    assert_eq!(i64::from(SeverityId::Other), 99);
+    assert_eq!(SeverityId::from(0), SeverityId::Unknown);
+    assert_eq!(SeverityId::from(99), SeverityId::Other);
+    assert_eq!(SeverityId::Other.caption(), Some("Other"));
 }
 
+#[cfg(feature = "discovery")]
 #[test]
 fn round_trip_de_serialization_is_idempotent() {
     let raw_log = include_str!("data/sample_device_inventory_info_missing_optional.json");
@@ -110,6 +138,7 @@ fn round_trip_de_serialization_is_idempotent() {
     assert_eq!(event2.category_uid, Some(5));
 }
 
+#[cfg(feature = "iam")]
 #[test]
 fn verify_default_values() {
     let mut event = ocsf_types::AccountChange::default();
@@ -122,7 +151,7 @@ fn verify_default_values() {
     assert_eq!(event.activity_name, None);
     assert_eq!(event.category_name, None);
     assert_eq!(event.category_uid, None);
-    event.activity_id = Some(1);
+    event.activity_id = Some(1.into());
     event.class_uid = Some(1001);
     event.message = Some("User password changed".to_string());
     event.metadata = Some(Box::new(ocsf_types::Metadata::default()));
@@ -131,7 +160,7 @@ fn verify_default_values() {
     event.activity_name = Some("User password changed".to_string());
     event.category_name = Some("User password changed".to_string());
     event.category_uid = Some(1);
-    assert_eq!(event.activity_id, Some(1));
+    assert_eq!(event.activity_id.map(i64::from), Some(1));
     assert_eq!(event.class_uid, Some(1001));
     assert_eq!(event.message, Some("User password changed".to_string()));
     assert_eq!(event.metadata, Some(Box::new(ocsf_types::Metadata::default())));
@@ -142,15 +171,16 @@ fn verify_default_values() {
     assert_eq!(event.category_uid, Some(1));
 }
 
+#[cfg(feature = "iam")]
 #[test]
 fn test_various_ways_to_create_an_event(){
     use ocsf_types::AccountChange;
     // Note - you should ensure that all required fields exist
     let mut event = AccountChange::default();
-    event.activity_id = Some(1);
+    event.activity_id = Some(1.into());
     let event2 = {
         let mut e = AccountChange::default();
-        e.activity_id = Some(1);
+        e.activity_id = Some(1.into());
         e
     };
     let event3: AccountChange = serde_json::from_value(
@@ -173,6 +203,7 @@ fn test_various_ways_to_create_an_event(){
     assert_eq!(serialized2, serialized3);
 }
 
+#[cfg(feature = "system")]
 #[test]
 fn extract_nested_data_functional() {
     let raw_log = include_str!("data/sample_file_activity.json");
@@ -183,4 +214,871 @@ fn extract_nested_data_functional() {
               .as_ref()
               .and_then(|file_result| file_result.ext.as_deref());
     assert_eq!(file_result_ext, Some("ranked review reverse"));
-}
\ No newline at end of file
+}
+#[cfg(feature = "system")]
+#[test]
+fn extract_nested_data_by_path() {
+    use ocsf_types::path::{FieldRef, PathAccess};
+    let raw_log = include_str!("data/sample_file_activity.json");
+    let event: ocsf_types::FileActivity = serde_json::from_str(raw_log).expect("Failed to parse FileActivity log");
+    assert_eq!(event.get_path("file_result.ext").and_then(|v| v.as_str()), Some("ranked review reverse"));
+    assert_eq!(event.get_path("file_result.signature.certificate.fingerprints[1].algorithm_id").and_then(|v| v.as_i64()), Some(9));
+    assert_eq!(event.get_path("time").and_then(|v| v.as_i64()), Some(1767051583891));
+    assert!(matches!(event.get_path("file_result.signature"), Some(FieldRef::Object(_))));
+    assert!(event.get_path("file_result.signature.certificate.fingerprints").is_none());
+    assert!(event.get_path("file_result.signature.certificate.fingerprints[7]").is_none());
+    assert!(event.get_path("file_result.nmae").is_none());
+    assert!(event.get_path("time.value").is_none());
+}
+#[cfg(feature = "network")]
+#[test]
+fn set_by_path_creates_intermediate_objects() {
+    use ocsf_types::path::{FieldValue, PathAccess, PathErrorKind};
+    let mut event = ocsf_types::NetworkActivity::default();
+    event.set_path("src_endpoint.ip", "10.0.0.1".into()).unwrap();
+    event.set_path("observables[0].name", "src_endpoint.ip".into()).unwrap();
+    event.set_path("observables[0].type_id", 2_i64.into()).unwrap();
+    event.set_path("observables[1]", serde_json::json!({"name": "dst_endpoint.ip", "type_id": 2}).into()).unwrap();
+    event.set_path("src_endpoint.intermediate_ips[0]", "192.168.0.1".into()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        serde_json::json!({
+            "src_endpoint": {"ip": "10.0.0.1", "intermediate_ips": ["192.168.0.1"]},
+            "observables": [{"name": "src_endpoint.ip", "type_id": 2}, {"name": "dst_endpoint.ip", "type_id": 2}]
+        })
+    );
+
+    let err = event.set_path("src_endpoint.port", FieldValue::from("https")).unwrap_err();
+    assert_eq!(err.to_string(), "src_endpoint.port: expected an integer");
+    let err = event.set_path("observables[5].name", "x".into()).unwrap_err();
+    assert_eq!((err.path.as_str(), err.kind), ("observables[5]", PathErrorKind::IndexOutOfBounds { len: 2 }));
+    let err = event.set_path("src_endpoint.hostnmae", "web".into()).unwrap_err();
+    assert_eq!((err.path.as_str(), err.kind), ("src_endpoint.hostnmae", PathErrorKind::UnknownField));
+    assert_eq!(event.set_path("observables.name", "x".into()).unwrap_err().kind, PathErrorKind::Index);
+
+    // a path that fails leaves no object it would have created on the way
+    let before = serde_json::to_value(&event).unwrap();
+    assert!(event.set_path("actor.user.uid", 7_i64.into()).is_err());
+    assert!(event.set_path("observables[2].type_id", "x".into()).is_err());
+    assert!(event.set_path("dst_endpoint.location.nmae", "x".into()).is_err());
+    assert_eq!(serde_json::to_value(&event).unwrap(), before);
+    let mut empty = ocsf_types::NetworkActivity::default();
+    assert!(empty.set_path("observables[0].type_id", "x".into()).is_err());
+    assert_eq!(empty, ocsf_types::NetworkActivity::default());
+}
+#[cfg(all(feature = "network", feature = "system"))]
+#[test]
+fn visitors_reach_every_nested_object() {
+    use ocsf_types::path::PathAccess;
+    use ocsf_types::visit::{self, Visit, VisitMut};
+    use ocsf_types::{Fingerprint, OcsfEvent, User};
+    #[derive(Default)]
+    struct Fingerprints(Vec<String>);
+    impl Visit for Fingerprints {
+        fn visit_fingerprint(&mut self, fingerprint: &Fingerprint) {
+            self.0.extend(fingerprint.value.clone());
+        }
+    }
+    struct Redact;
+    impl VisitMut for Redact {
+        fn visit_user_mut(&mut self, user: &mut User) {
+            user.email_addr = None;
+            visit::walk_user_mut(self, user);
+        }
+    }
+
+    let raw_log = include_str!("data/sample_file_activity.json");
+    let event: OcsfEvent = serde_json::from_str(raw_log).expect("Failed to parse FileActivity log");
+    let mut fingerprints = Fingerprints::default();
+    fingerprints.visit_event(&event);
+    assert_eq!(fingerprints.0.len(), 6);
+    assert!(fingerprints.0.contains(&event.get_path("raw_data_hash.value").unwrap().as_str().unwrap().to_string()));
+
+    let mut event: ocsf_types::NetworkActivity = serde_json::from_value(serde_json::json!({
+        "actor": {"user": {"name": "jdoe", "email_addr": "jdoe@example.com"}},
+        "src_endpoint": {"owner": {"email_addr": "admin@example.com"}}
+    })).unwrap();
+    Redact.visit_network_activity_mut(&mut event);
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        serde_json::json!({"actor": {"user": {"name": "jdoe"}}, "src_endpoint": {"owner": {}}})
+    );
+}
+#[cfg(feature = "network")]
+#[test]
+fn observables_are_built_from_observable_attributes() {
+    use ocsf_types::{OcsfEvent, Observables};
+    let event: OcsfEvent = serde_json::from_value(serde_json::json!({
+        "class_uid": 4001,
+        "actor": {"user": {"name": "jdoe", "uid": "S-1-5-21"}, "process": {"pid": 4242, "name": "curl"}},
+        "src_endpoint": {"ip": "10.0.0.1", "intermediate_ips": ["192.168.0.1", "192.168.0.1", "192.168.0.2"], "port": 51000},
+        "dst_endpoint": {"ip": "10.0.0.1", "hostname": "", "port": 443},
+        "observables": [{"name": "src_endpoint.ip", "type_id": 2, "value": "10.0.0.1"}]
+    })).expect("Failed to parse NetworkActivity log");
+    let observables: Vec<_> = event
+        .observables()
+        .into_iter()
+        .map(|o| (o.name.unwrap(), o.type_id.map(i64::from).unwrap(), o.value.unwrap()))
+        .collect();
+    let expected = [
+        ("actor.process.name", 9, "curl"),
+        ("actor.process.pid", 15, "4242"),
+        ("actor.user.name", 4, "jdoe"),
+        ("actor.user.uid", 31, "S-1-5-21"),
+        ("dst_endpoint.ip", 2, "10.0.0.1"),
+        ("dst_endpoint.port", 11, "443"),
+        ("src_endpoint.intermediate_ips", 2, "192.168.0.1"),
+        ("src_endpoint.intermediate_ips", 2, "192.168.0.2"),
+        ("src_endpoint.ip", 2, "10.0.0.1"),
+        ("src_endpoint.port", 11, "51000"),
+    ];
+    let expected: Vec<_> = expected.iter().map(|(n, t, v)| (n.to_string(), *t, v.to_string())).collect();
+    assert_eq!(observables, expected);
+}
+#[cfg(feature = "system")]
+#[test]
+fn validation_reports_missing_required_fields_with_paths() {
+    use ocsf_types::{Validate, ViolationKind};
+    let event: ocsf_types::FileActivity = serde_json::from_value(serde_json::json!({
+        "activity_id": 1,
+        "metadata": {"product": {"name": "edr"}},
+        "observables": [{"name": "file.name"}]
+    })).expect("Failed to parse FileActivity log");
+    let report = event.validate();
+    assert!(!report.is_valid());
+
+    let errors: Vec<&str> = report.errors().map(|v| v.path.as_str()).collect();
+    assert!(errors.contains(&"class_uid"));
+    assert!(errors.contains(&"time"));
+    assert!(errors.contains(&"metadata.version"));
+    assert!(errors.contains(&"observables[0].type_id"));
+    assert!(!errors.contains(&"activity_id"));
+    assert!(!errors.contains(&"metadata"));
+
+    let warning = report.warnings().find(|v| v.path == "metadata.product.vendor_name").expect("vendor_name warning is missing");
+    assert_eq!(warning.kind, ViolationKind::MissingRecommended);
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn validation_recurses_into_nested_objects_of_a_full_event() {
+    use ocsf_types::Validate;
+    let raw_log = include_str!("data/sample_file_activity.json");
+    let event: ocsf_types::FileActivity = serde_json::from_str(raw_log).expect("Failed to parse FileActivity log");
+    let report = event.validate();
+    // the sample omits the objects that the class marks required, and labels a SHA-512 digest as SHA-384
+    let errors: Vec<&str> = report.errors().map(|v| v.path.as_str()).collect();
+    assert_eq!(errors, vec!["actor", "device", "file_result.signature.certificate.fingerprints[1].value"]);
+    // it applies no profile, so the objects the profiles require are only missing when checking them all
+    let report = event.validate_all_profiles();
+    let errors: Vec<&str> = report.errors().map(|v| v.path.as_str()).collect();
+    assert!(errors.contains(&"cloud") && errors.contains(&"osint"));
+    assert!(report.warnings().any(|v| v.path.starts_with("file.")));
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn validation_enforces_class_constraints() {
+    use ocsf_types::{ConstraintRule, Validate, ViolationKind};
+    let event: ocsf_types::AuthorizeSession = serde_json::from_value(serde_json::json!({
+        "privileges": ["SeDebugPrivilege"],
+        "group": {"name": "Administrators"}
+    })).expect("Failed to parse AuthorizeSession log");
+    let report = event.validate();
+    let violation = report.violations.iter()
+                          .find(|v| matches!(v.kind, ViolationKind::Constraint { .. }))
+                          .expect("just_one violation is missing");
+    assert_eq!(violation.path, "");
+    assert_eq!(violation.kind, ViolationKind::Constraint {
+        rule: ConstraintRule::JustOne,
+        fields: vec!["privileges".to_string(), "group".to_string()],
+    });
+
+    let event: ocsf_types::AuthorizeSession = serde_json::from_value(serde_json::json!({
+        "group": {"name": "Administrators"}
+    })).expect("Failed to parse AuthorizeSession log");
+    assert!(!event.validate().violations.iter().any(|v| matches!(v.kind, ViolationKind::Constraint { .. })));
+}
+
+#[cfg(feature = "findings")]
+#[test]
+fn validation_reports_constraints_on_nested_objects() {
+    use ocsf_types::{ConstraintRule, Validate, ViolationKind};
+    let event: ocsf_types::VulnerabilityFinding = serde_json::from_value(serde_json::json!({
+        "vulnerabilities": [{"title": "no advisory, cve or cwe"}]
+    })).expect("Failed to parse VulnerabilityFinding log");
+    let report = event.validate();
+    let violation = report.violations.iter()
+                          .find(|v| v.path == "vulnerabilities[0]")
+                          .expect("nested constraint violation is missing");
+    assert!(matches!(&violation.kind, ViolationKind::Constraint { rule: ConstraintRule::JustOne, fields } if fields.len() == 3));
+    assert_eq!(violation.to_string(), "vulnerabilities[0]: just_one of [advisory, cve, cwe] not met");
+}
+
+#[cfg(feature = "discovery")]
+#[test]
+fn validation_follows_dotted_constraint_paths() {
+    use ocsf_types::{ConstraintRule, Validate, ViolationKind};
+    let mut json = serde_json::json!({
+        "activity_id": 1, "category_uid": 5, "class_uid": 5004, "type_uid": 500401, "severity_id": 1,
+        "time": 1700000000000_i64,
+        "metadata": {"version": "1.7.0", "product": {"name": "patch-agent", "vendor_name": "Acme"}},
+        "device": {"type_id": 1, "hostname": "ws-01", "uid": "d-1", "os": {"name": "Windows", "type_id": 100, "version": "10.0.19045"}},
+        "kb_article_list": [{"uid": "KB5031356"}]
+    });
+    let event: ocsf_types::PatchState = serde_json::from_value(json.clone()).unwrap();
+    let errors: Vec<String> = event.validate().errors().map(|v| v.to_string()).collect();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    json["device"]["os"].as_object_mut().unwrap().remove("version");
+    let event: ocsf_types::PatchState = serde_json::from_value(json).unwrap();
+    let report = event.validate();
+    let violation = report.violations.iter()
+                          .find(|v| matches!(v.kind, ViolationKind::Constraint { .. }))
+                          .expect("at_least_one violation is missing");
+    assert_eq!(violation.kind, ViolationKind::Constraint {
+        rule: ConstraintRule::AtLeastOne,
+        fields: vec!["device.os.sp_name".to_string(), "device.os.sp_ver".to_string(), "device.os.version".to_string()],
+    });
+}
+
+#[cfg(all(feature = "discovery", feature = "system"))]
+#[test]
+fn ocsf_event_dispatches_on_class_uid() {
+    use ocsf_types::OcsfEvent;
+    let file_activity: serde_json::Value = serde_json::from_str(include_str!("data/sample_file_activity.json")).unwrap();
+    let inventory_info: serde_json::Value = serde_json::from_str(include_str!("data/sample_device_inventory_info_missing_optional.json")).unwrap();
+    let ndjson = [
+        file_activity.to_string(),
+        inventory_info.to_string(),
+        r#"{"class_uid": 999999, "activity_id": 1}"#.to_string(),
+        r#"{"activity_id": 1}"#.to_string(),
+    ].join("\n");
+
+    let events: Vec<OcsfEvent> = ndjson.lines()
+                                       .map(|line| serde_json::from_str(line).expect("Failed to parse event"))
+                                       .collect();
+    let OcsfEvent::FileActivity(event) = &events[0] else { panic!("expected FileActivity, got {:?}", events[0]) };
+    assert_eq!(event.class_uid, Some(1001));
+    assert!(matches!(&events[1], OcsfEvent::InventoryInfo(_)));
+    assert!(matches!(&events[2], OcsfEvent::Unknown(_)));
+    assert!(matches!(&events[3], OcsfEvent::Unknown(_)));
+    assert_eq!(events[1].class_uid(), Some(5001));
+    assert_eq!(events[2].class_uid(), Some(999999));
+    assert_eq!(events[3].class_uid(), None);
+
+    // serializes the same as the inner event
+    assert_eq!(serde_json::to_value(&events[0]).unwrap(), serde_json::to_value(event).unwrap());
+    assert_eq!(serde_json::to_string(&events[2]).unwrap(), r#"{"activity_id":1,"class_uid":999999}"#);
+
+    // only an integer class_uid of an object picks a class, and a Value parses the same as text
+    let event: OcsfEvent = serde_json::from_str(r#"{"class_uid": "1001", "nested": {"class_uid": 1001}}"#).unwrap();
+    assert!(matches!(event, OcsfEvent::Unknown(_)));
+    assert!(matches!(serde_json::from_str(r#"[1001]"#).unwrap(), OcsfEvent::Unknown(serde_json::Value::Array(_))));
+    assert_eq!(serde_json::from_value::<OcsfEvent>(file_activity).unwrap(), events[0]);
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn ocsf_event_rejects_a_known_class_with_invalid_fields() {
+    let res: Result<ocsf_types::OcsfEvent, _> = serde_json::from_str(r#"{"class_uid": 1001, "activity_id": "not a number"}"#);
+    assert!(res.is_err());
+}
+
+#[cfg(all(feature = "iam", feature = "system"))]
+#[test]
+fn class_constants_are_exposed_through_ocsf_class() {
+    use ocsf_types::{AccountChange, FileActivity, OcsfClass};
+    fn label<T: OcsfClass>() -> String {
+        format!("{}/{}:{}", T::CATEGORY_UID, T::CLASS_UID, T::CAPTION)
+    }
+    assert_eq!(AccountChange::CLASS_UID, 3001);
+    assert_eq!(AccountChange::CATEGORY_UID, 3);
+    assert_eq!(AccountChange::CLASS_NAME, "account_change");
+    assert_eq!(AccountChange::CATEGORY_NAME, "iam");
+    assert_eq!(AccountChange::CAPTION, "Account Change");
+    assert_eq!(AccountChange::CATEGORY_CAPTION, "Identity & Access Management");
+    assert_eq!(label::<FileActivity>(), "1/1001:File System Activity");
+}
+
+#[cfg(all(feature = "iam", feature = "network"))]
+#[test]
+fn new_event_populates_class_level_constants() {
+    use ocsf_types::AccountChange;
+    let event = AccountChange::new_event();
+    assert_eq!(event.class_uid, Some(3001));
+    assert_eq!(event.category_uid, Some(3));
+    assert_eq!(event.class_name.as_deref(), Some("Account Change"));
+    assert_eq!(event.category_name.as_deref(), Some("Identity & Access Management"));
+    assert_eq!(event.activity_id, None);
+
+    let event = ocsf_types::NetworkActivity::new_event();
+    assert_eq!(event.class_uid, Some(4001));
+    assert_eq!(event.category_name.as_deref(), Some("Network Activity"));
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn type_uid_is_derived_from_class_and_activity() {
+    use ocsf_types::{Validate, ViolationKind};
+    let mut event = ocsf_types::AccountChange::new_event();
+    assert_eq!(event.derived_type_uid(), None);
+    event.activity_id = Some(ocsf_types::AccountChangeActivityId::Create);
+    assert_eq!(event.derived_type_uid(), Some(300101));
+    assert_eq!(event.derived_type_name().as_deref(), Some("Account Change: Create"));
+    event.activity_id = Some(3.into());
+    assert_eq!(event.derived_type_uid(), Some(300103));
+    event.fill_type();
+    assert_eq!(event.type_uid, Some(300103));
+    assert_eq!(event.type_name.as_deref(), Some("Account Change: Password Change"));
+    event.activity_id = Some(1234.into());
+    assert_eq!(event.derived_type_uid(), Some(301334));
+    assert_eq!(event.derived_type_name(), None);
+    event.activity_id = Some(3.into());
+    assert!(!event.validate().violations.iter().any(|v| v.path == "type_uid"));
+
+    event.type_uid = Some(100103);
+    let report = event.validate();
+    let violation = report.errors().find(|v| v.path == "type_uid").expect("type_uid mismatch is missing");
+    assert_eq!(violation.kind, ViolationKind::TypeUidMismatch { expected: 300103, found: 100103 });
+}
+
+#[cfg(feature = "discovery")]
+#[test]
+fn normalize_fills_missing_captions_from_their_ids() {
+    use ocsf_types::Normalize;
+    let raw_log = include_str!("data/sample_device_inventory_info_missing_optional.json");
+    let mut event: ocsf_types::InventoryInfo = serde_json::from_str(raw_log).expect("Failed to parse InventoryInfo log");
+    assert!(event.category_name.is_none());
+    let conflicts = event.normalize();
+    assert!(conflicts.is_empty(), "{:?}", conflicts);
+    assert_eq!(event.category_name.as_deref(), Some("Discovery"));
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn normalize_fills_enum_captions_in_nested_objects() {
+    use ocsf_types::Normalize;
+    let mut event: ocsf_types::FileActivity = serde_json::from_value(serde_json::json!({
+        "activity_id": 1, "severity_id": 4, "status_id": 2,
+        "file": {"name": "a.txt", "type_id": 2},
+        "actor": {"user": {"name": "alice", "type_id": 99, "type": "Contractor"}},
+        "observables": [{"name": "file.name", "type_id": 7, "value": "a.txt"}]
+    })).unwrap();
+    let conflicts = event.normalize();
+    assert!(conflicts.is_empty(), "{:?}", conflicts);
+    assert_eq!(event.activity_name.as_deref(), Some("Create"));
+    assert_eq!(event.severity.as_deref(), Some("High"));
+    assert_eq!(event.status.as_deref(), Some("Failure"));
+    assert_eq!(event.file.as_ref().unwrap().r#type.as_deref(), Some("Folder"));
+    assert_eq!(event.observables.as_ref().unwrap()[0].r#type.as_deref(), Some("File Name"));
+    // an `Other` caption is defined by the source
+    let user = event.actor.as_ref().unwrap().user.as_ref().unwrap();
+    assert_eq!(user.r#type.as_deref(), Some("Contractor"));
+
+    event.file.as_mut().unwrap().r#type = Some("Regular File".to_string());
+    let conflicts = event.normalize();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path, "file.type");
+    assert_eq!(conflicts[0].expected, "Folder");
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn normalize_keeps_and_reports_conflicting_captions() {
+    use ocsf_types::Normalize;
+    let mut event = ocsf_types::AccountChange::default();
+    event.class_uid = Some(3001);
+    event.class_name = Some("File System Activity".to_string());
+    let conflicts = event.normalize();
+    assert_eq!(event.class_name.as_deref(), Some("File System Activity"));
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path, "class_name");
+    assert_eq!(conflicts[0].id, 3001);
+    assert_eq!(conflicts[0].expected, "Account Change");
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn builders_wrap_values_and_fill_class_fields() {
+    use ocsf_types::{File, FileActivity, Metadata, Product};
+    let metadata = Metadata::builder()
+                             .product(Product::builder().name("edr").build_unchecked())
+                             .version("1.7.0")
+                             .build()
+                             .expect("Failed to build Metadata");
+    let event = FileActivity::builder()
+                             .activity_id(1)
+                             .metadata(metadata.clone())
+                             .file(File::builder().name("a.txt").build_unchecked())
+                             .message("file created")
+                             .build_unchecked();
+    assert_eq!(event.metadata, Some(Box::new(metadata)));
+    assert_eq!(event.class_uid, Some(1001));
+    assert_eq!(event.file.as_ref().and_then(|f| f.name.as_deref()), Some("a.txt"));
+    assert_eq!(event.message.as_deref(), Some("file created"));
+    // only build() fills the type
+    assert_eq!(event.type_uid, None);
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn builder_build_lists_missing_required_attributes() {
+    use ocsf_types::{Metadata, Product};
+    let err = Metadata::builder().version("1.7.0").build().unwrap_err();
+    assert_eq!(err.type_name, "Metadata");
+    assert_eq!(err.missing, vec!["product"]);
+    assert_eq!(err.to_string(), "Metadata is missing required attributes: product");
+
+    let err = ocsf_types::FileActivity::builder()
+                             .activity_id(1)
+                             .metadata(Metadata::builder().product(Product::default()).version("1.7.0").build().unwrap())
+                             .build()
+                             .unwrap_err();
+    // class_uid and category_uid come from the class, type_uid from activity_id
+    assert!(!err.missing.contains(&"class_uid"));
+    assert!(!err.missing.contains(&"type_uid"));
+    assert!(!err.missing.contains(&"metadata"));
+    assert!(err.missing.contains(&"time"));
+    assert!(err.missing.contains(&"file"));
+}
+
+#[cfg(all(feature = "network", feature = "preserve-unknown"))]
+#[test]
+fn unknown_fields_are_preserved_at_every_level() {
+    let json = serde_json::json!({
+        "activity_id": 1,
+        "class_uid": 4001,
+        "new_field": "kept",
+        "src_endpoint": {"ip": "10.0.0.1", "vendor_ext": {"zone": "dmz"}},
+        "observables": [{"name": "src_endpoint.ip", "source": "ids"}]
+    });
+    let event: ocsf_types::NetworkActivity = serde_json::from_value(json.clone()).expect("Failed to parse NetworkActivity log");
+    assert_eq!(event.unknown_fields["new_field"], "kept");
+    let src_endpoint = event.src_endpoint.as_ref().expect("src_endpoint is missing");
+    assert_eq!(src_endpoint.unknown_fields["vendor_ext"]["zone"], "dmz");
+    assert_eq!(event.observables.as_ref().expect("observables are missing")[0].unknown_fields["source"], "ids");
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
+}
+
+#[test]
+fn datetimes_keep_their_text_and_report_values_that_are_not_rfc_3339() {
+    use ocsf_types::{DateTime, Timestamp, Validate, ViolationKind};
+    let json = serde_json::json!({
+        "name": "a.txt",
+        "created_time": 1767051583891_i64,
+        "created_time_dt": "2025-12-29T23:39:43.891000+00:00",
+        "modified_time_dt": "yesterday"
+    });
+    let file: ocsf_types::File = serde_json::from_value(json.clone()).expect("Failed to parse File");
+    assert_eq!(file.created_time, Some(Timestamp::from_millis(1767051583891)));
+    assert!(file.created_time_dt.as_ref().is_some_and(DateTime::is_valid));
+    assert_eq!(file.modified_time_dt.as_ref().map(DateTime::as_str), Some("yesterday"));
+    assert_eq!(serde_json::to_value(&file).unwrap(), json);
+
+    let report = file.validate();
+    let invalid: Vec<_> = report.violations.iter().filter(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. })).collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].to_string(), r#"modified_time_dt: expected datetime_t, found "yesterday""#);
+    assert!(ocsf_types::strict::from_value::<ocsf_types::File>(json).is_err());
+
+    assert!("2025-12-29t23:39:43-05:00".parse::<DateTime>().is_ok());
+    assert!("2025-12-29 23:39:43".parse::<DateTime>().is_err());
+    assert!("2025-02-29T23:39:43Z".parse::<DateTime>().is_err());
+    assert_eq!("yesterday".parse::<DateTime>().unwrap_err().to_string(), r#"invalid datetime_t: "yesterday""#);
+}
+
+#[cfg(all(feature = "chrono", feature = "system"))]
+#[test]
+fn timestamps_convert_to_chrono_and_keep_their_wire_form() {
+    use chrono::{TimeZone, Utc};
+    use ocsf_types::{DateTime, Timestamp};
+    let json = serde_json::json!({
+        "time": 1767051583891_i64,
+        "time_dt": "2025-12-29T23:39:43.891+00:00",
+        "file": {"name": "a.txt", "modified_time_dt": "2025-12-29T18:39:43-05:00"}
+    });
+    let event: ocsf_types::FileActivity = serde_json::from_value(json.clone()).expect("Failed to parse FileActivity log");
+    let expected = Utc.timestamp_millis_opt(1767051583891).unwrap();
+    assert_eq!(event.time.unwrap().to_datetime(), Some(expected));
+    assert_eq!(event.time_dt.as_ref().and_then(DateTime::to_timestamp), event.time);
+    let modified = event.file.as_ref().unwrap().modified_time_dt.as_ref().unwrap();
+    assert_eq!(modified.to_timestamp(), Some(Timestamp::from_millis(1767051583000)));
+    assert_eq!(modified.to_chrono().map(|dt| dt.offset().local_minus_utc()), Some(-5 * 3600));
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
+
+    let event = ocsf_types::FileActivity::builder().time(expected).time_dt(expected).build_unchecked();
+    assert_eq!(event.time, Some(Timestamp::from(1767051583891)));
+    assert_eq!(event.time_dt.as_ref().map(DateTime::as_str), Some("2025-12-29T23:39:43.891Z"));
+    let event: ocsf_types::FileActivity = serde_json::from_str(r#"{"time_dt": "yesterday"}"#).expect("Failed to parse FileActivity log");
+    assert_eq!(event.time_dt.as_ref().and_then(DateTime::to_chrono), None);
+}
+
+#[test]
+fn network_values_keep_their_text_and_expose_parsed_values() {
+    use ocsf_types::{IpAddress, MacAddress, Port, Subnet, Validate, ViolationKind};
+    use std::net::{IpAddr, Ipv4Addr};
+    let json = serde_json::json!({
+        "ip": "10.1.2.3",
+        "mac": "18-36-F3-98-4F-9A",
+        "port": 443,
+        "intermediate_ips": ["192.168.0.1", "not an ip"]
+    });
+    let endpoint: ocsf_types::NetworkEndpoint = serde_json::from_value(json.clone()).expect("Failed to parse NetworkEndpoint");
+    let ip = endpoint.ip.as_ref().and_then(IpAddress::addr).expect("ip is not an address");
+    let subnet: Subnet = "10.0.0.0/8".parse().unwrap();
+    assert!(subnet.contains(ip));
+    assert!(!subnet.contains(IpAddr::V4(Ipv4Addr::new(11, 0, 0, 1))));
+    assert_eq!(subnet.network(), Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0))));
+    assert_eq!(endpoint.mac.as_ref().and_then(MacAddress::octets), Some([0x18, 0x36, 0xf3, 0x98, 0x4f, 0x9a]));
+    assert_eq!(endpoint.port.and_then(|port| port.get()), Some(443));
+    assert_eq!(serde_json::to_value(&endpoint).unwrap(), json);
+
+    let report = endpoint.validate();
+    let invalid: Vec<_> = report.violations.iter().filter(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. })).collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].path, "intermediate_ips[1]");
+    assert_eq!(invalid[0].to_string(), r#"intermediate_ips[1]: expected ip_t, found "not an ip""#);
+
+    assert!("10.0.0.0/33".parse::<Subnet>().is_err());
+    assert!("18:36:F3:98:4F".parse::<MacAddress>().is_err());
+    assert_eq!("not an ip".parse::<IpAddress>().unwrap_err().to_string(), r#"invalid ip_t: "not an ip""#);
+    assert!(!Port::from(-5_i64).is_valid());
+
+    let endpoint = ocsf_types::NetworkEndpoint::builder().ip(Ipv4Addr::LOCALHOST).port(8080_u16).build_unchecked();
+    assert_eq!(endpoint.ip.as_ref().map(IpAddress::as_str), Some("127.0.0.1"));
+    assert_eq!(endpoint.port.map(i64::from), Some(8080));
+}
+
+#[test]
+fn validation_checks_string_formats() {
+    use ocsf_types::{Validate, ViolationKind};
+    let json = serde_json::json!({
+        "name": "a.exe",
+        "hashes": [
+            {"algorithm_id": 3, "value": "3172ac7e2b55cbb81f04a6e65855a628"},
+            {"algorithm_id": 1, "value": "3172ac7e2b55cbb81f04a6e65855a628"},
+            {"algorithm_id": 5, "value": "3:AXGBicFlgVNhBGcL6wCrFQEv:AXGHsNhxLsr2C"}
+        ],
+        "url": {"url_string": "not a url"},
+        "owner": {"name": "jdoe", "email_addr": "jdoe@example.com"}
+    });
+    let file: ocsf_types::File = serde_json::from_value(json).expect("Failed to parse File");
+    let report = file.validate();
+    let invalid: Vec<String> = report
+        .violations
+        .iter()
+        .filter(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. }))
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(invalid, [
+        r#"hashes[0].value: expected file_hash_t, found "3172ac7e2b55cbb81f04a6e65855a628""#,
+        r#"url.url_string: expected url_t, found "not a url""#,
+    ]);
+
+    let mut email: ocsf_types::Email = serde_json::from_value(serde_json::json!({
+        "from": "alice@example.com",
+        "to": ["bob@example.com", "bob at example.com"]
+    })).expect("Failed to parse Email");
+    let paths: Vec<String> = email.validate().violations.into_iter().filter(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. })).map(|v| v.path).collect();
+    assert_eq!(paths, ["to[1]"]);
+    email.to = None;
+    assert!(!email.validate().violations.iter().any(|v| matches!(v.kind, ViolationKind::InvalidFormat { .. })));
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn validation_checks_profile_attributes_only_for_applied_profiles() {
+    use ocsf_types::schema;
+    use ocsf_types::{AccountChange, HostProfile, Validate, ViolationKind};
+    let mut event: AccountChange = serde_json::from_value(serde_json::json!({
+        "metadata": {"profiles": ["host"]},
+        "device": {"hostname": "web-01"}
+    })).expect("Failed to parse AccountChange log");
+    let missing = |report: &ocsf_types::ValidationReport, path: &str| {
+        report.violations.iter().any(|v| v.path == path && v.kind == ViolationKind::MissingRequired)
+    };
+    let report = event.validate();
+    assert!(!missing(&report, "cloud") && !missing(&report, "osint"));
+    assert!(missing(&report, "time"));
+    // checking every profile is an explicit opt-in
+    let report = event.validate_all_profiles();
+    assert!(missing(&report, "cloud") && missing(&report, "osint"));
+
+    fn hostname<T: HostProfile>(event: &T) -> Option<&str> {
+        event.device().and_then(|device| device.hostname.as_deref())
+    }
+    assert_eq!(hostname(&event), Some("web-01"));
+    *event.device_mut() = None;
+    assert_eq!(<AccountChange as HostProfile>::PROFILE, "host");
+
+    let class = schema::class("account_change").unwrap();
+    assert!(class.profiles().contains(&"cloud"));
+    assert_eq!(class.attribute("cloud").unwrap().profile(), Some("cloud"));
+    assert_eq!(class.attribute("user").unwrap().profile(), None);
+}
+#[cfg(all(feature = "iam", feature = "system"))]
+#[test]
+fn classes_live_in_their_category_module() {
+    use ocsf_types::OcsfClass;
+    let event: ocsf_types::iam::AccountChange = ocsf_types::AccountChange::new_event();
+    assert_eq!(ocsf_types::iam::AccountChange::CATEGORY_NAME, "iam");
+    let _: ocsf_types::ocsf_generated::system::FileActivity = ocsf_types::FileActivity::default();
+    // objects are shared by every category
+    let _: ocsf_types::common::User = ocsf_types::User::default();
+    let parsed: ocsf_types::OcsfEvent = serde_json::to_value(&event).and_then(serde_json::from_value).unwrap();
+    assert!(matches!(parsed, ocsf_types::OcsfEvent::AccountChange(_)));
+}
+#[cfg(all(feature = "win", feature = "system"))]
+#[test]
+fn extension_types_live_in_their_extension_module() {
+    use ocsf_types::schema;
+    let _: ocsf_types::win::WinRegistryKeyActivity = ocsf_types::WinRegistryKeyActivity::new_event();
+    let class = schema::class("win/registry_key_activity").unwrap();
+    assert_eq!((class.extension(), class.category()), (Some("win"), "system"));
+    assert_eq!(schema::object("win/reg_key").unwrap().extension(), Some("win"));
+    // attributes of core objects that the extension adds
+    let process = schema::object("process").unwrap();
+    assert_eq!(process.attribute("hosted_services").unwrap().extension(), Some("win"));
+    assert_eq!(process.attribute("name").unwrap().extension(), None);
+    assert_eq!(schema::class("file_activity").unwrap().extension(), None);
+    let process: ocsf_types::Process = serde_json::from_str(r#"{"hosted_services": [{"name": "wuauserv"}]}"#).unwrap();
+    assert_eq!(process.hosted_services.unwrap()[0].name.as_deref(), Some("wuauserv"));
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn default_schema_version_is_reexported_at_the_root() {
+    assert_eq!(ocsf_types::SCHEMA_VERSION, "1.7.0");
+    assert_eq!(ocsf_types::v1_7::SCHEMA.version(), ocsf_types::SCHEMA_VERSION);
+    assert_eq!(ocsf_types::v1_7::SCHEMA.class_by_uid(3001).map(|c| c.name()), Some("account_change"));
+    use ocsf_types::schema::EventSchema;
+    assert!(std::ptr::eq(ocsf_types::v1_7::OcsfEvent::schema(), &ocsf_types::v1_7::SCHEMA));
+    let event: ocsf_types::v1_7::AccountChange = ocsf_types::AccountChange::new_event();
+    assert_eq!(event.class_uid, Some(3001));
+}
+#[cfg(feature = "findings")]
+#[test]
+fn migrations_upgrade_deprecated_classes_and_report_changes() {
+    use ocsf_types::migrate::{self, Change, MigrationError};
+    let archived = serde_json::json!({
+        "class_uid": 2001, "class_name": "Security Finding", "category_uid": 2, "activity_id": 1,
+        "type_uid": 200101, "type_name": "Security Finding: Create",
+        "metadata": {"version": "1.0.0", "product": {"name": "scanner"}},
+        "finding": {"title": "Open bucket", "uid": "f-1", "supporting_data": {"rule": 7}},
+        "analytic": {"name": "bucket-policy", "type_id": 1},
+        "state_id": 1,
+        "cis_csc": [{"control": "3.3"}],
+        "observables": [{"name": "finding.uid", "type_id": 99, "value": "f-1"}, {"name": "cis_csc.control", "type_id": 99, "value": "3.3"}],
+    });
+    assert_eq!(migrate::replacements("security_finding")[0], "detection_finding");
+    assert!(migrate::replacements("compliance_finding").is_empty());
+
+    // a security finding is a detection finding unless told otherwise
+    let mut event = archived.clone();
+    migrate::upgrade(&mut event).unwrap();
+    assert_eq!(event["class_uid"], 2004);
+
+    let mut event = archived.clone();
+    let report = migrate::upgrade_to(&mut event, "compliance_finding").unwrap();
+    assert_eq!(report.changes[0], Change::Class { from: "security_finding", to: "compliance_finding" });
+    let type_name = Change::Set { path: "type_name".to_string(), value: "Compliance Finding: Create".into() };
+    assert!(report.changes.contains(&type_name));
+    assert!(report.changes.contains(&Change::Moved { from: "state_id".to_string(), to: "status_id".to_string() }));
+    assert_eq!(report.unmapped().collect::<Vec<_>>(), ["cis_csc", "finding_info.supporting_data"]);
+    assert_eq!(event["type_uid"], 200301);
+    // without a time and severity_id the event is no 1.7.0 event
+    assert_eq!(event["metadata"]["version"], "1.0.0");
+    assert_eq!(event["unmapped"]["finding_info.supporting_data"]["rule"], 7);
+    // observables follow their attribute, or go with it when it moves into unmapped
+    assert_eq!(event["observables"], serde_json::json!([{"name": "finding_info.uid", "type_id": 99, "value": "f-1"}]));
+    assert!(report.changes.contains(&Change::Set { path: "observables[0].name".to_string(), value: "finding_info.uid".into() }));
+    assert!(report.changes.contains(&Change::Removed { path: "observables[1]".to_string() }));
+    let ocsf_types::OcsfEvent::ComplianceFinding(finding) = serde_json::from_value(event).unwrap() else {
+        panic!("expected a compliance finding");
+    };
+    let finding_info = finding.finding_info.unwrap();
+    assert_eq!(finding_info.title.as_deref(), Some("Open bucket"));
+    assert_eq!(finding_info.analytic.unwrap().name.as_deref(), Some("bucket-policy"));
+
+    // the status of the event makes way for the state of the finding
+    let mut event = archived.clone();
+    event["time"] = 1767051583891i64.into();
+    event["severity_id"] = 3.into();
+    event["status_id"] = 1.into();
+    event["status"] = "Success".into();
+    event["state_id"] = 2.into();
+    event["state"] = "In Progress".into();
+    let report = migrate::upgrade(&mut event).unwrap();
+    assert_eq!((&event["status_id"], &event["status"]), (&2.into(), &"In Progress".into()));
+    assert_eq!((&event["unmapped"]["status_id"], &event["unmapped"]["status"]), (&1.into(), &"Success".into()));
+    assert!(report.changes.contains(&Change::Unmapped { path: "status_id".to_string() }));
+    assert!(report.changes.contains(&Change::Moved { from: "state_id".to_string(), to: "status_id".to_string() }));
+    // ... and makes the event a valid 1.7.0 event
+    assert_eq!(event["metadata"]["version"], ocsf_types::SCHEMA_VERSION);
+    let event: ocsf_types::DetectionFinding = ocsf_types::strict::from_value(event).unwrap();
+    assert_eq!(event.status_id, Some(ocsf_types::DetectionFindingStatusId::InProgress));
+
+    let mut event = archived.clone();
+    let err = migrate::upgrade_to(&mut event, "file_query").unwrap_err();
+    assert!(matches!(err, MigrationError::NotAReplacement { .. }));
+    assert_eq!(event, archived);
+
+    let mut event = serde_json::json!({
+        "class_uid": 4011, "activity_id": 1, "email_uid": "m-1",
+        "email": {"subject": "invoice", "files": [{"name": "a.pdf"}]},
+        "file": {"name": "b.pdf"},
+    });
+    migrate::upgrade(&mut event).unwrap();
+    assert_eq!(event["email"]["uid"], "m-1");
+    assert_eq!(event["email"]["files"][1]["name"], "b.pdf");
+
+    // the moved attributes join an existing query_evidence, whose own attributes win
+    let mut event = serde_json::json!({
+        "class_uid": 5007, "activity_id": 1,
+        "file": {"name": "a.txt"}, "job": {"name": "nightly"},
+        "query_evidence": {"query_type_id": 2, "job": {"name": "weekly"}},
+    });
+    let report = migrate::upgrade(&mut event).unwrap();
+    assert_eq!(event["query_evidence"]["file"]["name"], "a.txt");
+    assert_eq!(event["query_evidence"]["job"]["name"], "weekly");
+    assert_eq!(event["unmapped"]["job"]["name"], "nightly");
+    assert_eq!(report.unmapped().collect::<Vec<_>>(), ["job"]);
+    let mut event = serde_json::json!({"class_uid": 5007, "query_evidence": {"query_type_id": 10}});
+    let err = migrate::upgrade(&mut event).unwrap_err();
+    assert_eq!(err.to_string(), "query_evidence.query_type_id is already set");
+
+    // a current class only has an older version stamped, when it is valid
+    let current = serde_json::json!({
+        "class_uid": 2004, "category_uid": 2, "activity_id": 1, "type_uid": 200401, "severity_id": 1, "time": 1767051583891i64,
+        "finding_info": {"uid": "f-1"}, "metadata": {"version": "1.6.0", "product": {"name": "scanner"}},
+    });
+    let mut event = current.clone();
+    let report = migrate::upgrade(&mut event).unwrap();
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.changes[0].to_string(), "set metadata.version = \"1.7.0\"");
+    let mut event = current.clone();
+    event["finding_info"] = serde_json::json!({"title": "no uid"});
+    assert!(migrate::upgrade(&mut event).unwrap().is_empty());
+    let mut event = current.clone();
+    event["vendor_field"] = true.into();
+    assert!(migrate::upgrade(&mut event).unwrap().is_empty());
+    assert_eq!(event["metadata"]["version"], "1.6.0");
+    for version in ["1.8.0", "1.7.0", "1.10.0-dev", "latest"] {
+        let mut event = current.clone();
+        event["metadata"]["version"] = version.into();
+        assert!(migrate::upgrade(&mut event).unwrap().is_empty());
+        assert_eq!(event["metadata"]["version"], version);
+    }
+    let mut event = current.clone();
+    event["metadata"]["version"] = "1.7.0-dev".into();
+    assert!(!migrate::upgrade(&mut event).unwrap().is_empty());
+}
+#[cfg(all(feature = "application", feature = "discovery", feature = "findings"))]
+#[test]
+fn migrations_reset_ids_that_the_new_class_defines_differently() {
+    use ocsf_types::migrate::{self, Change};
+    // `Access Grant` would be `Create` in web_resources_activity
+    let mut event = serde_json::json!({
+        "class_uid": 6004, "activity_id": 1, "activity_name": "Access Grant", "type_uid": 600401, "status_id": 1,
+    });
+    let report = migrate::upgrade(&mut event).unwrap();
+    assert_eq!((&event["activity_id"], &event["type_uid"], &event["type_name"]), (&0.into(), &600100.into(), &"Web Resources Activity: Unknown".into()));
+    assert_eq!((&event["unmapped"]["activity_id"], &event["unmapped"]["activity_name"]), (&1.into(), &"Access Grant".into()));
+    assert!(report.changes.contains(&Change::Set { path: "activity_id".to_string(), value: 0.into() }));
+    assert!(report.changes.contains(&Change::Unmapped { path: "activity_id".to_string() }));
+    // Success means the same in both
+    assert_eq!(event["status_id"], 1);
+
+    // `Log` and `Success` would be `Create` and `New` in compliance_finding
+    let mut event = serde_json::json!({"class_uid": 5002, "activity_id": 1, "status_id": 1, "status": "Success"});
+    let report = migrate::upgrade(&mut event).unwrap();
+    assert_eq!((&event["activity_id"], &event["status_id"]), (&0.into(), &0.into()));
+    assert_eq!(event["unmapped"], serde_json::json!({"activity_id": 1, "status_id": 1, "status": "Success"}));
+    assert_eq!(report.unmapped().collect::<Vec<_>>(), ["activity_id", "status_id", "status"]);
+    // an id that means the same is kept
+    let mut event = serde_json::json!({"class_uid": 5002, "activity_id": 99, "status_id": 0});
+    migrate::upgrade(&mut event).unwrap();
+    assert_eq!((&event["activity_id"], &event["status_id"]), (&99.into(), &0.into()));
+    assert!(event.get("unmapped").is_none());
+}
+
+#[cfg(feature = "iam")]
+#[test]
+fn schema_introspection_describes_classes_objects_and_attributes() {
+    use ocsf_types::OcsfClass;
+    use ocsf_types::schema::{self, Requirement};
+    let class = schema::class_by_uid(3001).expect("account_change is missing");
+    assert_eq!(class.name(), ocsf_types::AccountChange::CLASS_NAME);
+    assert_eq!((class.category(), class.category_uid()), ("iam", 3));
+    assert_eq!(schema::class("account_change").map(|c| c.uid()), Some(3001));
+    let time = class.attribute("time").expect("time is missing");
+    assert_eq!((time.type_name(), time.requirement()), ("timestamp_t", Requirement::Required));
+    assert_eq!(class.attribute("class_uid").unwrap().enum_values(), &[(3001, "Account Change")]);
+    assert!(class.attribute("no_such_attribute").is_none());
+
+    let file = schema::object("file").expect("file is missing");
+    let hashes = file.attribute("hashes").expect("hashes is missing");
+    assert_eq!((hashes.type_name(), hashes.object_type(), hashes.is_array()), ("object_t", Some("fingerprint"), true));
+    assert_eq!(file.attribute("name").unwrap().caption(), "Name");
+    assert!(file.attributes().windows(2).all(|w| w[0].name() < w[1].name()));
+
+    let deprecated = schema::class("admin_group_query").and_then(|c| c.deprecated()).expect("admin_group_query is not deprecated");
+    assert_eq!(deprecated.since, "1.5.0");
+    // uids are unique
+    assert!(schema::classes().iter().all(|c| schema::class_by_uid(c.uid()).map(|found| found.name()) == Some(c.name())));
+    // classes and extensions are only described with their feature
+    assert_eq!(schema::object("win/reg_key").is_some(), cfg!(feature = "win"));
+    assert_eq!(schema::class("file_activity").is_some(), cfg!(feature = "system"));
+    assert_eq!(schema::object("process").unwrap().attribute("hosted_services").is_some(), cfg!(feature = "win"));
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn strict_parsing_rejects_invalid_network_values() {
+    use ocsf_types::strict::{self, StrictError};
+    let json = r#"{"class_uid": 4001, "src_endpoint": {"ip": "not an ip", "port": -5}}"#;
+    let lenient: ocsf_types::NetworkActivity = serde_json::from_str(json).expect("Failed to parse NetworkActivity log");
+    assert_eq!(lenient.src_endpoint.as_ref().and_then(|e| e.ip.as_ref()).map(|ip| ip.as_str()), Some("not an ip"));
+    let Err(StrictError::InvalidValues(violations)) = strict::from_str::<ocsf_types::NetworkActivity>(json) else {
+        panic!("expected invalid values");
+    };
+    let paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+    assert_eq!(paths, ["src_endpoint.ip", "src_endpoint.port"]);
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn strict_parsing_rejects_unknown_fields_with_their_paths() {
+    use ocsf_types::strict::{self, StrictError};
+    let json = r#"{
+        "actvity_id": 1,
+        "class_uid": 4001,
+        "src_endpoint": {"ip": "10.0.0.1", "hostnmae": "web"},
+        "observables": [{"name": "a"}, {"name": "b", "vaule": "c"}],
+        "unmapped": {"anything": "goes"}
+    }"#;
+    let err = strict::from_str::<ocsf_types::NetworkActivity>(json).unwrap_err();
+    let StrictError::UnknownFields(paths) = &err else { panic!("expected unknown fields, got {:?}", err) };
+    assert_eq!(paths, &vec!["actvity_id", "observables[1].vaule", "src_endpoint.hostnmae"]);
+
+    // the lenient default still accepts it
+    assert!(serde_json::from_str::<ocsf_types::NetworkActivity>(json).is_ok());
+}
+
+#[cfg(all(feature = "discovery", feature = "system"))]
+#[test]
+fn strict_parsing_accepts_schema_conformant_events() {
+    use ocsf_types::strict;
+    let mut raw_log: serde_json::Value = serde_json::from_str(include_str!("data/sample_file_activity.json")).unwrap();
+    // the sample labels a SHA-512 digest as SHA-384
+    raw_log["file_result"]["signature"]["certificate"]["fingerprints"][1]["algorithm_id"] = 4.into();
+    let event: ocsf_types::FileActivity = strict::from_value(raw_log).expect("Failed to strictly parse FileActivity log");
+    assert_eq!(event.class_uid, Some(1001));
+    let mut raw_log: serde_json::Value = serde_json::from_str(include_str!("data/sample_device_inventory_info_missing_optional.json")).unwrap();
+    // the sample's product url is not a URL
+    raw_log["metadata"]["product"]["url_string"] = "https://example.com".into();
+    let event: ocsf_types::OcsfEvent = strict::from_value(raw_log).expect("Failed to strictly parse InventoryInfo log");
+    assert!(matches!(event, ocsf_types::OcsfEvent::InventoryInfo(_)));
+    assert!(strict::from_str::<ocsf_types::OcsfEvent>(r#"{"class_uid": 5001, "devcie": {}}"#).is_err());
+}